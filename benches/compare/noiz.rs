@@ -1,19 +1,20 @@
 use crate::{FREQUENCY, LACUNARITY, PERSISTENCE, SIZE_3D, SIZE_4D};
 
 use super::SIZE_2D;
-use bevy_math::{Vec2, Vec3, Vec3A, Vec4};
+use bevy_math::{IVec2, Vec2, Vec3, Vec3A, Vec4};
 use criterion::{measurement::WallTime, *};
 use noiz::{
     Noise, Sampleable, SampleableFor, ScalableNoise,
     cell_noise::{
-        BlendCellGradients, MixCellGradients, MixCellValues, PerCellPointDistances, QuickGradients,
-        SimplecticBlend, WorleyLeastDistance,
+        BlendCellGradients, MixCellGradients, MixCellValues, MultiWorley, PerCellPointDistances,
+        PerNearestPoint, QuickGradients, SharedPartition, SimplecticBlend, WorleyDifference,
+        WorleyLeastDistance,
     },
     cells::{OrthoGrid, SimplexGrid, Voronoi},
     curves::Smoothstep,
     layering::{FractalLayers, LayeredNoise, Normed, Octave, Persistence},
     lengths::EuclideanLength,
-    rng::{Random, UNorm},
+    rng::{AnyValueFromBits, NoiseRng, PermTableRng, Random, UNorm},
 };
 
 #[inline]
@@ -236,4 +237,109 @@ pub fn benches(c: &mut Criterion) {
             res
         });
     });
+
+    group.bench_function("voronoi two children repeated partition", |bencher| {
+        bencher.iter(|| {
+            let distances = Noise::<
+                PerCellPointDistances<Voronoi, EuclideanLength, WorleyLeastDistance>,
+            >::default();
+            let values =
+                Noise::<PerNearestPoint<Voronoi, EuclideanLength, Random<UNorm, f32>>>::default();
+            let mut res = 0.0;
+            for x in 0..SIZE_2D {
+                for y in 0..SIZE_2D {
+                    let loc = Vec2::new(x as f32, y as f32) * FREQUENCY;
+                    res += distances.sample_for::<f32>(loc) + values.sample_for::<f32>(loc);
+                }
+            }
+            res
+        });
+    });
+    group.bench_function("voronoi two children shared partition", |bencher| {
+        bencher.iter(|| {
+            let noise = Noise::<
+                SharedPartition<
+                    Voronoi,
+                    (
+                        PerCellPointDistances<(), EuclideanLength, WorleyLeastDistance>,
+                        PerNearestPoint<(), EuclideanLength, Random<UNorm, f32>>,
+                    ),
+                >,
+            >::default();
+            let mut res = 0.0;
+            for x in 0..SIZE_2D {
+                for y in 0..SIZE_2D {
+                    let loc = Vec2::new(x as f32, y as f32) * FREQUENCY;
+                    let (distance, value): (f32, f32) = noise.sample(loc);
+                    res += distance + value;
+                }
+            }
+            res
+        });
+    });
+
+    group.bench_function("worley two modes separate", |bencher| {
+        bencher.iter(|| {
+            let least = Noise::<
+                PerCellPointDistances<Voronoi, EuclideanLength, WorleyLeastDistance>,
+            >::default();
+            let difference = Noise::<
+                PerCellPointDistances<Voronoi, EuclideanLength, WorleyDifference>,
+            >::default();
+            let mut res = 0.0;
+            for x in 0..SIZE_2D {
+                for y in 0..SIZE_2D {
+                    let loc = Vec2::new(x as f32, y as f32) * FREQUENCY;
+                    res += least.sample_for::<f32>(loc) + difference.sample_for::<f32>(loc);
+                }
+            }
+            res
+        });
+    });
+    group.bench_function("worley two modes multi", |bencher| {
+        bencher.iter(|| {
+            let noise = Noise::<
+                MultiWorley<Voronoi, EuclideanLength, (WorleyLeastDistance, WorleyDifference)>,
+            >::default();
+            let mut res = 0.0;
+            for x in 0..SIZE_2D {
+                for y in 0..SIZE_2D {
+                    let loc = Vec2::new(x as f32, y as f32) * FREQUENCY;
+                    let (least, difference): (f32, f32) = noise.sample(loc);
+                    res += least + difference;
+                }
+            }
+            res
+        });
+    });
+
+    let rng = NoiseRng(0);
+    group.bench_function("value manual hash", |bencher| {
+        bencher.iter(|| manual_value_2d(|cell| rng.rand_u32(cell)));
+    });
+    let table = PermTableRng::new(0);
+    group.bench_function("value manual perm table", |bencher| {
+        bencher.iter(|| manual_value_2d(|cell| table.rand_u32(cell)));
+    });
+}
+
+/// Samples 2d value noise, using `hash` to generate the value at each lattice point.
+#[inline]
+fn manual_value_2d(hash: impl Fn(IVec2) -> u32) -> f32 {
+    let mut res = 0.0;
+    for x in 0..SIZE_2D {
+        for y in 0..SIZE_2D {
+            let loc = Vec2::new(x as f32, y as f32) * FREQUENCY;
+            let floored = loc.floor();
+            let cell = floored.as_ivec2();
+            let t = loc - floored;
+            let t = t * t * (Vec2::splat(3.0) - 2.0 * t);
+            let value = |offset: IVec2| -> f32 { UNorm.any_value(hash(cell + offset)) };
+            let lerp = |a: f32, b: f32, t: f32| a + (b - a) * t;
+            let bottom = lerp(value(IVec2::ZERO), value(IVec2::X), t.x);
+            let top = lerp(value(IVec2::Y), value(IVec2::ONE), t.x);
+            res += lerp(bottom, top, t.y);
+        }
+    }
+    res
 }
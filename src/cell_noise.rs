@@ -6,20 +6,105 @@ use core::{
 };
 
 use bevy_math::{
-    Curve, Vec2, Vec3, Vec3A, Vec4, Vec4Swizzles, VectorSpace, curve::derivatives::SampleDerivative,
+    Curve, IVec2, Mat2, Vec2, Vec3, Vec3A, Vec4, Vec4Swizzles, VectorSpace,
+    curve::derivatives::{SampleDerivative, SampleTwoDerivatives},
 };
 
 use crate::{
-    NoiseFunction,
+    NoiseFunction, NoiseRange, OutputRange,
     cells::{
-        BlendableDomainCell, DifferentiableCell, DomainCell, InterpolatableCell, Partitioner,
-        WithGradient, WorleyDomainCell,
+        BlendableDomainCell, CellPoint, DifferentiableCell, DomainCell, InterpolatableCell,
+        OrthoGrid, Partitioner, SquareCell, Voronoi, WithGradient, WithHessian, WorleyDomainCell,
+        WrappingAmount,
     },
     curves::{SmoothMin, Smoothstep},
-    lengths::{ElementalVectorSpace, EuclideanLength, LengthFunction},
-    rng::{AnyValueFromBits, ConcreteAnyValueFromBits, NoiseRng, SNormSplit, UNorm},
+    lengths::{
+        DifferentiableLengthFunction, ElementalVectorSpace, EuclideanLength, LengthFunction,
+    },
+    misc_noise::RandomElements,
+    rng::{
+        AnyValueFromBits, ConcreteAnyValueFromBits, NoiseRng, NoiseRngInput, Random, SNorm,
+        SNormSplit, UNorm,
+    },
 };
 
+/// Represents a computation on a [`DomainCell`] `C` that has already been partitioned.
+/// This is implemented by many [`NoiseFunction`]s here that would otherwise partition the domain themselves.
+/// See [`SharedPartition`] for why this is useful.
+pub trait CellFunction<C: DomainCell> {
+    /// The output of the function.
+    type Output;
+
+    /// Evaluates the function for this `cell` with this [`NoiseRng`].
+    /// Any [`Partitioner`] owned by `self` is ignored.
+    fn evaluate_cell(&self, cell: &C, seeds: &mut NoiseRng) -> Self::Output;
+}
+
+impl<C: DomainCell, T0: CellFunction<C>, T1: CellFunction<C>> CellFunction<C> for (T0, T1) {
+    type Output = (T0::Output, T1::Output);
+
+    #[inline]
+    fn evaluate_cell(&self, cell: &C, seeds: &mut NoiseRng) -> Self::Output {
+        (
+            self.0.evaluate_cell(cell, seeds),
+            self.1.evaluate_cell(cell, seeds),
+        )
+    }
+}
+
+impl<C: DomainCell, T0: CellFunction<C>, T1: CellFunction<C>, T2: CellFunction<C>> CellFunction<C>
+    for (T0, T1, T2)
+{
+    type Output = (T0::Output, T1::Output, T2::Output);
+
+    #[inline]
+    fn evaluate_cell(&self, cell: &C, seeds: &mut NoiseRng) -> Self::Output {
+        (
+            self.0.evaluate_cell(cell, seeds),
+            self.1.evaluate_cell(cell, seeds),
+            self.2.evaluate_cell(cell, seeds),
+        )
+    }
+}
+
+/// A [`NoiseFunction`] that partitions space by some [`Partitioner`] `P` once and passes the resulting [`DomainCell`] to each [`CellFunction`] in `F`.
+/// This produces the same results as evaluating each child on its own, but it avoids repeating the partitioning (and point generation setup) per child.
+/// That can be a significant saving for expensive [`Partitioner`]s like [`Voronoi`](crate::cells::Voronoi).
+///
+/// The children's own partitioners are ignored, so it is conventional to leave them as `()`:
+///
+/// ```
+/// # use noiz::prelude::*;
+/// use noiz::cell_noise::{PerNearestPoint, SharedPartition};
+/// let noise = Noise::<SharedPartition<Voronoi, (
+///     PerCellPointDistances<(), EuclideanLength, WorleyLeastDistance>,
+///     PerNearestPoint<(), EuclideanLength, Random<UNorm, f32>>,
+/// )>>::default();
+/// let (distance, cell_value): (f32, f32) = noise.sample(bevy_math::Vec2::ZERO);
+/// ```
+#[derive(Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct SharedPartition<P, F> {
+    /// The [`Partitioner`].
+    pub cells: P,
+    /// The [`CellFunction`]s, usually a tuple.
+    pub children: F,
+}
+
+impl<I: VectorSpace, P: Partitioner<I>, F: CellFunction<P::Cell>> NoiseFunction<I>
+    for SharedPartition<P, F>
+{
+    type Output = F::Output;
+
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        self.children
+            .evaluate_cell(&self.cells.partition(input), seeds)
+    }
+}
+
 /// A [`NoiseFunction`] that sharply jumps between values for different [`DomainCell`]s form a [`Partitioner`] `S`, where each value is from a [`NoiseFunction<u32>`] `N`.
 ///
 /// This is the simplest kind of spatial [`NoiseFunction`], and it can be used to make white noise.
@@ -61,6 +146,135 @@ impl<I: VectorSpace, P: Partitioner<I>, N: NoiseFunction<u32>> NoiseFunction<I>
     }
 }
 
+impl<W, N: NoiseFunction<u32>> PerCell<OrthoGrid<W>, N> {
+    /// Evaluates the noise of the grid cell at the integer coordinate `coord`, which may be an [`IVec2`], [`IVec3`](bevy_math::IVec3), or [`IVec4`](bevy_math::IVec4).
+    /// This is the same as evaluating anywhere within that cell, but skips flooring the input.
+    #[inline]
+    pub fn evaluate_lattice<I: NoiseRngInput>(&self, coord: I, seeds: &mut NoiseRng) -> N::Output
+    where
+        W: WrappingAmount<I>,
+    {
+        // This matches `SquareCell::rough_id`, which only depends on the floored corner.
+        self.noise.evaluate(seeds.rand_u32(coord), seeds)
+    }
+}
+
+impl<P, N: NoiseRange> NoiseRange for PerCell<P, N> {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        self.noise.output_range(input)
+    }
+}
+
+/// A [`NoiseFunction`] that partitions space by some [`Partitioner`] `P` into [`DomainCell`]s and returns the raw random bits of each cell, its [`rough_id`](DomainCell::rough_id).
+///
+/// This is the same `u32` that [`PerCell`] passes to its [`NoiseFunction<u32>`], so `PerCell<P, N>` is equivalent to `RawBits<P>` followed by `N`.
+/// For the same seed and cell, the bits are always the same, which makes them useful for decoding into custom values, like enum variants or bit flags.
+/// See [`NoiseRng::high_u16`] and [`NoiseRng::low_u16`] to split them into two well distributed fields.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// use noiz::{cell_noise::RawBits, rng::NoiseRng};
+/// let noise = Noise::<RawBits<OrthoGrid>>::default();
+/// let bits = noise.sample_for::<u32>(bevy_math::Vec2::ZERO);
+/// let variant = NoiseRng::high_u16(bits) % 3;
+/// ```
+#[derive(Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct RawBits<P> {
+    /// The [`Partitioner`].
+    pub cells: P,
+}
+
+impl<I: VectorSpace, P: Partitioner<I>> NoiseFunction<I> for RawBits<P> {
+    type Output = u32;
+
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        self.evaluate_cell(&self.cells.partition(input), seeds)
+    }
+}
+
+impl<C: DomainCell, P> CellFunction<C> for RawBits<P> {
+    type Output = u32;
+
+    #[inline]
+    fn evaluate_cell(&self, cell: &C, seeds: &mut NoiseRng) -> Self::Output {
+        cell.rough_id(*seeds)
+    }
+}
+
+/// A [`NoiseFunction`] that partitions space by an [`OrthoGrid`](crate::cells::OrthoGrid)-like [`Partitioner`] `P` and returns the [`offset`](SquareCell::offset) of the sample within its [`SquareCell`].
+/// Each component of the result is in [0, 1), increasing linearly across the cell.
+///
+/// This is mostly useful for debugging and visualizing partitioning.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// use noiz::cell_noise::CellOffset;
+/// let noise = Noise::<CellOffset<OrthoGrid>>::default();
+/// let offset: bevy_math::Vec3 = noise.sample(bevy_math::Vec3::new(1.25, -0.5, 3.0));
+/// ```
+#[derive(Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct CellOffset<P> {
+    /// The [`Partitioner`].
+    pub cells: P,
+}
+
+impl<I: VectorSpace, Z, W, P: Partitioner<I, Cell = SquareCell<I, Z, W>>> NoiseFunction<I>
+    for CellOffset<P>
+{
+    type Output = I;
+
+    #[inline]
+    fn evaluate(&self, input: I, _seeds: &mut NoiseRng) -> Self::Output {
+        self.cells.partition(input).offset
+    }
+}
+
+/// A [`NoiseFunction`] that partitions space by a [`Partitioner`] `P` and picks one of `K` fixed [`values`](PalettePerCell::values) for each [`DomainCell`].
+/// The value is chosen deterministically by the cell's [`rough_id`](DomainCell::rough_id), scaled to `K` using its higher bits, which are the most random.
+/// This is useful for discrete per-cell lookups like biome or color indices.
+///
+/// `K` must not be 0.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// use noiz::cell_noise::PalettePerCell;
+/// let noise = Noise::<PalettePerCell<Voronoi, 3>>::from(PalettePerCell {
+///     cells: Voronoi::default(),
+///     values: [0.0, 0.25, 1.0],
+/// });
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct PalettePerCell<P, const K: usize, T = f32> {
+    /// The [`Partitioner`].
+    pub cells: P,
+    /// The palette to choose from.
+    pub values: [T; K],
+}
+
+impl<I: VectorSpace, P: Partitioner<I>, const K: usize, T: Copy> NoiseFunction<I>
+    for PalettePerCell<P, K, T>
+{
+    type Output = T;
+
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        let cell = self.cells.partition(input);
+        let index = (cell.rough_id(*seeds) as u64 * K as u64) >> 32;
+        self.values[index as usize]
+    }
+}
+
 /// A [`NoiseFunction`] that sharply jumps between values for different [`CellPoint`](crate::cells::CellPoint)s form a [`Partitioner`] `P`,
 /// where each value is from a [`NoiseFunction<u32>`] `N` where the `u32` is sourced from the nearest [`CellPoint`](crate::cells::CellPoint)s.
 /// The [`LengthFunction`] `L` is used to determine which point is nearest.
@@ -103,7 +317,17 @@ impl<I: VectorSpace, L: LengthFunction<I>, P: Partitioner<I>, N: NoiseFunction<u
 
     #[inline]
     fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
-        let cell = self.cells.partition(input);
+        self.evaluate_cell(&self.cells.partition(input), seeds)
+    }
+}
+
+impl<C: DomainCell, P, L: LengthFunction<C::Full>, N: NoiseFunction<u32>> CellFunction<C>
+    for PerNearestPoint<P, L, N>
+{
+    type Output = N::Output;
+
+    #[inline]
+    fn evaluate_cell(&self, cell: &C, seeds: &mut NoiseRng) -> Self::Output {
         let mut nearest_id = 0u32;
         let mut least_length_order = f32::INFINITY;
         for point in cell.iter_points(*seeds) {
@@ -117,430 +341,626 @@ impl<I: VectorSpace, L: LengthFunction<I>, P: Partitioner<I>, N: NoiseFunction<u
     }
 }
 
-/// A [`NoiseFunction`] partitions space by a [`Partitioner`] `P` (usually [`Voronoi`](crate::cells::Voronoi)) into a [`DomainCell`] and
-/// finds the distance to the nearest voronoi edge of according to some [`LengthFunction`] `L`.
-/// The result is a unorm f32.
-///
-/// If `APPROXIMATE` is on (defaults to false), this will be a cheaper, approximate, discontinuous distance to edge.
-/// If you need speed, and don't care about discontinuities or exactness, turn this on.
-///
-/// **Artifact Warning:** Depending on the [`LengthFunction`] `L`, this will create artifacting.
-/// Some of the math presumes a [`EuclideanLength`]. Other lengths still work, but may artifact.
-/// This is kept generic over `L` to enable custom functions that are
-/// similar enough to euclidean to not artifact and different enough to require a custom [`EuclideanLength`].
+/// A [`NoiseFunction`] that partitions space by a [`Partitioner`] `P` into [`DomainCell`]s and finds the nearest [`CellPoint`] by some [`LengthFunction`] `L`.
+/// The result includes both the point's [`rough_id`](CellPoint::rough_id) and the [`offset`](CellPoint::offset) of the sample from it.
 ///
-/// Here's an example:
+/// This is useful for custom cell shading that needs both, since it only partitions once.
+/// The id is the same one that [`PerNearestPoint`] passes to its [`NoiseFunction<u32>`].
 ///
 /// ```
 /// # use noiz::prelude::*;
-/// use noiz::cell_noise::DistanceToEdge;
-/// let noise = Noise::<DistanceToEdge<Voronoi>>::default();
-/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// use noiz::{cell_noise::NearestCellInfo, cells::CellPoint};
+/// let noise = Noise::<NearestCellInfo<Voronoi, EuclideanLength>>::default();
+/// let nearest = noise.sample_for::<CellPoint<bevy_math::Vec2>>(bevy_math::Vec2::ZERO);
+/// let distance = nearest.offset.length();
 /// ```
 #[derive(Default, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub struct DistanceToEdge<P, L = EuclideanLength, const APPROXIMATE: bool = false> {
+pub struct NearestCellInfo<P, L> {
     /// The [`Partitioner`].
     pub cells: P,
     /// The [`LengthFunction`].
     pub length_mode: L,
 }
 
-macro_rules! impl_distance_to_edge {
-    ($t:ty) => {
-        impl<L: LengthFunction<$t>, P: Partitioner<$t, Cell: WorleyDomainCell>> NoiseFunction<$t>
-            for DistanceToEdge<P, L, true>
-        {
-            type Output = f32;
-
-            #[inline]
-            fn evaluate(&self, input: $t, seeds: &mut NoiseRng) -> Self::Output {
-                let cell = self.cells.partition(input);
-
-                let mut least_length_order = f32::INFINITY;
-                let mut least_offset = <$t>::ZERO;
-                let mut next_least_length_order = f32::INFINITY;
-                let mut next_least_offset = <$t>::ZERO;
+impl<I: VectorSpace, L: LengthFunction<I>, P: Partitioner<I>> NoiseFunction<I>
+    for NearestCellInfo<P, L>
+{
+    type Output = CellPoint<I>;
 
-                for point in cell.iter_points(*seeds) {
-                    let length_order = self.length_mode.length_ordering(point.offset);
-                    if length_order < least_length_order {
-                        next_least_length_order = least_length_order;
-                        next_least_offset = least_offset;
-                        least_length_order = length_order;
-                        least_offset = point.offset;
-                    } else if length_order < next_least_length_order {
-                        next_least_length_order = length_order;
-                        next_least_offset = point.offset;
-                    }
-                }
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        self.evaluate_cell(&self.cells.partition(input), seeds)
+    }
+}
 
-                let to_other_point = least_offset - next_least_offset;
-                let dir_to_other = to_other_point.normalize();
-                let nearest_traveled_towards_other = dir_to_other * dir_to_other.dot(least_offset);
-                let nearest_traveled_to_edge = to_other_point * 0.5;
-                let sample_to_this_edge = nearest_traveled_to_edge - nearest_traveled_towards_other;
+impl<C: DomainCell, P, L: LengthFunction<C::Full>> CellFunction<C> for NearestCellInfo<P, L> {
+    type Output = CellPoint<C::Full>;
 
-                let dist = self.length_mode.length_of(sample_to_this_edge);
-                let max_dits = cell.next_nearest_1d_point_always_within();
-                dist / max_dits
+    #[inline]
+    fn evaluate_cell(&self, cell: &C, seeds: &mut NoiseRng) -> Self::Output {
+        let mut nearest = CellPoint {
+            rough_id: 0,
+            offset: C::Full::ZERO,
+        };
+        let mut least_length_order = f32::INFINITY;
+        for point in cell.iter_points(*seeds) {
+            let length_order = self.length_mode.length_ordering(point.offset);
+            if length_order < least_length_order {
+                least_length_order = length_order;
+                nearest = point;
             }
         }
+        nearest
+    }
+}
 
-        impl<L: LengthFunction<$t>, P: Partitioner<$t, Cell: WorleyDomainCell>> NoiseFunction<$t>
-            for DistanceToEdge<P, L, false>
-        {
-            type Output = f32;
+/// A [`NoiseFunction`] that partitions space by a [`Partitioner`] `P` into [`DomainCell`]s,
+/// and blends the [`NoiseFunction<u32>`] `N` of the nearest [`CellPoint`](crate::cells::CellPoint) with that of the next nearest, by some [`LengthFunction`] `L`.
+///
+/// This is a softened [`PerNearestPoint`]: values are still flat within most of each cell, but instead of jumping at borders, they fade into each other.
+/// The fade is a smoothstep of the ratio between the nearest and next nearest distances, which reaches an even mix exactly on the border.
+/// [`blend`](SmoothPerCell::blend) controls how far from the border the fade starts: 0 is a hard border, and 1 fades across the whole cell.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// use noiz::cell_noise::SmoothPerCell;
+/// let noise = Noise::<SmoothPerCell<Voronoi, EuclideanLength, Random<UNorm, f32>>>::default();
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct SmoothPerCell<P, L, N> {
+    /// The [`Partitioner`].
+    pub cells: P,
+    /// The [`LengthFunction`].
+    pub length_mode: L,
+    /// The [`NoiseFunction<u32>`].
+    pub noise: N,
+    /// How much of each cell, from 0 to 1, fades into its neighbors.
+    /// Defaults to 0.5.
+    pub blend: f32,
+}
 
-            #[inline]
-            fn evaluate(&self, input: $t, seeds: &mut NoiseRng) -> Self::Output {
-                let cell = self.cells.partition(input);
-                let mut nearest_offset = <$t>::ZERO;
-                let mut least_length_order = f32::INFINITY;
-                for point in cell.iter_points(*seeds) {
-                    let length_order = self.length_mode.length_ordering(point.offset);
-                    if length_order < least_length_order {
-                        least_length_order = length_order;
-                        nearest_offset = point.offset;
-                    }
-                }
+impl<P: Default, L: Default, N: Default> Default for SmoothPerCell<P, L, N> {
+    fn default() -> Self {
+        Self {
+            cells: P::default(),
+            length_mode: L::default(),
+            noise: N::default(),
+            blend: 0.5,
+        }
+    }
+}
 
-                let mut to_nearest_edge = <$t>::ZERO;
-                let mut to_nearest_edge_order = f32::INFINITY;
-                for point in cell.iter_points(*seeds) {
-                    let to_other_point = nearest_offset - point.offset;
-                    let Some(dir_to_other) = to_other_point.try_normalize() else {
-                        continue;
-                    };
-                    let nearest_traveled_towards_other =
-                        dir_to_other * dir_to_other.dot(nearest_offset);
-                    let nearest_traveled_to_edge = to_other_point * 0.5;
-                    let sample_to_this_edge =
-                        nearest_traveled_to_edge - nearest_traveled_towards_other;
+impl<
+    I: VectorSpace,
+    L: LengthFunction<I>,
+    P: Partitioner<I>,
+    N: NoiseFunction<u32, Output: VectorSpace>,
+> NoiseFunction<I> for SmoothPerCell<P, L, N>
+{
+    type Output = N::Output;
 
-                    let order = self.length_mode.length_ordering(sample_to_this_edge);
-                    if order < to_nearest_edge_order {
-                        to_nearest_edge_order = order;
-                        to_nearest_edge = sample_to_this_edge;
-                    }
-                }
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        self.evaluate_cell(&self.cells.partition(input), seeds)
+    }
+}
 
-                let dist = self.length_mode.length_of(to_nearest_edge);
-                let max_dits = cell.nearest_1d_point_always_within();
-                dist / max_dits
+impl<C: DomainCell, P, L: LengthFunction<C::Full>, N: NoiseFunction<u32, Output: VectorSpace>>
+    CellFunction<C> for SmoothPerCell<P, L, N>
+{
+    type Output = N::Output;
+
+    #[inline]
+    fn evaluate_cell(&self, cell: &C, seeds: &mut NoiseRng) -> Self::Output {
+        let mut nearest = (f32::INFINITY, 0u32);
+        let mut next_nearest = (f32::INFINITY, 0u32);
+        for point in cell.iter_points(*seeds) {
+            let length_order = self.length_mode.length_ordering(point.offset);
+            if length_order < nearest.0 {
+                next_nearest = nearest;
+                nearest = (length_order, point.rough_id);
+            } else if length_order < next_nearest.0 {
+                next_nearest = (length_order, point.rough_id);
             }
         }
-    };
-}
-
-impl_distance_to_edge!(Vec2);
-impl_distance_to_edge!(Vec3);
-impl_distance_to_edge!(Vec3A);
-impl_distance_to_edge!(Vec4);
-
-/// Represents a way to compute worley noise, noise based on the distances of [`CellPoint`](crate::cells::CellPoint)s to the sample point.
-/// This is designed for use in [`PerCellPointDistances`].
-pub trait WorleyMode {
-    /// Evaluates the result of this worley mode with the these offsets from the [`CellPoint`](crate::cells::CellPoint)s according to this [`LengthFunction`].
-    fn evaluate_worley<I: VectorSpace>(
-        &self,
-        points: impl Iterator<Item = I>,
-        lengths: &impl LengthFunction<I>,
-        max_least_length: f32,
-        max_next_least_length: f32,
-    ) -> f32;
-}
 
-/// Returns the least and then next least values of `vals`.
-#[inline]
-fn two_least(vals: impl Iterator<Item = f32>) -> (f32, f32) {
-    let mut least = f32::INFINITY;
-    let mut next_least = f32::INFINITY;
+        let ratio = self.length_mode.length_from_ordering(nearest.0)
+            / self.length_mode.length_from_ordering(next_nearest.0);
+        let t = ((ratio - 1.0) / self.blend.max(f32::EPSILON) + 1.0).clamp(0.0, 1.0);
+        let weight = Smoothstep.sample_unchecked(t) * 0.5;
 
-    for v in vals {
-        if v < least {
-            next_least = least;
-            least = v;
-        } else {
-            next_least = next_least.min(v);
-        }
+        let mut next_seeds = *seeds;
+        let next_value = self.noise.evaluate(next_nearest.1, &mut next_seeds);
+        let value = self.noise.evaluate(nearest.1, seeds);
+        value.lerp(next_value, weight)
     }
-
-    (least, next_least)
 }
 
-/// A [`WorleyMode`] that returns the unorm distance to the nearest [`CellPoint`](crate::cells::CellPoint) via a [`SmoothMin`].
-/// This is similar to [`WorleyLeastDistance`], but instead of dividing nearby cells, it smooths between them.
-/// Note that when cells are close together, this can merge them into a single value.
+/// A [`NoiseFunction`] that partitions space by a [`Partitioner`] `P` (usually [`Voronoi`](crate::cells::Voronoi)) into [`DomainCell`]s,
+/// and produces 1.0 within [`radius`](PointMask::radius) of the nearest [`CellPoint`](crate::cells::CellPoint) by some [`LengthFunction`] `L`, and 0.0 elsewhere.
+///
+/// This is a cheap way to scatter one dot per cell, for stippling, dithering, or placing objects.
+/// When the points are spread apart, as with a [`Voronoi`](crate::cells::Voronoi) randomness below 1, the dots approximate a blue noise, poisson disk distribution.
 ///
 /// ```
 /// # use noiz::prelude::*;
-/// use noiz::cell_noise::WorleySmoothMin;
-/// use noiz::curves::CubicSMin;
-/// let noise = Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleySmoothMin<CubicSMin>>>::default();
+/// use noiz::cell_noise::PointMask;
+/// let noise = Noise::<PointMask<Voronoi>>::default();
 /// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
 /// ```
 #[derive(Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub struct WorleySmoothMin<T> {
-    /// The [`SmoothMin`].
-    pub smooth_min: T,
-    /// The inverse of the radius to smooth cells together.
-    /// Positive values between 0 and 1 are recommended.
-    pub smoothing_inverse_radius: f32,
+pub struct PointMask<P, L = EuclideanLength> {
+    /// The [`Partitioner`].
+    pub cells: P,
+    /// The [`LengthFunction`].
+    pub length_mode: L,
+    /// The radius of each dot, in the scale of the cells.
+    /// Defaults to 0.1.
+    pub radius: f32,
 }
 
-impl<T: Default> Default for WorleySmoothMin<T> {
+impl<P: Default, L: Default> Default for PointMask<P, L> {
     fn default() -> Self {
         Self {
-            smooth_min: T::default(),
-            smoothing_inverse_radius: 1.0 / 16.0,
+            cells: P::default(),
+            length_mode: L::default(),
+            radius: 0.1,
         }
     }
 }
 
-impl<T: SmoothMin> WorleyMode for WorleySmoothMin<T> {
+impl<I: VectorSpace, L: LengthFunction<I>, P: Partitioner<I>> NoiseFunction<I> for PointMask<P, L> {
+    type Output = f32;
+
     #[inline]
-    fn evaluate_worley<I: VectorSpace>(
-        &self,
-        points: impl Iterator<Item = I>,
-        lengths: &impl LengthFunction<I>,
-        _max_least_length: f32,
-        _max_next_least_length: f32,
-    ) -> f32 {
-        let mut res = f32::INFINITY;
-        for p in points {
-            res = self.smooth_min.smin_norm(
-                res,
-                lengths.length_ordering(p),
-                self.smoothing_inverse_radius,
-            );
-        }
-        lengths.length_from_ordering(res)
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        self.evaluate_cell(&self.cells.partition(input), seeds)
     }
 }
 
-/// A [`WorleyMode`] that returns the unorm distance to the nearest [`CellPoint`](crate::cells::CellPoint) via a [`SmoothMin`].
-/// This is similar to [`WorleySmoothMin`], but instead smoothing every cell, it smooths the nearest two points.
-/// Note that when cells are close together, this can merge them into a single value.
+impl<C: DomainCell, P, L: LengthFunction<C::Full>> CellFunction<C> for PointMask<P, L> {
+    type Output = f32;
+
+    #[inline]
+    fn evaluate_cell(&self, cell: &C, seeds: &mut NoiseRng) -> Self::Output {
+        let within = cell
+            .iter_points(*seeds)
+            .any(|point| self.length_mode.length_of(point.offset) <= self.radius);
+        if within { 1.0 } else { 0.0 }
+    }
+}
+
+impl<P, L> NoiseRange for PointMask<P, L> {
+    #[inline]
+    fn output_range(&self, _input: OutputRange) -> OutputRange {
+        OutputRange::UNORM
+    }
+}
+
+/// A [`NoiseFunction`] that partitions space by a [`Partitioner`] `P` (usually [`Voronoi`](crate::cells::Voronoi)) into [`DomainCell`]s,
+/// and sums the brightness of every nearby [`CellPoint`](crate::cells::CellPoint), which falls off linearly to 0 at a distance of [`falloff`](AdditiveWorley::falloff) by some [`LengthFunction`] `L`.
+///
+/// Unlike traditional worley noise, which only considers the nearest point, nearby points add together, making glowing clusters for starfields and sparks.
+/// Each isolated point peaks at 1.0, but where points overlap, the result can exceed 1.0.
+///
+/// The `falloff` should not exceed the distance the partitioner always searches, or points will cut off at cell borders.
+/// For the default [`Voronoi`](crate::cells::Voronoi), that is 1.0.
 ///
 /// ```
 /// # use noiz::prelude::*;
-/// use noiz::cell_noise::WorleyNearestSmoothMin;
-/// use noiz::curves::CubicSMin;
-/// let noise = Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleyNearestSmoothMin<CubicSMin>>>::default();
+/// use noiz::cell_noise::AdditiveWorley;
+/// let noise = Noise::<AdditiveWorley<Voronoi>>::default();
 /// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
 /// ```
 #[derive(Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub struct WorleyNearestSmoothMin<T> {
-    /// The [`SmoothMin`].
-    pub smooth_min: T,
-    /// The inverse of the radius to smooth cells together.
-    /// Positive values between 0 and 1 are recommended.
-    pub smoothing_inverse_radius: f32,
+pub struct AdditiveWorley<P, L = EuclideanLength> {
+    /// The [`Partitioner`].
+    pub cells: P,
+    /// The [`LengthFunction`].
+    pub length_mode: L,
+    /// The distance from a point at which its brightness reaches 0.
+    pub falloff: f32,
 }
 
-impl<T: Default> Default for WorleyNearestSmoothMin<T> {
+impl<P: Default, L: Default> Default for AdditiveWorley<P, L> {
     fn default() -> Self {
         Self {
-            smooth_min: T::default(),
-            smoothing_inverse_radius: 1.0 / 16.0,
+            cells: P::default(),
+            length_mode: L::default(),
+            falloff: 0.5,
         }
     }
 }
 
-impl<T: SmoothMin> WorleyMode for WorleyNearestSmoothMin<T> {
+impl<I: VectorSpace, L: LengthFunction<I>, P: Partitioner<I>> NoiseFunction<I>
+    for AdditiveWorley<P, L>
+{
+    type Output = f32;
+
     #[inline]
-    fn evaluate_worley<I: VectorSpace>(
-        &self,
-        points: impl Iterator<Item = I>,
-        lengths: &impl LengthFunction<I>,
-        _max_least_length: f32,
-        _max_next_least_length: f32,
-    ) -> f32 {
-        let (least, next_least) = two_least(points.map(|p| lengths.length_ordering(p)));
-        lengths.length_from_ordering(self.smooth_min.smin_norm(
-            lengths.length_from_ordering(least),
-            lengths.length_from_ordering(next_least),
-            self.smoothing_inverse_radius,
-        ))
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        self.evaluate_cell(&self.cells.partition(input), seeds)
     }
 }
 
-/// A [`WorleyMode`] that returns the unorm distance to the nearest [`CellPoint`](crate::cells::CellPoint).
-/// This is traditional worley noise.
+impl<C: DomainCell, P, L: LengthFunction<C::Full>> CellFunction<C> for AdditiveWorley<P, L> {
+    type Output = f32;
+
+    #[inline]
+    fn evaluate_cell(&self, cell: &C, seeds: &mut NoiseRng) -> Self::Output {
+        cell.iter_points(*seeds)
+            .map(|point| (1.0 - self.length_mode.length_of(point.offset) / self.falloff).max(0.0))
+            .sum()
+    }
+}
+
+/// A [`NoiseFunction`] that partitions space by a [`Partitioner`] `P` (usually [`Voronoi`]) into [`DomainCell`]s,
+/// and shapes the distance to the nearest [`CellPoint`](crate::cells::CellPoint) by some [`LengthFunction`] `L` into a crater, for planetary surfaces.
+///
+/// Each crater has a bowl shaped floor, [`depth`](Craters::depth) deep at its center, rising to a raised [`rim`](Craters::rim) at its [`radius`](Craters::radius).
+/// Outside the rim, the height smoothly falls back to 0 at twice the radius, where the surface is flat.
+/// For that flat exterior to be continuous, twice the radius should not exceed the distance the partitioner always searches, which is 1.0 for the default [`Voronoi`].
 ///
 /// ```
 /// # use noiz::prelude::*;
-/// let noise = Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleyLeastDistance>>::default();
+/// use noiz::cell_noise::Craters;
+/// let noise = Noise::<Craters<Voronoi>>::default();
 /// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
 /// ```
-#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub struct WorleyLeastDistance;
+pub struct Craters<P, L = EuclideanLength> {
+    /// The [`Partitioner`].
+    pub cells: P,
+    /// The [`LengthFunction`].
+    pub length_mode: L,
+    /// The distance from the center of each crater to its rim.
+    pub radius: f32,
+    /// The height of the rim above the flat exterior.
+    pub rim: f32,
+    /// The depth of the center of each crater below the flat exterior.
+    pub depth: f32,
+}
 
-impl WorleyMode for WorleyLeastDistance {
+impl<P: Default, L: Default> Default for Craters<P, L> {
+    fn default() -> Self {
+        Self {
+            cells: P::default(),
+            length_mode: L::default(),
+            radius: 0.3,
+            rim: 0.2,
+            depth: 1.0,
+        }
+    }
+}
+
+impl<P, L> Craters<P, L> {
+    /// Computes the height of a crater at this `distance` from its center.
     #[inline]
-    fn evaluate_worley<I: VectorSpace>(
-        &self,
-        points: impl Iterator<Item = I>,
-        lengths: &impl LengthFunction<I>,
-        max_least_length: f32,
-        _max_next_least_length: f32,
-    ) -> f32 {
-        let mut res = f32::INFINITY;
-        for p in points {
-            res = res.min(lengths.length_ordering(p));
+    pub fn profile(&self, distance: f32) -> f32 {
+        let t = distance / self.radius;
+        if t < 1.0 {
+            -self.depth + (self.rim + self.depth) * t * t
+        } else {
+            self.rim * (1.0 - Smoothstep.sample_clamped(t - 1.0))
         }
-        lengths.length_from_ordering(res) / max_least_length
     }
 }
 
-/// A [`WorleyMode`] that returns the unorm distance to the second nearest [`CellPoint`](crate::cells::CellPoint).
-/// This will have artifacts when using `HALF_SCALE` on [`Voronoi`](crate::cells::Voronoi).
-///
-/// ```
-/// # use noiz::prelude::*;
-/// use noiz::cell_noise::WorleySecondLeastDistance;
-/// let noise = Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleySecondLeastDistance>>::default();
-/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
-/// ```
-#[derive(Default, Clone, Copy, PartialEq, Eq)]
-#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
-#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
-#[cfg_attr(feature = "debug", derive(Debug))]
-pub struct WorleySecondLeastDistance;
+impl<I: VectorSpace, L: LengthFunction<I>, P: Partitioner<I>> NoiseFunction<I> for Craters<P, L> {
+    type Output = f32;
 
-impl WorleyMode for WorleySecondLeastDistance {
     #[inline]
-    fn evaluate_worley<I: VectorSpace>(
-        &self,
-        points: impl Iterator<Item = I>,
-        lengths: &impl LengthFunction<I>,
-        _max_least_length: f32,
-        max_next_least_length: f32,
-    ) -> f32 {
-        let (_least, next_least) = two_least(points.map(|p| lengths.length_ordering(p)));
-        lengths.length_from_ordering(next_least) / max_next_least_length
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        self.evaluate_cell(&self.cells.partition(input), seeds)
     }
 }
 
-/// A [`WorleyMode`] that returns the unorm difference between the first and second nearest [`CellPoint`](crate::cells::CellPoint).
-/// This will have artifacts when using `HALF_SCALE` on [`Voronoi`](crate::cells::Voronoi).
-///
-/// ```
-/// # use noiz::prelude::*;
-/// use noiz::cell_noise::WorleyDifference;
-/// let noise = Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleyDifference>>::default();
-/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
-/// ```
-#[derive(Default, Clone, Copy, PartialEq, Eq)]
-#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
-#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
-#[cfg_attr(feature = "debug", derive(Debug))]
-pub struct WorleyDifference;
+impl<C: DomainCell, P, L: LengthFunction<C::Full>> CellFunction<C> for Craters<P, L> {
+    type Output = f32;
 
-impl WorleyMode for WorleyDifference {
     #[inline]
-    fn evaluate_worley<I: VectorSpace>(
-        &self,
-        points: impl Iterator<Item = I>,
-        lengths: &impl LengthFunction<I>,
-        _max_least_length: f32,
-        max_next_least_length: f32,
-    ) -> f32 {
-        let (least, next_least) = two_least(points.map(|p| lengths.length_ordering(p)));
-        (lengths.length_from_ordering(next_least) - lengths.length_from_ordering(least))
-            / max_next_least_length
+    fn evaluate_cell(&self, cell: &C, seeds: &mut NoiseRng) -> Self::Output {
+        let least_length_order = cell
+            .iter_points(*seeds)
+            .map(|point| self.length_mode.length_ordering(point.offset))
+            .fold(f32::INFINITY, f32::min);
+        self.profile(self.length_mode.length_from_ordering(least_length_order))
     }
 }
 
-/// A [`WorleyMode`] that returns the unorm average of the first and second nearest [`CellPoint`](crate::cells::CellPoint).
-/// This will have artifacts when using `HALF_SCALE` on [`Voronoi`](crate::cells::Voronoi).
+impl<P, L> NoiseRange for Craters<P, L> {
+    #[inline]
+    fn output_range(&self, _input: OutputRange) -> OutputRange {
+        OutputRange {
+            min: -self.depth,
+            max: self.rim,
+        }
+    }
+}
+
+/// A [`NoiseFunction`] partitions space by a [`Partitioner`] `P` (usually [`Voronoi`](crate::cells::Voronoi)) into a [`DomainCell`] and
+/// finds the distance to the nearest voronoi edge of according to some [`LengthFunction`] `L`.
+/// The result is a unorm f32.
+///
+/// If `APPROXIMATE` is on (defaults to false), this will be a cheaper, approximate, discontinuous distance to edge.
+/// If you need speed, and don't care about discontinuities or exactness, turn this on.
+///
+/// **Artifact Warning:** Depending on the [`LengthFunction`] `L`, this will create artifacting.
+/// Some of the math presumes a [`EuclideanLength`]. Other lengths still work, but may artifact.
+/// This is kept generic over `L` to enable custom functions that are
+/// similar enough to euclidean to not artifact and different enough to require a custom [`EuclideanLength`].
+///
+/// Here's an example:
 ///
 /// ```
 /// # use noiz::prelude::*;
-/// use noiz::cell_noise::WorleyAverage;
-/// let noise = Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleyAverage>>::default();
+/// use noiz::cell_noise::DistanceToEdge;
+/// let noise = Noise::<DistanceToEdge<Voronoi>>::default();
 /// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
 /// ```
-#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Default, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub struct WorleyAverage;
+pub struct DistanceToEdge<P, L = EuclideanLength, const APPROXIMATE: bool = false> {
+    /// The [`Partitioner`].
+    pub cells: P,
+    /// The [`LengthFunction`].
+    pub length_mode: L,
+}
 
-impl WorleyMode for WorleyAverage {
-    #[inline]
-    fn evaluate_worley<I: VectorSpace>(
-        &self,
-        points: impl Iterator<Item = I>,
-        lengths: &impl LengthFunction<I>,
-        max_least_length: f32,
-        max_next_least_length: f32,
-    ) -> f32 {
-        let (least, next_least) = two_least(points.map(|p| lengths.length_ordering(p)));
-        (lengths.length_from_ordering(next_least) / max_next_least_length
-            + lengths.length_from_ordering(least) / max_least_length)
-            * 0.5
-    }
+macro_rules! impl_distance_to_edge {
+    ($t:ty) => {
+        impl<L: LengthFunction<$t>, P: Partitioner<$t, Cell: WorleyDomainCell>> NoiseFunction<$t>
+            for DistanceToEdge<P, L, true>
+        {
+            type Output = f32;
+
+            #[inline]
+            fn evaluate(&self, input: $t, seeds: &mut NoiseRng) -> Self::Output {
+                let cell = self.cells.partition(input);
+
+                let mut least_length_order = f32::INFINITY;
+                let mut least_offset = <$t>::ZERO;
+                let mut next_least_length_order = f32::INFINITY;
+                let mut next_least_offset = <$t>::ZERO;
+
+                for point in cell.iter_points(*seeds) {
+                    let length_order = self.length_mode.length_ordering(point.offset);
+                    if length_order < least_length_order {
+                        next_least_length_order = least_length_order;
+                        next_least_offset = least_offset;
+                        least_length_order = length_order;
+                        least_offset = point.offset;
+                    } else if length_order < next_least_length_order {
+                        next_least_length_order = length_order;
+                        next_least_offset = point.offset;
+                    }
+                }
+
+                let to_other_point = least_offset - next_least_offset;
+                let dir_to_other = to_other_point.normalize();
+                let nearest_traveled_towards_other = dir_to_other * dir_to_other.dot(least_offset);
+                let nearest_traveled_to_edge = to_other_point * 0.5;
+                let sample_to_this_edge = nearest_traveled_to_edge - nearest_traveled_towards_other;
+
+                let dist = self.length_mode.length_of(sample_to_this_edge);
+                let max_dits = cell.next_nearest_1d_point_always_within();
+                dist / max_dits
+            }
+        }
+
+        impl<L: LengthFunction<$t>, P: Partitioner<$t, Cell: WorleyDomainCell>> NoiseFunction<$t>
+            for DistanceToEdge<P, L, false>
+        {
+            type Output = f32;
+
+            #[inline]
+            fn evaluate(&self, input: $t, seeds: &mut NoiseRng) -> Self::Output {
+                let cell = self.cells.partition(input);
+                let mut nearest_offset = <$t>::ZERO;
+                let mut least_length_order = f32::INFINITY;
+                for point in cell.iter_points(*seeds) {
+                    let length_order = self.length_mode.length_ordering(point.offset);
+                    if length_order < least_length_order {
+                        least_length_order = length_order;
+                        nearest_offset = point.offset;
+                    }
+                }
+
+                let mut to_nearest_edge = <$t>::ZERO;
+                let mut to_nearest_edge_order = f32::INFINITY;
+                for point in cell.iter_points(*seeds) {
+                    let to_other_point = nearest_offset - point.offset;
+                    let Some(dir_to_other) = to_other_point.try_normalize() else {
+                        continue;
+                    };
+                    let nearest_traveled_towards_other =
+                        dir_to_other * dir_to_other.dot(nearest_offset);
+                    let nearest_traveled_to_edge = to_other_point * 0.5;
+                    let sample_to_this_edge =
+                        nearest_traveled_to_edge - nearest_traveled_towards_other;
+
+                    let order = self.length_mode.length_ordering(sample_to_this_edge);
+                    if order < to_nearest_edge_order {
+                        to_nearest_edge_order = order;
+                        to_nearest_edge = sample_to_this_edge;
+                    }
+                }
+
+                let dist = self.length_mode.length_of(to_nearest_edge);
+                let max_dits = cell.nearest_1d_point_always_within();
+                dist / max_dits
+            }
+        }
+    };
 }
 
-/// A [`WorleyMode`] that returns the unorm product between the first and second nearest [`CellPoint`](crate::cells::CellPoint).
-/// This will have artifacts when using `HALF_SCALE` on [`Voronoi`](crate::cells::Voronoi).
+impl_distance_to_edge!(Vec2);
+impl_distance_to_edge!(Vec3);
+impl_distance_to_edge!(Vec3A);
+impl_distance_to_edge!(Vec4);
+
+/// A [`NoiseFunction`] that produces a crisp mask of the edges found by a [`DistanceToEdge`].
+/// The result is 1.0 within [`width`](CellBorder::width) of an edge and 0.0 elsewhere.
+/// This is useful for stylized cell borders, like hex grid overlays and stained glass.
+///
+/// The `width` is in the same units as the result of [`DistanceToEdge`], so it is relative to the size of the cells.
 ///
 /// ```
 /// # use noiz::prelude::*;
-/// use noiz::cell_noise::WorleyProduct;
-/// let noise = Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleyProduct>>::default();
+/// use noiz::cell_noise::CellBorder;
+/// let noise = Noise::<CellBorder<Voronoi>>::default();
 /// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
 /// ```
-#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub struct WorleyProduct;
+pub struct CellBorder<P, L = EuclideanLength> {
+    /// The [`DistanceToEdge`] that finds the edges.
+    pub edges: DistanceToEdge<P, L>,
+    /// How close to an edge a sample must be to be part of the border.
+    pub width: f32,
+}
+
+impl<P: Default, L: Default> Default for CellBorder<P, L> {
+    fn default() -> Self {
+        Self {
+            edges: DistanceToEdge::default(),
+            width: 0.05,
+        }
+    }
+}
+
+impl<I, P, L> NoiseFunction<I> for CellBorder<P, L>
+where
+    DistanceToEdge<P, L>: NoiseFunction<I, Output = f32>,
+{
+    type Output = f32;
 
-impl WorleyMode for WorleyProduct {
     #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        if self.edges.evaluate(input, seeds) <= self.width {
+            1.0
+        } else {
+            0.0
+        }
+    }
+}
+
+impl<P, L> NoiseRange for CellBorder<P, L> {
+    #[inline]
+    fn output_range(&self, _input: OutputRange) -> OutputRange {
+        OutputRange::UNORM
+    }
+}
+
+/// Represents a way to compute worley noise, noise based on the distances of [`CellPoint`](crate::cells::CellPoint)s to the sample point.
+/// This is designed for use in [`PerCellPointDistances`].
+pub trait WorleyMode {
+    /// Evaluates the result of this worley mode with the these offsets from the [`CellPoint`](crate::cells::CellPoint)s according to this [`LengthFunction`].
     fn evaluate_worley<I: VectorSpace>(
         &self,
         points: impl Iterator<Item = I>,
         lengths: &impl LengthFunction<I>,
         max_least_length: f32,
         max_next_least_length: f32,
-    ) -> f32 {
-        let (least, next_least) = two_least(points.map(|p| lengths.length_ordering(p)));
-        (lengths.length_from_ordering(next_least) * lengths.length_from_ordering(least))
-            / (max_least_length * max_next_least_length)
+    ) -> f32;
+
+    /// The range of values [`evaluate_worley`](WorleyMode::evaluate_worley) can produce.
+    /// This is used for the [`NoiseRange`] of worley noise.
+    /// Defaults to [`OutputRange::UNKNOWN`].
+    #[inline]
+    fn output_range(&self) -> OutputRange {
+        OutputRange::UNKNOWN
     }
 }
 
-/// A [`WorleyMode`] that returns the unorm ratio between the first and second nearest [`CellPoint`](crate::cells::CellPoint).
-/// This will have artifacts when using `HALF_SCALE` on [`Voronoi`](crate::cells::Voronoi).
+/// Returns the least and then next least values of `vals`.
+#[inline]
+fn two_least(vals: impl Iterator<Item = f32>) -> (f32, f32) {
+    let mut least = f32::INFINITY;
+    let mut next_least = f32::INFINITY;
+
+    for v in vals {
+        if v < least {
+            next_least = least;
+            least = v;
+        } else {
+            next_least = next_least.min(v);
+        }
+    }
+
+    (least, next_least)
+}
+
+/// Returns the `N` least values of `vals`, sorted from least to greatest.
+/// If there are fewer than `N` values, the rest will be infinity.
+#[inline]
+fn n_least<const N: usize>(vals: impl Iterator<Item = f32>) -> [f32; N] {
+    let mut least = [f32::INFINITY; N];
+
+    for mut v in vals {
+        for slot in least.iter_mut() {
+            if v < *slot {
+                core::mem::swap(slot, &mut v);
+            }
+        }
+    }
+
+    least
+}
+
+/// A [`WorleyMode`] that returns the unorm distance to the nearest [`CellPoint`](crate::cells::CellPoint) via a [`SmoothMin`].
+/// This is similar to [`WorleyLeastDistance`], but instead of dividing nearby cells, it smooths between them.
+/// Note that when cells are close together, this can merge them into a single value.
 ///
 /// ```
 /// # use noiz::prelude::*;
-/// use noiz::cell_noise::WorleyRatio;
-/// let noise = Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleyRatio>>::default();
+/// use noiz::cell_noise::WorleySmoothMin;
+/// use noiz::curves::CubicSMin;
+/// let noise = Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleySmoothMin<CubicSMin>>>::default();
 /// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
 /// ```
-#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub struct WorleyRatio;
+pub struct WorleySmoothMin<T> {
+    /// The [`SmoothMin`].
+    pub smooth_min: T,
+    /// The inverse of the radius to smooth cells together.
+    /// Positive values between 0 and 1 are recommended.
+    pub smoothing_inverse_radius: f32,
+}
 
-impl WorleyMode for WorleyRatio {
+impl<T: Default> Default for WorleySmoothMin<T> {
+    fn default() -> Self {
+        Self {
+            smooth_min: T::default(),
+            smoothing_inverse_radius: 1.0 / 16.0,
+        }
+    }
+}
+
+impl<T: SmoothMin> WorleyMode for WorleySmoothMin<T> {
     #[inline]
     fn evaluate_worley<I: VectorSpace>(
         &self,
@@ -549,1122 +969,3972 @@ impl WorleyMode for WorleyRatio {
         _max_least_length: f32,
         _max_next_least_length: f32,
     ) -> f32 {
-        let (least, next_least) = two_least(points.map(|p| lengths.length_ordering(p)));
-        // For this to be a division by zero, the points would need to be ontop of eachother, which is impossible.
-        lengths.length_from_ordering(least) / lengths.length_from_ordering(next_least)
+        let mut res = f32::INFINITY;
+        for p in points {
+            res = self.smooth_min.smin_norm(
+                res,
+                lengths.length_ordering(p),
+                self.smoothing_inverse_radius,
+            );
+        }
+        lengths.length_from_ordering(res)
     }
 }
 
-/// A [`NoiseFunction`] that partitions space by some [`Partitioner`] `P` into [`DomainCell`]s,
-/// and then provides the distance to each [`CellPoint`](crate::cells::CellPoint)s to some [`WorleyMode`] `M` by some [`LengthFunction`] `L`.
-///
-/// You can use this to make worley noise:
+/// A [`WorleyMode`] that returns the unorm distance to the nearest [`CellPoint`](crate::cells::CellPoint) via a [`SmoothMin`].
+/// This is similar to [`WorleySmoothMin`], but instead smoothing every cell, it smooths the nearest two points.
+/// Note that when cells are close together, this can merge them into a single value.
 ///
 /// ```
 /// # use noiz::prelude::*;
-/// let noise = Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleyLeastDistance>>::default();
+/// use noiz::cell_noise::WorleyNearestSmoothMin;
+/// use noiz::curves::CubicSMin;
+/// let noise = Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleyNearestSmoothMin<CubicSMin>>>::default();
 /// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
 /// ```
-///
-/// Lots of noise types are available. See also [`WorleyMode`], [`WorleyLeastDistance`], [`WorleyDifference`], etc.
-/// This is not explicitly called `Worley` because it doesn't cover every type of worley noise, for example, [`DistanceToEdge`].
-#[derive(Default, Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub struct PerCellPointDistances<P, L, W> {
-    /// The [`Partitioner`].
-    pub cells: P,
-    /// The [`LengthFunction`].
-    pub length_mode: L,
-    /// The [`WorleyMode`].
-    pub worley_mode: W,
+pub struct WorleyNearestSmoothMin<T> {
+    /// The [`SmoothMin`].
+    pub smooth_min: T,
+    /// The inverse of the radius to smooth cells together.
+    /// Positive values between 0 and 1 are recommended.
+    pub smoothing_inverse_radius: f32,
 }
 
-impl<I: VectorSpace, L: LengthFunction<I>, P: Partitioner<I, Cell: WorleyDomainCell>, W: WorleyMode>
-    NoiseFunction<I> for PerCellPointDistances<P, L, W>
-{
-    type Output = f32;
+impl<T: Default> Default for WorleyNearestSmoothMin<T> {
+    fn default() -> Self {
+        Self {
+            smooth_min: T::default(),
+            smoothing_inverse_radius: 1.0 / 16.0,
+        }
+    }
+}
 
+impl<T: SmoothMin> WorleyMode for WorleyNearestSmoothMin<T> {
     #[inline]
-    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
-        let cell = self.cells.partition(input);
-        let max_least_length = self
-            .length_mode
-            .max_for_element_max(cell.nearest_1d_point_always_within());
-        let max_next_least_length = self
-            .length_mode
-            .max_for_element_max(cell.next_nearest_1d_point_always_within());
-
-        self.worley_mode.evaluate_worley(
-            cell.iter_points(*seeds).map(|p| p.offset),
-            &self.length_mode,
-            max_least_length,
-            max_next_least_length,
-        )
+    fn evaluate_worley<I: VectorSpace>(
+        &self,
+        points: impl Iterator<Item = I>,
+        lengths: &impl LengthFunction<I>,
+        _max_least_length: f32,
+        _max_next_least_length: f32,
+    ) -> f32 {
+        let (least, next_least) = two_least(points.map(|p| lengths.length_ordering(p)));
+        lengths.length_from_ordering(self.smooth_min.smin_norm(
+            lengths.length_from_ordering(least),
+            lengths.length_from_ordering(next_least),
+            self.smoothing_inverse_radius,
+        ))
     }
 }
 
-/// A [`NoiseFunction`] that mixes a value sourced from a [`ConcreteAnyValueFromBits`] `N` by a [`Curve`] `C` within some [`DomainCell`] form a [`Partitioner`] `P`.
-///
-/// Usually, the [`ConcreteAnyValueFromBits`] will be a [`Random`](crate::rng::Random), ex `Random<UNorm, f32>`.
-///
-/// Here's an example of linear value noise:
-///
-/// ```
-/// # use noiz::prelude::*;
-/// let noise = Noise::<MixCellValues<OrthoGrid, Linear, Random<SNorm, f32>>>::default();
-/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
-/// ```
-///
-/// Usually linear doesn't look very good, so here's smoothstep:
-///
-/// ```
-/// # use noiz::prelude::*;
-/// let noise = Noise::<MixCellValues<OrthoGrid, Smoothstep, Random<SNorm, f32>>>::default();
-/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
-/// ```
-///
-/// If you are interested in calculating the gradient of the noise as well, turn on `DIFFERENTIATE` (off by default).
+/// A [`WorleyMode`] that returns the unorm distance to the nearest [`CellPoint`](crate::cells::CellPoint) via a [`SmoothMin`].
+/// This is similar to [`WorleyNearestSmoothMin`], but instead of smoothing the nearest two points, it smooths the nearest `N` points.
+/// This rounds off junctions where more than two cells meet, which would otherwise be faceted.
+/// Note that when cells are close together, this can merge them into a single value.
 ///
 /// ```
 /// # use noiz::prelude::*;
-/// let noise = Noise::<MixCellValues<OrthoGrid, Smoothstep, Random<SNorm, f32>, true>>::default();
+/// use noiz::cell_noise::WorleyNearestSmoothMinN;
+/// use noiz::curves::CubicSMin;
+/// let noise = Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleyNearestSmoothMinN<CubicSMin, 3>>>::default();
 /// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
 /// ```
-///
-/// This is typically used with [`NormedByDerivative`](crate::layering::NormedByDerivative).
-#[derive(Default, Clone, Copy, PartialEq)]
+#[derive(Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub struct MixCellValues<P, C, N, const DIFFERENTIATE: bool = false> {
-    /// The [`Partitioner`].
-    pub cells: P,
-    /// The [`ConcreteAnyValueFromBits`].
-    pub noise: N,
-    /// The [`Curve`].
-    pub curve: C,
+pub struct WorleyNearestSmoothMinN<T, const N: usize = 3> {
+    /// The [`SmoothMin`].
+    pub smooth_min: T,
+    /// The inverse of the radius to smooth cells together.
+    /// Positive values between 0 and 1 are recommended.
+    pub smoothing_inverse_radius: f32,
 }
 
-impl<
-    I: VectorSpace,
-    P: Partitioner<I, Cell: InterpolatableCell>,
-    C: Curve<f32>,
-    N: ConcreteAnyValueFromBits<Concrete: VectorSpace>,
-> NoiseFunction<I> for MixCellValues<P, C, N, false>
-{
-    type Output = N::Concrete;
-
-    #[inline]
-    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
-        let segment = self.cells.partition(input);
-        let raw = segment.interpolate_within(
-            *seeds,
-            |point| self.noise.linear_equivalent_value(point.rough_id),
-            &self.curve,
-        );
-        self.noise.finish_linear_equivalent_value(raw)
+impl<T: Default, const N: usize> Default for WorleyNearestSmoothMinN<T, N> {
+    fn default() -> Self {
+        Self {
+            smooth_min: T::default(),
+            smoothing_inverse_radius: 1.0 / 16.0,
+        }
     }
 }
 
-impl<
-    I: VectorSpace,
-    P: Partitioner<I, Cell: DifferentiableCell>,
-    C: SampleDerivative<f32>,
-    N: ConcreteAnyValueFromBits<Concrete: VectorSpace>,
-> NoiseFunction<I> for MixCellValues<P, C, N, true>
-{
-    type Output = WithGradient<N::Concrete, <P::Cell as DifferentiableCell>::Gradient<N::Concrete>>;
-
+impl<T: SmoothMin, const N: usize> WorleyMode for WorleyNearestSmoothMinN<T, N> {
     #[inline]
-    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
-        let segment = self.cells.partition(input);
-        let WithGradient { value, gradient } = segment.interpolate_with_gradient(
-            *seeds,
-            |point| self.noise.linear_equivalent_value(point.rough_id),
-            &self.curve,
-            self.noise.finishing_derivative(),
-        );
-        WithGradient {
-            value: self.noise.finish_linear_equivalent_value(value),
-            gradient,
+    fn evaluate_worley<I: VectorSpace>(
+        &self,
+        points: impl Iterator<Item = I>,
+        lengths: &impl LengthFunction<I>,
+        _max_least_length: f32,
+        _max_next_least_length: f32,
+    ) -> f32 {
+        let nearest = n_least::<N>(points.map(|p| lengths.length_ordering(p)));
+        let mut nearest = nearest
+            .into_iter()
+            .take_while(|ordering| ordering.is_finite())
+            .map(|ordering| lengths.length_from_ordering(ordering));
+        let Some(mut res) = nearest.next() else {
+            return f32::INFINITY;
+        };
+        for length in nearest {
+            res = self
+                .smooth_min
+                .smin_norm(res, length, self.smoothing_inverse_radius);
         }
+        res
     }
 }
 
-/// A [`NoiseFunction`] that mixes a value sourced from a [`AnyValueFromBits`] `N` by a [`Curve`] `C` within some [`DomainCell`] form a [`Partitioner`] `P`.
-///
-/// This is similar to [`MixCellValues`] but more restricted. Instead of taking a [`ConcreteAnyValueFromBits`], this takes the more general [`AnyValueFromBits`].
-/// That allows the output of the noise to depend on the input's type!
-///
-/// This is most commonly used for very fast domain warping:
+/// A [`WorleyMode`] that returns the unorm distance to the nearest [`CellPoint`](crate::cells::CellPoint).
+/// This is traditional worley noise.
 ///
 /// ```
 /// # use noiz::prelude::*;
-/// use noiz::cell_noise::MixCellValuesForDomain;
-/// let noise = Noise::<(
-///     Offset<MixCellValuesForDomain<OrthoGrid, Smoothstep, SNorm>>,
-///     common_noise::Perlin,
-/// )>::default();
+/// let noise = Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleyLeastDistance>>::default();
 /// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
 /// ```
-///
-/// See also [`DomainWarp`](crate::layering::DomainWarp) and [`RandomElements`](crate::misc_noise::RandomElements) as alternatives.
-///
-/// This also supports `DIFFERENTIATE` (off by default) similar to [`MixCellValues`], but this is not as useful here.
-#[derive(Default, Clone, Copy, PartialEq)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub struct MixCellValuesForDomain<P, C, N, const DIFFERENTIATE: bool = false> {
-    /// The [`Partitioner`].
-    pub cells: P,
-    /// The [`ConcreteAnyValueFromBits`].
-    pub noise: N,
-    /// The [`Curve`].
-    pub curve: C,
-}
-
-impl<
-    I: VectorSpace,
-    P: Partitioner<I, Cell: InterpolatableCell>,
-    C: Curve<f32>,
-    N: AnyValueFromBits<I>,
-> NoiseFunction<I> for MixCellValuesForDomain<P, C, N, false>
-{
-    type Output = I;
+pub struct WorleyLeastDistance;
 
+impl WorleyMode for WorleyLeastDistance {
     #[inline]
-    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
-        let segment = self.cells.partition(input);
-        let raw = segment.interpolate_within(
-            *seeds,
-            |point| self.noise.linear_equivalent_value(point.rough_id),
-            &self.curve,
-        );
-        self.noise.finish_linear_equivalent_value(raw)
+    fn output_range(&self) -> OutputRange {
+        OutputRange::UNORM
     }
-}
-
-impl<
-    I: VectorSpace,
-    P: Partitioner<I, Cell: DifferentiableCell>,
-    C: SampleDerivative<f32>,
-    N: AnyValueFromBits<I>,
-> NoiseFunction<I> for MixCellValuesForDomain<P, C, N, true>
-{
-    type Output = WithGradient<I, <P::Cell as DifferentiableCell>::Gradient<I>>;
 
     #[inline]
-    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
-        let segment = self.cells.partition(input);
-        let WithGradient { value, gradient } = segment.interpolate_with_gradient(
-            *seeds,
-            |point| self.noise.linear_equivalent_value(point.rough_id),
-            &self.curve,
-            self.noise.finishing_derivative(),
-        );
-        WithGradient {
-            value: self.noise.finish_linear_equivalent_value(value),
-            gradient,
+    fn evaluate_worley<I: VectorSpace>(
+        &self,
+        points: impl Iterator<Item = I>,
+        lengths: &impl LengthFunction<I>,
+        max_least_length: f32,
+        _max_next_least_length: f32,
+    ) -> f32 {
+        let mut res = f32::INFINITY;
+        for p in points {
+            res = res.min(lengths.length_ordering(p));
         }
+        lengths.length_from_ordering(res) / max_least_length
     }
 }
 
-/// Allows blending between different values `V` where each values corresponds to a [`CellPoint<I>`](crate::cells::CellPoint).
-pub trait ValueBlender<I: VectorSpace, V> {
-    /// Blends together each value `V` of `to_blend` according to some weight `I`, where weights beyond the range of `blending_half_radius` are ignored.
-    /// `blending_half_radius` cuts off the blend before it hits discontinuities.
-    fn blend_values(&self, to_blend: impl Iterator<Item = (V, I)>, blending_half_radius: f32) -> V;
-}
-
-/// Same as [`ValueBlender`] but also differentiates the result.
-pub trait DifferentiableValueBlender<I: VectorSpace, V>: ValueBlender<I, V> {
-    /// Same as [`blend_values`](ValueBlender::blend_values) but also differentiates the result.
-    fn differential_blend_values(
-        &self,
-        to_blend: impl Iterator<Item = (V, I)>,
-        blending_half_radius: f32,
-    ) -> WithGradient<V, I>;
-}
+/// A [`WorleyMode`] that returns the unorm distance to the second nearest [`CellPoint`](crate::cells::CellPoint).
+/// This will have artifacts when using `HALF_SCALE` on [`Voronoi`](crate::cells::Voronoi).
+///
+/// ```
+/// # use noiz::prelude::*;
+/// use noiz::cell_noise::WorleySecondLeastDistance;
+/// let noise = Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleySecondLeastDistance>>::default();
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct WorleySecondLeastDistance;
 
-/// Similar to [`ValueBlender`] but specialized for blending gradient dot products.
-/// In other words, the weight has a role in the value, even before blending.
-pub trait GradientBlender<I: VectorSpace> {
-    /// Blends between gradient and offset pairs, returning a dot product blended value.
-    fn blend_gradients(
-        &self,
-        to_blend: impl Iterator<Item = (I, I)>,
-        blending_half_radius: f32,
-    ) -> f32;
-}
+impl WorleyMode for WorleySecondLeastDistance {
+    #[inline]
+    fn output_range(&self) -> OutputRange {
+        OutputRange::UNORM
+    }
 
-/// Same as [`GradientBlender`] but also differentiates the result.
-pub trait DifferentiableGradientBlender<I: VectorSpace>: GradientBlender<I> {
-    /// Same as [`blend_gradients`](GradientBlender::blend_gradients) but also differentiates the result.
-    fn differential_blend_gradients(
+    #[inline]
+    fn evaluate_worley<I: VectorSpace>(
         &self,
-        to_blend: impl Iterator<Item = (I, I)>,
-        blending_half_radius: f32,
-    ) -> WithGradient<f32, I>;
+        points: impl Iterator<Item = I>,
+        lengths: &impl LengthFunction<I>,
+        _max_least_length: f32,
+        max_next_least_length: f32,
+    ) -> f32 {
+        let (_least, next_least) = two_least(points.map(|p| lengths.length_ordering(p)));
+        lengths.length_from_ordering(next_least) / max_next_least_length
+    }
 }
 
-/// A [`NoiseFunction`] that blends values sourced from a [`ConcreteAnyValueFromBits`] `N` by a [`ValueBlender`] `B` within some [`DomainCell`] form a [`Partitioner`] `P`.
-///
-/// This results in smooth blending between values. Note that this does *not* mix between values; it only blends them together so there isn't a sharp jump.
-/// To see this clearly, run the "show_noise" example.
-///
-/// Here's a way to hexagonally stacked circular values together:
+/// A [`WorleyMode`] that returns the unorm difference between the first and second nearest [`CellPoint`](crate::cells::CellPoint).
+/// This is guaranteed to stay within 0 and 1, but it rarely gets close to 1. See [`Crackle`] for a brighter version.
+/// This will have artifacts when using `HALF_SCALE` on [`Voronoi`](crate::cells::Voronoi).
 ///
 /// ```
 /// # use noiz::prelude::*;
-/// let noise = Noise::<BlendCellValues<SimplexGrid, SimplecticBlend, Random<UNorm, f32>>>::default();
+/// use noiz::cell_noise::WorleyDifference;
+/// let noise = Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleyDifference>>::default();
 /// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
 /// ```
-///
-/// You can also use this to make fun stars:
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct WorleyDifference;
+
+impl WorleyMode for WorleyDifference {
+    #[inline]
+    fn output_range(&self) -> OutputRange {
+        OutputRange::UNORM
+    }
+
+    #[inline]
+    fn evaluate_worley<I: VectorSpace>(
+        &self,
+        points: impl Iterator<Item = I>,
+        lengths: &impl LengthFunction<I>,
+        _max_least_length: f32,
+        max_next_least_length: f32,
+    ) -> f32 {
+        let (least, next_least) = two_least(points.map(|p| lengths.length_ordering(p)));
+        (lengths.length_from_ordering(next_least) - lengths.length_from_ordering(least))
+            / max_next_least_length
+    }
+}
+
+/// A [`WorleyMode`] that returns the unorm average of the first and second nearest [`CellPoint`](crate::cells::CellPoint).
+/// This will have artifacts when using `HALF_SCALE` on [`Voronoi`](crate::cells::Voronoi).
 ///
 /// ```
 /// # use noiz::prelude::*;
-/// let noise = Noise::<BlendCellValues<Voronoi, DistanceBlend<ManhattanLength>, Random<UNorm, f32>>>::default();
+/// use noiz::cell_noise::WorleyAverage;
+/// let noise = Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleyAverage>>::default();
 /// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
 /// ```
-///
-/// If you are interested in calculating the gradient of the noise as well, turn on `DIFFERENTIATE` (off by default), and use a [`DifferentiableValueBlender`].
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct WorleyAverage;
+
+impl WorleyMode for WorleyAverage {
+    #[inline]
+    fn output_range(&self) -> OutputRange {
+        OutputRange::UNORM
+    }
+
+    #[inline]
+    fn evaluate_worley<I: VectorSpace>(
+        &self,
+        points: impl Iterator<Item = I>,
+        lengths: &impl LengthFunction<I>,
+        max_least_length: f32,
+        max_next_least_length: f32,
+    ) -> f32 {
+        let (least, next_least) = two_least(points.map(|p| lengths.length_ordering(p)));
+        (lengths.length_from_ordering(next_least) / max_next_least_length
+            + lengths.length_from_ordering(least) / max_least_length)
+            * 0.5
+    }
+}
+
+/// A [`WorleyMode`] that returns the unorm product between the first and second nearest [`CellPoint`](crate::cells::CellPoint).
+/// This will have artifacts when using `HALF_SCALE` on [`Voronoi`](crate::cells::Voronoi).
 ///
 /// ```
 /// # use noiz::prelude::*;
-/// let noise = Noise::<BlendCellValues<SimplexGrid, SimplecticBlend, Random<UNorm, f32>, true>>::default();
+/// use noiz::cell_noise::WorleyProduct;
+/// let noise = Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleyProduct>>::default();
 /// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
 /// ```
-///
-/// This is typically used with [`NormedByDerivative`](crate::layering::NormedByDerivative).
-#[derive(Default, Clone, Copy, PartialEq)]
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub struct BlendCellValues<P, B, N, const DIFFERENTIATE: bool = false> {
-    /// The [`Partitioner`].
-    pub cells: P,
-    /// The [`ConcreteAnyValueFromBits`].
-    pub noise: N,
-    /// The [`ValueBlender`].
-    pub blender: B,
-}
+pub struct WorleyProduct;
 
-impl<
-    I: VectorSpace,
-    P: Partitioner<I, Cell: BlendableDomainCell>,
-    B: ValueBlender<I, N::Concrete>,
-    N: ConcreteAnyValueFromBits,
-> NoiseFunction<I> for BlendCellValues<P, B, N, false>
-{
-    type Output = N::Concrete;
+impl WorleyMode for WorleyProduct {
+    #[inline]
+    fn output_range(&self) -> OutputRange {
+        OutputRange::UNORM
+    }
 
     #[inline]
-    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
-        let cell = self.cells.partition(input);
-        let to_blend = cell.iter_points(*seeds).map(|p| {
-            // We can't use the `linear_equivalent_value` because the blend type is not linear.
-            let value = self.noise.any_value(p.rough_id);
-            (value, p.offset)
-        });
-        self.blender
-            .blend_values(to_blend, cell.blending_half_radius())
+    fn evaluate_worley<I: VectorSpace>(
+        &self,
+        points: impl Iterator<Item = I>,
+        lengths: &impl LengthFunction<I>,
+        max_least_length: f32,
+        max_next_least_length: f32,
+    ) -> f32 {
+        let (least, next_least) = two_least(points.map(|p| lengths.length_ordering(p)));
+        (lengths.length_from_ordering(next_least) * lengths.length_from_ordering(least))
+            / (max_least_length * max_next_least_length)
     }
 }
 
-impl<
-    I: VectorSpace + Mul<N::Concrete, Output = I>,
-    P: Partitioner<I, Cell: BlendableDomainCell>,
-    B: DifferentiableValueBlender<I, N::Concrete>,
-    N: ConcreteAnyValueFromBits<Concrete: Copy>,
-> NoiseFunction<I> for BlendCellValues<P, B, N, true>
-{
-    type Output = WithGradient<N::Concrete, I>;
+/// A [`WorleyMode`] that returns the unorm ratio between the first and second nearest [`CellPoint`](crate::cells::CellPoint).
+/// This will have artifacts when using `HALF_SCALE` on [`Voronoi`](crate::cells::Voronoi).
+///
+/// ```
+/// # use noiz::prelude::*;
+/// use noiz::cell_noise::WorleyRatio;
+/// let noise = Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleyRatio>>::default();
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct WorleyRatio;
+
+impl WorleyMode for WorleyRatio {
+    #[inline]
+    fn output_range(&self) -> OutputRange {
+        OutputRange::UNORM
+    }
 
     #[inline]
-    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
-        let cell = self.cells.partition(input);
-        let to_blend = cell.iter_points(*seeds).map(|p| {
-            // We can't use the `linear_equivalent_value` because the blend type is not linear.
-            let value = self.noise.any_value(p.rough_id);
-            (value, p.offset)
-        });
-        self.blender
-            .differential_blend_values(to_blend, cell.blending_half_radius())
+    fn evaluate_worley<I: VectorSpace>(
+        &self,
+        points: impl Iterator<Item = I>,
+        lengths: &impl LengthFunction<I>,
+        _max_least_length: f32,
+        _max_next_least_length: f32,
+    ) -> f32 {
+        let (least, next_least) = two_least(points.map(|p| lengths.length_ordering(p)));
+        // For this to be a division by zero, the points would need to be ontop of eachother, which is impossible.
+        lengths.length_from_ordering(least) / lengths.length_from_ordering(next_least)
     }
 }
 
-/// This trait facilitates generating gradients and computing their dot products.
+/// A [`WorleyMode`] that returns the difference between the first and second nearest [`CellPoint`](crate::cells::CellPoint), normalized so that the ridges reach full brightness.
+/// This produces crackle patterns, like [`WorleyDifference`], but [`WorleyDifference`] divides by the worst case distance to the second nearest point, which leaves the result dim.
 ///
-/// If you're not sure which one to use, try [`QuickGradients`], a fast lookup table.
-pub trait GradientGenerator<I: VectorSpace> {
-    /// Gets the dot product of `I` with some gradient vector based on this seed.
-    /// Each element of `offset` can be assumed to be in -1..=1.
-    /// The dot product should be in (-1,1).
-    fn get_gradient_dot(&self, seed: u32, offset: I) -> f32;
+/// The difference between the second and first nearest distances is at most the distance from the nearest point to its own nearest neighbor, and that is reached at the point itself.
+/// That neighbor distance is usually far below the worst case, so instead, this divides by [`peak`](Crackle::peak), a typical high value of the difference.
+/// The provided constants were measured as the 99th percentile of the difference over dense samples of points jittered uniformly within unit cells (the default [`Voronoi`](crate::cells::Voronoi)),
+/// and the result is clamped to 1, so only the brightest 1% or so saturates.
+/// For other [`LengthFunction`]s or [`Voronoi::randomness`](crate::cells::Voronoi::randomness), you may want to tune [`peak`](Crackle::peak) yourself.
+/// This will have artifacts when using `HALF_SCALE` on [`Voronoi`](crate::cells::Voronoi).
+///
+/// ```
+/// # use noiz::prelude::*;
+/// use noiz::cell_noise::Crackle;
+/// let noise = Noise::<PerCellPointDistances<Voronoi, EuclideanLength, Crackle>>::default();
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// // In 3d, the typical difference is a bit smaller.
+/// let noise = Noise::from(PerCellPointDistances {
+///     worley_mode: Crackle::EUCLIDEAN_3D,
+///     ..PerCellPointDistances::<Voronoi, EuclideanLength, Crackle>::default()
+/// });
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec3::ZERO);
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Crackle {
+    /// The difference between the second and first nearest distances that maps to 1.
+    /// This must be positive.
+    pub peak: f32,
+}
 
-    /// Gets the gradient that would be used in [`get_gradient_dot`](GradientGenerator::get_gradient_dot).
-    fn get_gradient(&self, seed: u32) -> I;
+impl Crackle {
+    /// Tuned for [`EuclideanLength`](crate::lengths::EuclideanLength) in 2d with a default [`Voronoi`](crate::cells::Voronoi).
+    pub const EUCLIDEAN_2D: Self = Self { peak: 0.83 };
+    /// Tuned for [`EuclideanLength`](crate::lengths::EuclideanLength) in 3d with a default [`Voronoi`](crate::cells::Voronoi).
+    pub const EUCLIDEAN_3D: Self = Self { peak: 0.67 };
 }
 
-/// A [`NoiseFunction`] that mixes gradients sourced from a [`GradientGenerator`] `G` by a [`Curve`] `C` within some [`DomainCell`] form a [`Partitioner`] `P`.
+impl Default for Crackle {
+    fn default() -> Self {
+        Self::EUCLIDEAN_2D
+    }
+}
+
+impl WorleyMode for Crackle {
+    #[inline]
+    fn output_range(&self) -> OutputRange {
+        OutputRange::UNORM
+    }
+
+    #[inline]
+    fn evaluate_worley<I: VectorSpace>(
+        &self,
+        points: impl Iterator<Item = I>,
+        lengths: &impl LengthFunction<I>,
+        _max_least_length: f32,
+        _max_next_least_length: f32,
+    ) -> f32 {
+        let (least, next_least) = two_least(points.map(|p| lengths.length_ordering(p)));
+        let difference =
+            lengths.length_from_ordering(next_least) - lengths.length_from_ordering(least);
+        (difference / self.peak).min(1.0)
+    }
+}
+
+/// A [`NoiseFunction`] that partitions space by some [`Partitioner`] `P` into [`DomainCell`]s,
+/// and then provides the distance to each [`CellPoint`](crate::cells::CellPoint)s to some [`WorleyMode`] `M` by some [`LengthFunction`] `L`.
 ///
-/// This is most commonly used for perlin noise:
+/// You can use this to make worley noise:
 ///
 /// ```
 /// # use noiz::prelude::*;
-/// let noise = Noise::<MixCellGradients<OrthoGrid, Smoothstep, QuickGradients>>::default();
+/// let noise = Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleyLeastDistance>>::default();
 /// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
 /// ```
 ///
-/// If you need smoother derivatives (ex: to compute analytical normals), try a smoother [`Curve`] like [`DoubleSmoothstep`](crate::curves::DoubleSmoothstep).
-/// Note that [`Linear`](crate::curves::Linear) is not appealing here.
+/// Lots of noise types are available. See also [`WorleyMode`], [`WorleyLeastDistance`], [`WorleyDifference`], etc.
+/// This is not explicitly called `Worley` because it doesn't cover every type of worley noise, for example, [`DistanceToEdge`].
 ///
 /// If you are interested in calculating the gradient of the noise as well, turn on `DIFFERENTIATE` (off by default).
+/// This is currently only supported for [`WorleyLeastDistance`] with a [`DifferentiableLengthFunction`].
+/// The gradient points away from the nearest point, and is undefined exactly on the borders between cells, where the nearest point changes.
 ///
 /// ```
 /// # use noiz::prelude::*;
-/// let noise = Noise::<MixCellGradients<OrthoGrid, Smoothstep, QuickGradients, true>>::default();
-/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// let noise = Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleyLeastDistance, true>>::default();
+/// # let val = noise.sample_for::<WithGradient<f32, bevy_math::Vec2>>(bevy_math::Vec2::ZERO);
 /// ```
-///
-/// This is typically used with [`NormedByDerivative`](crate::layering::NormedByDerivative).
 #[derive(Default, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub struct MixCellGradients<P, C, G, const DIFFERENTIATE: bool = false> {
+pub struct PerCellPointDistances<P, L, W, const DIFFERENTIATE: bool = false> {
     /// The [`Partitioner`].
     pub cells: P,
-    /// The [`GradientGenerator`].
-    pub gradients: G,
-    /// The [`Curve`].
-    pub curve: C,
+    /// The [`LengthFunction`].
+    pub length_mode: L,
+    /// The [`WorleyMode`].
+    pub worley_mode: W,
 }
 
-impl<
-    I: ElementalVectorSpace,
-    P: Partitioner<I, Cell: InterpolatableCell>,
-    C: Curve<f32>,
-    G: GradientGenerator<I>,
-> NoiseFunction<I> for MixCellGradients<P, C, G, false>
+impl<L, W> PerCellPointDistances<Voronoi, L, W> {
+    /// Constructs worley noise on a default [`Voronoi`] graph with this [`LengthFunction`] and [`WorleyMode`].
+    /// This saves spelling out the [`Partitioner`] for the most common kind of cellular noise.
+    ///
+    /// ```
+    /// # use noiz::prelude::*;
+    /// let noise = Noise::from(PerCellPointDistances::worley(
+    ///     EuclideanLength,
+    ///     WorleyLeastDistance,
+    /// ));
+    /// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+    /// ```
+    pub fn worley(length_mode: L, worley_mode: W) -> Self {
+        Self {
+            cells: Voronoi::default(),
+            length_mode,
+            worley_mode,
+        }
+    }
+}
+
+impl<I: VectorSpace, L: LengthFunction<I>, P: Partitioner<I, Cell: WorleyDomainCell>, W: WorleyMode>
+    NoiseFunction<I> for PerCellPointDistances<P, L, W>
 {
     type Output = f32;
 
     #[inline]
     fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
-        let segment = self.cells.partition(input);
-        segment.interpolate_within(
-            *seeds,
-            |point| {
-                self.gradients
-                    .get_gradient_dot(point.rough_id, point.offset)
-                    * I::SQRT_NUM_ELEMENTS
-            },
-            &self.curve,
-        )
+        self.evaluate_cell(&self.cells.partition(input), seeds)
     }
 }
 
-impl<
-    I: ElementalVectorSpace,
-    P: Partitioner<I, Cell: DifferentiableCell<Gradient<f32>: Into<I>>>,
-    C: SampleDerivative<f32>,
-    G: GradientGenerator<I>,
-> NoiseFunction<I> for MixCellGradients<P, C, G, true>
+impl<C: WorleyDomainCell, P, L: LengthFunction<C::Full>, W: WorleyMode> CellFunction<C>
+    for PerCellPointDistances<P, L, W>
 {
-    type Output = WithGradient<f32, I>;
+    type Output = f32;
 
     #[inline]
-    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
-        let segment = self.cells.partition(input);
-        let gradients = segment.interpolate_within(
-            *seeds,
-            |point| self.gradients.get_gradient(point.rough_id) * I::SQRT_NUM_ELEMENTS,
-            &self.curve,
-        );
-        let WithGradient { value, gradient } = segment.interpolate_with_gradient(
-            *seeds,
-            |point| {
-                self.gradients
-                    .get_gradient_dot(point.rough_id, point.offset)
-                    * I::SQRT_NUM_ELEMENTS
-            },
-            &self.curve,
-            1.0,
-        );
-        WithGradient {
-            value,
-            gradient: gradient.into() + gradients,
-        }
+    fn evaluate_cell(&self, cell: &C, seeds: &mut NoiseRng) -> Self::Output {
+        let max_least_length = self
+            .length_mode
+            .max_for_element_max(cell.nearest_1d_point_always_within());
+        let max_next_least_length = self
+            .length_mode
+            .max_for_element_max(cell.next_nearest_1d_point_always_within());
+
+        self.worley_mode.evaluate_worley(
+            cell.iter_points(*seeds).map(|p| p.offset),
+            &self.length_mode,
+            max_least_length,
+            max_next_least_length,
+        )
     }
 }
 
-/// A [`NoiseFunction`] that blends gradients sourced from a [`GradientGenerator`] `G` by a [`GradientBlender`] `B` within some [`DomainCell`] form a [`Partitioner`] `P`.
+/// A [`NoiseFunction`] that partitions space by some [`Partitioner`] `P` into [`DomainCell`]s,
+/// and then provides the distances to the nearest and next nearest [`CellPoint`](crate::cells::CellPoint)s by some [`LengthFunction`] `L` as a [`Vec2`].
 ///
-/// This is typically used for simplex noise:
+/// This exposes the raw F1 and F2 distances of worley noise, so any combination of them can be computed downstream without a custom [`WorleyMode`].
+/// Both distances are divided by the maximum next nearest distance, so `x <= y` always holds and the ratios between them are preserved.
+/// As a result, both are unorm, but `x` will generally not reach 1.
 ///
 /// ```
 /// # use noiz::prelude::*;
-/// let noise = Noise::<BlendCellGradients<SimplexGrid, SimplecticBlend, QuickGradients>>::default();
-/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// # use noiz::cell_noise::WorleyDistances;
+/// let noise = Noise::<WorleyDistances<Voronoi, EuclideanLength>>::default();
+/// let f1_f2 = noise.sample_for::<bevy_math::Vec2>(bevy_math::Vec2::ZERO);
+/// let difference = f1_f2.y - f1_f2.x;
 /// ```
+#[derive(Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct WorleyDistances<P, L> {
+    /// The [`Partitioner`].
+    pub cells: P,
+    /// The [`LengthFunction`].
+    pub length_mode: L,
+}
+
+impl<I: VectorSpace, L: LengthFunction<I>, P: Partitioner<I, Cell: WorleyDomainCell>>
+    NoiseFunction<I> for WorleyDistances<P, L>
+{
+    type Output = Vec2;
+
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        self.evaluate_cell(&self.cells.partition(input), seeds)
+    }
+}
+
+impl<C: WorleyDomainCell, P, L: LengthFunction<C::Full>> CellFunction<C> for WorleyDistances<P, L> {
+    type Output = Vec2;
+
+    #[inline]
+    fn evaluate_cell(&self, cell: &C, seeds: &mut NoiseRng) -> Self::Output {
+        let max_next_least_length = self
+            .length_mode
+            .max_for_element_max(cell.next_nearest_1d_point_always_within());
+        let (least, next_least) = two_least(
+            cell.iter_points(*seeds)
+                .map(|p| self.length_mode.length_ordering(p.offset)),
+        );
+        Vec2::new(
+            self.length_mode.length_from_ordering(least),
+            self.length_mode.length_from_ordering(next_least),
+        ) / max_next_least_length
+    }
+}
+
+/// A [`NoiseFunction`] that partitions space by some [`Partitioner`] `P` (usually [`Voronoi`]) into [`DomainCell`]s,
+/// and estimates the size of the local cell as the mean distance to the 3 nearest [`CellPoint`](crate::cells::CellPoint)s by some [`LengthFunction`] `L`.
 ///
-/// If you are interested in calculating the gradient of the noise as well, turn on `DIFFERENTIATE` (off by default), and use a  [`DifferentiableGradientBlender`].
+/// This is useful for scattering content with a density proportional to cell size.
+/// The result is in the units of the partition, so for a [`Voronoi`] graph, where cells average an area of 1, it is usually around 0.7.
+///
+/// This is only an estimate.
+/// On average, it grows with the area of the cell around the nearest point, but it also varies within each cell, being smallest near the cell's point.
+/// Averaging it over a cell or smoothing it afterwards gives a more stable size.
 ///
 /// ```
 /// # use noiz::prelude::*;
-/// let noise = Noise::<BlendCellGradients<SimplexGrid, SimplecticBlend, QuickGradients, true>>::default();
+/// use noiz::cell_noise::CellSizeEstimate;
+/// let noise = Noise::<CellSizeEstimate<Voronoi, EuclideanLength>>::default();
 /// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
 /// ```
-///
-/// This is typically used with [`NormedByDerivative`](crate::layering::NormedByDerivative).
 #[derive(Default, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub struct BlendCellGradients<P, B, G, const DIFFERENTIATE: bool = false> {
+pub struct CellSizeEstimate<P, L> {
     /// The [`Partitioner`].
     pub cells: P,
-    /// The [`GradientGenerator`].
-    pub gradients: G,
-    /// The [`GradientBlender`].
-    pub blender: B,
+    /// The [`LengthFunction`].
+    pub length_mode: L,
 }
 
-impl<
-    I: VectorSpace,
-    P: Partitioner<I, Cell: BlendableDomainCell>,
-    B: GradientBlender<I>,
-    G: GradientGenerator<I>,
-> NoiseFunction<I> for BlendCellGradients<P, B, G, false>
+impl<I: VectorSpace, L: LengthFunction<I>, P: Partitioner<I>> NoiseFunction<I>
+    for CellSizeEstimate<P, L>
 {
     type Output = f32;
 
     #[inline]
     fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
-        let cell = self.cells.partition(input);
-        let to_blend = cell.iter_points(*seeds).map(|p| {
-            let grad = self.gradients.get_gradient(p.rough_id);
-            (grad, p.offset)
-        });
-        let radius = cell.blending_half_radius();
-        self.blender.blend_gradients(to_blend, radius)
+        self.evaluate_cell(&self.cells.partition(input), seeds)
     }
 }
 
-impl<
-    I: VectorSpace,
-    P: Partitioner<I, Cell: BlendableDomainCell>,
-    B: DifferentiableGradientBlender<I>,
-    G: GradientGenerator<I>,
-> NoiseFunction<I> for BlendCellGradients<P, B, G, true>
-{
-    type Output = WithGradient<f32, I>;
+impl<C: DomainCell, P, L: LengthFunction<C::Full>> CellFunction<C> for CellSizeEstimate<P, L> {
+    type Output = f32;
 
     #[inline]
-    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
-        let cell = self.cells.partition(input);
-        let to_blend = cell.iter_points(*seeds).map(|p| {
-            let grad = self.gradients.get_gradient(p.rough_id);
-            (grad, p.offset)
-        });
-        let radius = cell.blending_half_radius();
-        self.blender.differential_blend_gradients(to_blend, radius)
+    fn evaluate_cell(&self, cell: &C, seeds: &mut NoiseRng) -> Self::Output {
+        let nearest = n_least::<3>(
+            cell.iter_points(*seeds)
+                .map(|p| self.length_mode.length_ordering(p.offset)),
+        );
+        nearest
+            .into_iter()
+            .map(|ordering| self.length_mode.length_from_ordering(ordering))
+            .sum::<f32>()
+            / 3.0
     }
 }
 
-/// A simple [`GradientGenerator`] that maps seeds directly to gradient vectors via a lookup table.
-/// This is the fastest provided [`GradientGenerator`].
+/// A [`NoiseFunction`] that partitions 2d space by some [`Partitioner`] `P` (usually [`Voronoi`]) into [`DomainCell`]s,
+/// and provides both the distance to the nearest [`CellPoint`](crate::cells::CellPoint) by some [`LengthFunction`] `L` and the angle of the sample around that point, as a [`Vec2`].
 ///
-/// The lookup table is shared for all dimensions to reduce memory.
-/// Instead of an expensive `%` to index the array, the bits are shifted within range `>>`.
-/// This has the unfortunate (but worth it) effect of making some values more likely than others in 3d.
-/// There are 12 vectors for 3d, but 16 possible indices, so 4 are weighted double the others.
-#[derive(Default, Clone, Copy, PartialEq, Eq)]
+/// The distance, `x`, is the same as [`WorleyLeastDistance`]. The angle, `y`, is unorm, increasing counterclockwise from 0 to 1 starting from the negative x axis.
+/// The angle wraps from 1 back to 0 along that axis, so it is continuous within each cell everywhere else.
+/// This is useful for polar cellular textures, like pie slice cells, scales, and directional effects.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// use noiz::cell_noise::WorleyPolar;
+/// let noise = Noise::<WorleyPolar<Voronoi, EuclideanLength>>::default();
+/// let polar = noise.sample_for::<bevy_math::Vec2>(bevy_math::Vec2::ZERO);
+/// let slices = (polar.y * 6.0) as u32;
+/// ```
+#[derive(Default, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub struct QuickGradients;
+pub struct WorleyPolar<P, L> {
+    /// The [`Partitioner`].
+    pub cells: P,
+    /// The [`LengthFunction`].
+    pub length_mode: L,
+}
+
+impl<L: LengthFunction<Vec2>, P: Partitioner<Vec2, Cell: WorleyDomainCell>> NoiseFunction<Vec2>
+    for WorleyPolar<P, L>
+{
+    type Output = Vec2;
 
-impl GradientGenerator<Vec2> for QuickGradients {
     #[inline]
-    fn get_gradient_dot(&self, seed: u32, offset: Vec2) -> f32 {
-        GradientGenerator::<Vec2>::get_gradient(self, seed).dot(offset)
+    fn evaluate(&self, input: Vec2, seeds: &mut NoiseRng) -> Self::Output {
+        self.evaluate_cell(&self.cells.partition(input), seeds)
     }
+}
+
+impl<C: WorleyDomainCell<Full = Vec2>, P, L: LengthFunction<Vec2>> CellFunction<C>
+    for WorleyPolar<P, L>
+{
+    type Output = Vec2;
 
     #[inline]
-    fn get_gradient(&self, seed: u32) -> Vec2 {
-        // SAFETY: Ensured by bit shift. Bit shift is better than bit and since the rng is cheap and puts more entropy in higher bits.
-        unsafe { *GRADIENT_TABLE.get_unchecked((seed >> 30) as usize) }.xy()
+    fn evaluate_cell(&self, cell: &C, seeds: &mut NoiseRng) -> Self::Output {
+        let max_least_length = self
+            .length_mode
+            .max_for_element_max(cell.nearest_1d_point_always_within());
+        let mut nearest = Vec2::ZERO;
+        let mut least_length_order = f32::INFINITY;
+        for point in cell.iter_points(*seeds) {
+            let length_order = self.length_mode.length_ordering(point.offset);
+            if length_order < least_length_order {
+                least_length_order = length_order;
+                nearest = point.offset;
+            }
+        }
+        Vec2::new(
+            self.length_mode.length_from_ordering(least_length_order) / max_least_length,
+            nearest.to_angle() / f32::consts::TAU + 0.5,
+        )
     }
 }
 
-impl GradientGenerator<Vec3> for QuickGradients {
+/// Represents a collection of [`WorleyMode`]s that are evaluated from the same [`CellPoint`](crate::cells::CellPoint)s.
+/// This is implemented for tuples of [`WorleyMode`]s and is designed for use in [`MultiWorley`].
+pub trait WorleyModes {
+    /// The output of all the modes together.
+    type Output;
+
+    /// Evaluates each mode like [`WorleyMode::evaluate_worley`], where `points` produces the same offsets each time it is called.
+    fn evaluate_worleys<I: VectorSpace, T: Iterator<Item = I>>(
+        &self,
+        points: impl Fn() -> T,
+        lengths: &impl LengthFunction<I>,
+        max_least_length: f32,
+        max_next_least_length: f32,
+    ) -> Self::Output;
+}
+
+macro_rules! impl_worley_modes {
+    ($($t:ident = $i:tt),*) => {
+        impl<$($t: WorleyMode),*> WorleyModes for ($($t,)*) {
+            type Output = ($(impl_worley_modes!(@f32 $t),)*);
+
+            #[inline]
+            fn evaluate_worleys<I: VectorSpace, T: Iterator<Item = I>>(
+                &self,
+                points: impl Fn() -> T,
+                lengths: &impl LengthFunction<I>,
+                max_least_length: f32,
+                max_next_least_length: f32,
+            ) -> Self::Output {
+                ($(
+                    self.$i.evaluate_worley(
+                        points(),
+                        lengths,
+                        max_least_length,
+                        max_next_least_length,
+                    ),
+                )*)
+            }
+        }
+    };
+    (@f32 $t:ident) => {
+        f32
+    };
+}
+
+impl_worley_modes!(T0 = 0, T1 = 1);
+impl_worley_modes!(T0 = 0, T1 = 1, T2 = 2);
+impl_worley_modes!(T0 = 0, T1 = 1, T2 = 2, T3 = 3);
+
+/// A [`NoiseFunction`] that partitions space by some [`Partitioner`] `P` into [`DomainCell`]s,
+/// and evaluates several [`WorleyMode`]s, collected into a tuple `W`, from one scan of the [`CellPoint`](crate::cells::CellPoint)s.
+///
+/// This produces the same values as a [`PerCellPointDistances`] for each mode, but the points are only partitioned and generated once.
+/// They are kept on the stack, which holds up to `CAPACITY` points.
+/// The default is enough for a [`Voronoi`] of radius 1 in up to 3 dimensions.
+/// Cells with more points than that are still correct but generate their points again for each mode.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// use noiz::cell_noise::{MultiWorley, WorleyDifference};
+/// let noise = Noise::<MultiWorley<Voronoi, EuclideanLength, (WorleyLeastDistance, WorleyDifference)>>::default();
+/// let (distance, difference): (f32, f32) = noise.sample(bevy_math::Vec2::ZERO);
+/// ```
+#[derive(Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct MultiWorley<P, L, W, const CAPACITY: usize = 27> {
+    /// The [`Partitioner`].
+    pub cells: P,
+    /// The [`LengthFunction`].
+    pub length_mode: L,
+    /// The [`WorleyModes`].
+    pub worley_modes: W,
+}
+
+impl<
+    I: VectorSpace,
+    L: LengthFunction<I>,
+    P: Partitioner<I, Cell: WorleyDomainCell>,
+    W: WorleyModes,
+    const CAPACITY: usize,
+> NoiseFunction<I> for MultiWorley<P, L, W, CAPACITY>
+{
+    type Output = W::Output;
+
     #[inline]
-    fn get_gradient_dot(&self, seed: u32, offset: Vec3) -> f32 {
-        GradientGenerator::<Vec3>::get_gradient(self, seed).dot(offset)
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        self.evaluate_cell(&self.cells.partition(input), seeds)
     }
+}
+
+impl<C: WorleyDomainCell, P, L: LengthFunction<C::Full>, W: WorleyModes, const CAPACITY: usize>
+    CellFunction<C> for MultiWorley<P, L, W, CAPACITY>
+{
+    type Output = W::Output;
 
     #[inline]
-    fn get_gradient(&self, seed: u32) -> Vec3 {
-        // SAFETY: Ensured by bit shift. Bit shift is better than bit and since the rng is cheap and puts more entropy in higher bits.
-        unsafe { *GRADIENT_TABLE.get_unchecked((seed >> 28) as usize) }.xyz()
+    fn evaluate_cell(&self, cell: &C, seeds: &mut NoiseRng) -> Self::Output {
+        let max_least_length = self
+            .length_mode
+            .max_for_element_max(cell.nearest_1d_point_always_within());
+        let max_next_least_length = self
+            .length_mode
+            .max_for_element_max(cell.next_nearest_1d_point_always_within());
+
+        let mut offsets = [C::Full::ZERO; CAPACITY];
+        let mut len = 0;
+        for point in cell.iter_points(*seeds) {
+            if len == CAPACITY {
+                let seeds = *seeds;
+                return self.worley_modes.evaluate_worleys(
+                    || cell.iter_points(seeds).map(|p| p.offset),
+                    &self.length_mode,
+                    max_least_length,
+                    max_next_least_length,
+                );
+            }
+            offsets[len] = point.offset;
+            len += 1;
+        }
+
+        self.worley_modes.evaluate_worleys(
+            || offsets[..len].iter().copied(),
+            &self.length_mode,
+            max_least_length,
+            max_next_least_length,
+        )
     }
 }
 
-impl GradientGenerator<Vec3A> for QuickGradients {
+impl<I: VectorSpace, L: DifferentiableLengthFunction<I>, P: Partitioner<I, Cell: WorleyDomainCell>>
+    NoiseFunction<I> for PerCellPointDistances<P, L, WorleyLeastDistance, true>
+{
+    type Output = WithGradient<f32, I>;
+
     #[inline]
-    fn get_gradient_dot(&self, seed: u32, offset: Vec3A) -> f32 {
-        GradientGenerator::<Vec3A>::get_gradient(self, seed).dot(offset)
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        let cell = self.cells.partition(input);
+        let max_least_length = self
+            .length_mode
+            .max_for_element_max(cell.nearest_1d_point_always_within());
+
+        let mut nearest = I::ZERO;
+        let mut least_length_order = f32::INFINITY;
+        for point in cell.iter_points(*seeds) {
+            let length_order = self.length_mode.length_ordering(point.offset);
+            if length_order < least_length_order {
+                least_length_order = length_order;
+                nearest = point.offset;
+            }
+        }
+
+        // The offset is from the point to the sample, so its derivative with respect to the sample is the identity.
+        let length = self.length_mode.length_and_gradient_of(nearest);
+        WithGradient {
+            value: length.value / max_least_length,
+            gradient: length.gradient * (1.0 / max_least_length),
+        }
     }
+}
 
+impl<P, L, W: WorleyMode, const DIFFERENTIATE: bool> NoiseRange
+    for PerCellPointDistances<P, L, W, DIFFERENTIATE>
+{
     #[inline]
-    fn get_gradient(&self, seed: u32) -> Vec3A {
-        // SAFETY: Ensured by bit shift. Bit shift is better than bit and since the rng is cheap and puts more entropy in higher bits.
-        unsafe { *GRADIENT_TABLE.get_unchecked((seed >> 28) as usize) }
-            .xyz()
-            .into()
+    fn output_range(&self, _input: OutputRange) -> OutputRange {
+        self.worley_mode.output_range()
     }
 }
 
-impl GradientGenerator<Vec4> for QuickGradients {
+/// A [`NoiseFunction`] that domain warps its input before computing worley noise, producing organic, blob like cells.
+/// The input is displaced by [`RandomElements`] of a noise `N`, times [`warp_strength`](OrganicWorley::warp_strength),
+/// and then the distances are computed by a [`PerCellPointDistances`] with a [`Partitioner`] `P`, [`LengthFunction`] `L`, and [`WorleyMode`] `W`.
+///
+/// The warp uses a different seed than the cells, so a `warp_strength` of 0 is exactly the same as the inner worley noise.
+/// Since the warp is evaluated at the same scale as the cells, strengths around 0.1 to 0.5 work well.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// use noiz::cell_noise::OrganicWorley;
+/// let noise = Noise::<OrganicWorley<Voronoi, EuclideanLength, WorleyLeastDistance>>::default();
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct OrganicWorley<P, L, W, N = MixCellGradients<OrthoGrid, Smoothstep, QuickGradients>> {
+    /// The worley noise sampled at the warped location.
+    pub worley: PerCellPointDistances<P, L, W>,
+    /// The [`RandomElements`] that displace the input.
+    pub warp: RandomElements<N>,
+    /// How far the input is displaced per unit of the warp.
+    pub warp_strength: f32,
+}
+
+impl<P: Default, L: Default, W: Default, N: Default> Default for OrganicWorley<P, L, W, N> {
+    fn default() -> Self {
+        Self {
+            worley: PerCellPointDistances::default(),
+            warp: RandomElements::default(),
+            warp_strength: 0.3,
+        }
+    }
+}
+
+impl<I: VectorSpace, P, L, W, N> NoiseFunction<I> for OrganicWorley<P, L, W, N>
+where
+    PerCellPointDistances<P, L, W>: NoiseFunction<I>,
+    RandomElements<N>: NoiseFunction<I, Output = I>,
+{
+    type Output = <PerCellPointDistances<P, L, W> as NoiseFunction<I>>::Output;
+
     #[inline]
-    fn get_gradient_dot(&self, seed: u32, offset: Vec4) -> f32 {
-        GradientGenerator::<Vec4>::get_gradient(self, seed).dot(offset)
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        let mut warp_seeds = *seeds;
+        warp_seeds.re_seed();
+        let offset = self.warp.evaluate(input, &mut warp_seeds) * self.warp_strength;
+        self.worley.evaluate(input + offset, seeds)
     }
+}
 
+impl<P, L, W, N> NoiseRange for OrganicWorley<P, L, W, N>
+where
+    PerCellPointDistances<P, L, W>: NoiseRange,
+{
     #[inline]
-    fn get_gradient(&self, seed: u32) -> Vec4 {
-        // SAFETY: Ensured by bit shift. Bit shift is better than bit and since the rng is cheap and puts more entropy in higher bits.
-        unsafe { *GRADIENT_TABLE.get_unchecked((seed >> 27) as usize) }
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        self.worley.output_range(input)
     }
 }
 
-/// A table of normalized gradient vectors.
-/// This is meant to fit in a single page of memory and be reused by any kind of vector.
-/// Only -1, 0, and 1 are used so that the float multiplication is faster.
-///
-/// The first 4 are usable in 2d; the first 16 are usable in 3d (first 4 are repeated in the last 4, so only 12 are unique)
+/// A [`NoiseFunction`] that works like [`PerCellPointDistances`], but stretches the cells along [`direction`](AnisotropicWorley::direction) by [`stretch`](AnisotropicWorley::stretch).
+/// This is useful for elongated cellular patterns like muscle fibers or wood grain.
 ///
-/// Inspired by similar tables in libnoise.
-const GRADIENT_TABLE: [Vec4; 32] = [
-    // 2d combinations (4)
-    Vec4::new(0.0, -1.0, -1.0, -1.0),
-    Vec4::new(0.0, 1.0, -1.0, -1.0),
-    Vec4::new(-1.0, 0.0, -1.0, -1.0),
-    Vec4::new(1.0, 0.0, -1.0, -1.0),
-    // 3d combinations (12, 8 more)
-    Vec4::new(0.0, -1.0, 1.0, -1.0),
-    Vec4::new(0.0, 1.0, 1.0, -1.0),
-    Vec4::new(-1.0, 0.0, 1.0, -1.0),
-    Vec4::new(1.0, 0.0, 1.0, -1.0),
-    // where z = 0
-    Vec4::new(1.0, 1.0, 0.0, -1.0),
-    Vec4::new(-1.0, 1.0, 0.0, -1.0),
-    Vec4::new(1.0, -1.0, 0.0, -1.0),
-    Vec4::new(-1.0, -1.0, 0.0, -1.0),
-    // 4d combinations (32, 20 more)
-    Vec4::new(0.0, -1.0, -1.0, 1.0), //
-    Vec4::new(0.0, 1.0, -1.0, 1.0),  //
-    Vec4::new(-1.0, 0.0, -1.0, 1.0), //
-    Vec4::new(1.0, 0.0, -1.0, 1.0), // These first 4 need 0 in x or y, so we can use binary ops to get the index for 3d.
-    Vec4::new(0.0, -1.0, 1.0, 1.0),
-    Vec4::new(0.0, 1.0, 1.0, 1.0),
-    Vec4::new(-1.0, 0.0, 1.0, 1.0),
-    Vec4::new(1.0, 0.0, 1.0, 1.0),
-    Vec4::new(1.0, 1.0, 0.0, 1.0),
-    Vec4::new(-1.0, 1.0, 0.0, 1.0),
-    Vec4::new(1.0, -1.0, 0.0, 1.0),
-    Vec4::new(-1.0, -1.0, 0.0, 1.0),
-    // where w = 0
-    Vec4::new(1.0, 1.0, 1.0, 0.0),
-    Vec4::new(1.0, 1.0, -1.0, 0.0),
-    Vec4::new(1.0, -1.0, 1.0, 0.0),
-    Vec4::new(1.0, -1.0, -1.0, 0.0),
-    Vec4::new(-1.0, 1.0, 1.0, 0.0),
-    Vec4::new(-1.0, 1.0, -1.0, 0.0),
-    Vec4::new(-1.0, -1.0, 1.0, 0.0),
-    Vec4::new(-1.0, -1.0, -1.0, 0.0),
-];
-
-/// A medium qualaty [`GradientGenerator`] that distributes normalized gradient vectors.
-/// This results in totally random gradient vectors (not from a lookup table).
+/// This works by shrinking each offset along `direction` before passing it to the [`WorleyMode`] `W` and [`LengthFunction`] `L`.
+/// A `stretch` of 1 is the same as [`PerCellPointDistances`].
+/// With a large `stretch`, the true nearest points may be outside the searched cells, causing discontinuities.
+/// If that happens, increase the `RADIUS` of [`Voronoi`](crate::cells::Voronoi).
 ///
-/// However, this is not uniform because it normalizes vectors *in* a square *onto* a circle (and so on for higher dimensions).
-/// That creates undesirable bunching of gradients.
-/// If you want to fix this (with a performance hit) see [`QualityGradients`].
-#[derive(Default, Clone, Copy, PartialEq, Eq)]
+/// ```
+/// # use noiz::prelude::*;
+/// use noiz::cell_noise::AnisotropicWorley;
+/// let noise = Noise::from(AnisotropicWorley {
+///     direction: bevy_math::Vec2::Y,
+///     stretch: 3.0,
+///     ..AnisotropicWorley::<Voronoi<false, OrthoGrid, 2>, EuclideanLength>::default()
+/// });
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+#[derive(Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub struct RandomGradients;
-
-macro_rules! impl_random_gradients {
-    ($t:ty) => {
-        impl GradientGenerator<$t> for RandomGradients {
-            #[inline]
-            fn get_gradient_dot(&self, seed: u32, offset: $t) -> f32 {
-                GradientGenerator::<$t>::get_gradient(self, seed).dot(offset)
-            }
-
-            #[inline]
-            fn get_gradient(&self, seed: u32) -> $t {
-                let v: $t = SNormSplit.linear_equivalent_value(seed);
-                v.normalize()
-            }
-        }
-    };
+pub struct AnisotropicWorley<P, L, W = WorleyLeastDistance> {
+    /// The [`Partitioner`].
+    pub cells: P,
+    /// The [`LengthFunction`].
+    pub length_mode: L,
+    /// The [`WorleyMode`].
+    pub worley_mode: W,
+    /// The direction to elongate cells along.
+    /// This must be normalized.
+    pub direction: Vec2,
+    /// How much to elongate cells along [`direction`](AnisotropicWorley::direction).
+    /// This must be positive. Values less than 1 squash the cells instead.
+    pub stretch: f32,
 }
 
-impl_random_gradients!(Vec2);
-impl_random_gradients!(Vec3);
-impl_random_gradients!(Vec3A);
-impl_random_gradients!(Vec4);
-
-/// A high quality (but slow) [`GradientGenerator`] that uniformly distributes normalized gradient vectors.
-/// Note that this is not yet implemented for [`Vec4`].
-// TODO: implement for 4d
-#[derive(Default, Clone, Copy, PartialEq, Eq)]
-#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
-#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
-#[cfg_attr(feature = "debug", derive(Debug))]
-pub struct QualityGradients;
-
-impl GradientGenerator<Vec2> for QualityGradients {
-    #[inline]
-    fn get_gradient_dot(&self, seed: u32, offset: Vec2) -> f32 {
-        GradientGenerator::<Vec2>::get_gradient(self, seed).dot(offset)
-    }
-
-    #[inline]
-    fn get_gradient(&self, seed: u32) -> Vec2 {
-        let angle: f32 = UNorm.any_value(seed);
-        Vec2::from_angle(angle * f32::consts::PI * 2.0)
+impl<P: Default, L: Default, W: Default> Default for AnisotropicWorley<P, L, W> {
+    fn default() -> Self {
+        Self {
+            cells: P::default(),
+            length_mode: L::default(),
+            worley_mode: W::default(),
+            direction: Vec2::X,
+            stretch: 1.0,
+        }
     }
 }
 
-impl GradientGenerator<Vec3> for QualityGradients {
-    #[inline]
-    fn get_gradient_dot(&self, seed: u32, offset: Vec3) -> f32 {
-        GradientGenerator::<Vec3>::get_gradient(self, seed).dot(offset)
-    }
+impl<L: LengthFunction<Vec2>, P: Partitioner<Vec2, Cell: WorleyDomainCell>, W: WorleyMode>
+    NoiseFunction<Vec2> for AnisotropicWorley<P, L, W>
+{
+    type Output = f32;
 
     #[inline]
-    fn get_gradient(&self, seed: u32) -> Vec3 {
-        let Vec2 { x, y } = UNorm.any_value(seed);
-        let theta = x * f32::consts::PI * 2.0;
-        let phi = y * f32::consts::PI;
-        Vec2::from_angle(theta).extend(phi.cos())
-    }
-}
+    fn evaluate(&self, input: Vec2, seeds: &mut NoiseRng) -> Self::Output {
+        let cell = self.cells.partition(input);
+        // Shrinking never makes offsets longer, but squashing can.
+        let growth = (1.0 / self.stretch).max(1.0);
+        let max_least_length = self
+            .length_mode
+            .max_for_element_max(cell.nearest_1d_point_always_within() * growth);
+        let max_next_least_length = self
+            .length_mode
+            .max_for_element_max(cell.next_nearest_1d_point_always_within() * growth);
 
-impl GradientGenerator<Vec3A> for QualityGradients {
-    #[inline]
-    fn get_gradient_dot(&self, seed: u32, offset: Vec3A) -> f32 {
-        GradientGenerator::<Vec3A>::get_gradient(self, seed).dot(offset)
+        let shrink = 1.0 / self.stretch - 1.0;
+        self.worley_mode.evaluate_worley(
+            cell.iter_points(*seeds)
+                .map(|p| p.offset + self.direction * (p.offset.dot(self.direction) * shrink)),
+            &self.length_mode,
+            max_least_length,
+            max_next_least_length,
+        )
     }
+}
 
+impl<P, L, W: WorleyMode> NoiseRange for AnisotropicWorley<P, L, W> {
     #[inline]
-    fn get_gradient(&self, seed: u32) -> Vec3A {
-        GradientGenerator::<Vec3>::get_gradient(self, seed).into()
+    fn output_range(&self, _input: OutputRange) -> OutputRange {
+        self.worley_mode.output_range()
     }
 }
 
-/// A [`ValueBlender`] that weighs each values by it's distance, as computed by a [`LengthFunction`].
+/// A [`NoiseFunction`] that mixes a value sourced from a [`ConcreteAnyValueFromBits`] `N` by a [`Curve`] `C` within some [`DomainCell`] form a [`Partitioner`] `P`.
 ///
-/// This is mainly used for fun worly noise:
+/// Usually, the [`ConcreteAnyValueFromBits`] will be a [`Random`](crate::rng::Random), ex `Random<UNorm, f32>`.
+///
+/// Here's an example of linear value noise:
 ///
 /// ```
 /// # use noiz::prelude::*;
-/// let noise = Noise::<BlendCellValues<Voronoi, DistanceBlend<ManhattanLength>, Random<UNorm, f32>>>::default();
+/// let noise = Noise::<MixCellValues<OrthoGrid, Linear, Random<SNorm, f32>>>::default();
 /// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
 /// ```
 ///
-#[derive(Default, Clone, Copy, PartialEq, Eq)]
+/// Usually linear doesn't look very good, so here's smoothstep:
+///
+/// ```
+/// # use noiz::prelude::*;
+/// let noise = Noise::<MixCellValues<OrthoGrid, Smoothstep, Random<SNorm, f32>>>::default();
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+///
+/// If you are interested in calculating the gradient of the noise as well, turn on `DIFFERENTIATE` (off by default).
+///
+/// ```
+/// # use noiz::prelude::*;
+/// let noise = Noise::<MixCellValues<OrthoGrid, Smoothstep, Random<SNorm, f32>, true>>::default();
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+///
+/// This is typically used with [`NormedByDerivative`](crate::layering::NormedByDerivative).
+#[derive(Default, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub struct DistanceBlend<L>(pub L);
+pub struct MixCellValues<P, C, N, const DIFFERENTIATE: bool = false> {
+    /// The [`Partitioner`].
+    pub cells: P,
+    /// The [`ConcreteAnyValueFromBits`].
+    pub noise: N,
+    /// The [`Curve`].
+    pub curve: C,
+}
 
-impl<V: Mul<f32, Output = V> + Default + AddAssign<V>, L: LengthFunction<I>, I: VectorSpace>
-    ValueBlender<I, V> for DistanceBlend<L>
+impl<
+    I: VectorSpace,
+    P: Partitioner<I, Cell: InterpolatableCell>,
+    C: Curve<f32>,
+    N: ConcreteAnyValueFromBits<Concrete: VectorSpace>,
+> NoiseFunction<I> for MixCellValues<P, C, N, false>
 {
+    type Output = N::Concrete;
+
     #[inline]
-    fn blend_values(&self, to_blend: impl Iterator<Item = (V, I)>, blending_half_radius: f32) -> V {
-        let mut sum = V::default();
-        let mut weight_sum = 0f32;
-        let mut cnt = 0;
-        let clamp_len = self.0.max_for_element_max(blending_half_radius);
-        for (val, weight) in to_blend {
-            let len = self.0.length_of(weight);
-            let weight = Smoothstep.sample_unchecked((clamp_len - len).max(0.0) / clamp_len);
-            sum += val * weight;
-            weight_sum += weight;
-            cnt += 1;
-        }
-        sum * (weight_sum / (cnt as f32 * clamp_len))
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        let segment = self.cells.partition(input);
+        let raw = segment.interpolate_within(
+            *seeds,
+            |point| self.noise.linear_equivalent_value(point.rough_id),
+            &self.curve,
+        );
+        self.noise.finish_linear_equivalent_value(raw)
     }
 }
 
-/// A blender that defers to another blender `T` and scales its blending radius by some value.
-/// This works with [`GradientBlender`], [`DifferentiableGradientBlender`], [`ValueBlender`], and [`DifferentiableValueBlender`].
+impl<P, C, N: NoiseRange> NoiseRange for MixCellValues<P, C, N, false> {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        // Interpolation never leaves the range of the values being interpolated.
+        self.noise.output_range(input)
+    }
+}
+
+/// A [`NoiseFunction`] that mixes a 4x4 neighborhood of values sourced from a [`ConcreteAnyValueFromBits`] `N` around each 2d [`SquareCell`] from a [`Partitioner`] `P` by cubic interpolation.
+///
+/// Where [`MixCellValues`] interpolates between the 4 corners of each cell, this uses the 12 surrounding lattice points too,
+/// fitting a Catmull-Rom spline along each axis. This gives value noise a continuous first derivative for any values, which makes it look less blocky.
+/// This matches the "value cubic" noise of other libraries.
+///
+/// Catmull-Rom splines can overshoot the values being interpolated, by at most 28.125% of their range in 2d.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// use noiz::cell_noise::CubicCellValues;
+/// let noise = Noise::<CubicCellValues<OrthoGrid, Random<UNorm, f32>>>::default();
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
 #[derive(Default, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub struct LocalBlend<T> {
-    /// The inner blender.
-    pub blender: T,
-    /// The scale of the blending radius.
-    /// Values in (0, 1) will decrease the blending, producing more localized values.
-    /// Values higher than 1 will produce discontinuities.
-    /// Negative values have no meaning (and may have either no effect or look really cool/strange).
-    pub radius_scale: f32,
+pub struct CubicCellValues<P, N> {
+    /// The [`Partitioner`].
+    pub cells: P,
+    /// The [`ConcreteAnyValueFromBits`].
+    pub noise: N,
 }
 
-impl<V, I: VectorSpace, B: ValueBlender<I, V>> ValueBlender<I, V> for LocalBlend<B> {
-    #[inline]
-    fn blend_values(&self, to_blend: impl Iterator<Item = (V, I)>, blending_half_radius: f32) -> V {
-        self.blender
-            .blend_values(to_blend, blending_half_radius * self.radius_scale)
-    }
+/// Evaluates the Catmull-Rom spline through `values` at `t`, between `values[1]` and `values[2]`.
+#[inline]
+fn catmull_rom<V: VectorSpace>(values: [V; 4], t: f32) -> V {
+    let t2 = t * t;
+    let t3 = t2 * t;
+    values[0] * (0.5 * (-t3 + 2.0 * t2 - t))
+        + values[1] * (0.5 * (3.0 * t3 - 5.0 * t2 + 2.0))
+        + values[2] * (0.5 * (-3.0 * t3 + 4.0 * t2 + t))
+        + values[3] * (0.5 * (t3 - t2))
 }
 
-impl<V, I: VectorSpace, B: DifferentiableValueBlender<I, V>> DifferentiableValueBlender<I, V>
-    for LocalBlend<B>
+impl<
+    W: WrappingAmount<IVec2>,
+    P: Partitioner<Vec2, Cell = SquareCell<Vec2, IVec2, W>>,
+    N: ConcreteAnyValueFromBits<Concrete: VectorSpace>,
+> NoiseFunction<Vec2> for CubicCellValues<P, N>
 {
+    type Output = N::Concrete;
+
     #[inline]
-    fn differential_blend_values(
-        &self,
-        to_blend: impl Iterator<Item = (V, I)>,
-        blending_half_radius: f32,
-    ) -> WithGradient<V, I> {
-        self.blender
-            .differential_blend_values(to_blend, blending_half_radius * self.radius_scale)
+    fn evaluate(&self, input: Vec2, seeds: &mut NoiseRng) -> Self::Output {
+        let cell = self.cells.partition(input);
+        let mut values = [N::Concrete::ZERO; 16];
+        for (value, point) in values.iter_mut().zip(cell.iter_cubic_points(*seeds)) {
+            *value = self.noise.linear_equivalent_value(point.rough_id);
+        }
+        let rows = [0, 1, 2, 3].map(|row| {
+            catmull_rom(
+                [
+                    values[row * 4],
+                    values[row * 4 + 1],
+                    values[row * 4 + 2],
+                    values[row * 4 + 3],
+                ],
+                cell.offset.x,
+            )
+        });
+        self.noise
+            .finish_linear_equivalent_value(catmull_rom(rows, cell.offset.y))
     }
 }
 
-impl<I: VectorSpace, B: GradientBlender<I>> GradientBlender<I> for LocalBlend<B> {
+impl<P, N: NoiseRange> NoiseRange for CubicCellValues<P, N> {
     #[inline]
-    fn blend_gradients(
-        &self,
-        to_blend: impl Iterator<Item = (I, I)>,
-        blending_half_radius: f32,
-    ) -> f32 {
-        self.blender
-            .blend_gradients(to_blend, blending_half_radius * self.radius_scale)
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        let values = self.noise.output_range(input);
+        let overshoot = (values.max - values.min) * 0.28125;
+        OutputRange {
+            min: values.min - overshoot,
+            max: values.max + overshoot,
+        }
     }
 }
 
-impl<I: VectorSpace, B: DifferentiableGradientBlender<I>> DifferentiableGradientBlender<I>
-    for LocalBlend<B>
+impl<
+    I: VectorSpace,
+    P: Partitioner<I, Cell: DifferentiableCell>,
+    C: SampleDerivative<f32>,
+    N: ConcreteAnyValueFromBits<Concrete: VectorSpace>,
+> NoiseFunction<I> for MixCellValues<P, C, N, true>
 {
+    type Output = WithGradient<N::Concrete, <P::Cell as DifferentiableCell>::Gradient<N::Concrete>>;
+
     #[inline]
-    fn differential_blend_gradients(
-        &self,
-        to_blend: impl Iterator<Item = (I, I)>,
-        blending_half_radius: f32,
-    ) -> WithGradient<f32, I> {
-        self.blender
-            .differential_blend_gradients(to_blend, blending_half_radius * self.radius_scale)
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        let segment = self.cells.partition(input);
+        let WithGradient { value, gradient } = segment.interpolate_with_gradient(
+            *seeds,
+            |point| self.noise.linear_equivalent_value(point.rough_id),
+            &self.curve,
+            self.noise.finishing_derivative(),
+        );
+        WithGradient {
+            value: self.noise.finish_linear_equivalent_value(value),
+            gradient,
+        }
     }
 }
 
-/// A [`GradientBlender`] and [`ValueBlender`] built for the [`SimplexGrid`](crate::cells::SimplexGrid) for simplex noise that smoothly blends values in a pleasant way.
-/// This also implements [`DifferentiableGradientBlender`] and [`DifferentiableValueBlender`].
+/// A [`NoiseFunction`] that produces the [`CellCornerWeights`] of the [`SquareCell`] from an [`OrthoGrid`](crate::cells::OrthoGrid)-like [`Partitioner`] `P`, using the [`Curve`] `C` to make the weights.
 ///
-/// This can also be used to make "even" blending in [`BlendCellValues`].
-/// If you're not sure which blender to use, start with this one.
-#[derive(Default, Clone, Copy, PartialEq, Eq)]
+/// This exposes what [`MixCellValues`] does internally, so you can do the blending yourself.
+/// For example, this is useful for blending textures in a splat map.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use noiz::cell_noise::{CellBlendWeights, CellCornerWeights};
+/// let noise = Noise::<CellBlendWeights<OrthoGrid, Smoothstep>>::default();
+/// let corners = noise.sample_for::<CellCornerWeights>(bevy_math::Vec2::new(1.5, -2.5));
+/// ```
+#[derive(Default, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub struct SimplecticBlend;
+pub struct CellBlendWeights<P, C> {
+    /// The [`Partitioner`].
+    pub cells: P,
+    /// The [`Curve`] used to make the weights.
+    pub curve: C,
+}
 
-#[inline]
-fn general_simplex_weight(length_sqrd: f32, blending_half_radius: f32) -> f32 {
-    // We do the unorm mapping here instead of later to prevent precision issues.
-    let weight_unorm = (blending_half_radius - length_sqrd) / blending_half_radius;
-    if weight_unorm <= 0.0 {
-        0.0
-    } else {
-        let s = weight_unorm * weight_unorm;
-        s * s
+/// The output of [`CellBlendWeights`].
+///
+/// Each array lists the corners of the cell in the order: (0, 0), (0, 1), (1, 0), (1, 1).
+#[derive(Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct CellCornerWeights {
+    /// The [`CellPoint::rough_id`](crate::cells::CellPoint::rough_id) of each corner.
+    pub rough_ids: [u32; 4],
+    /// The weight of each corner. These always sum to 1.
+    pub weights: [f32; 4],
+}
+
+impl<
+    W: WrappingAmount<IVec2>,
+    P: Partitioner<Vec2, Cell = SquareCell<Vec2, IVec2, W>>,
+    C: Curve<f32>,
+> NoiseFunction<Vec2> for CellBlendWeights<P, C>
+{
+    type Output = CellCornerWeights;
+
+    #[inline]
+    fn evaluate(&self, input: Vec2, seeds: &mut NoiseRng) -> Self::Output {
+        let cell = self.cells.partition(input);
+        let mut rough_ids = [0; 4];
+        for (id, point) in rough_ids.iter_mut().zip(cell.iter_points(*seeds)) {
+            *id = point.rough_id;
+        }
+        let mix = cell.offset.map(|t| self.curve.sample_unchecked(t));
+        let inv = Vec2::ONE - mix;
+        CellCornerWeights {
+            rough_ids,
+            weights: [inv.x * inv.y, inv.x * mix.y, mix.x * inv.y, mix.x * mix.y],
+        }
     }
 }
 
-#[inline]
-fn general_simplex_weight_derivative(length_sqrd: f32, blending_half_radius: f32) -> f32 {
-    // We do the unorm mapping here instead of later to prevent precision issues.
-    let weight_unorm = (blending_half_radius - length_sqrd) / blending_half_radius;
-    if weight_unorm <= 0.0 {
-        0.0
-    } else {
-        (weight_unorm * weight_unorm) * (weight_unorm * 4.0)
+/// A [`NoiseFunction`] that produces the interpolation weights of the [`SquareCell`] from an [`OrthoGrid`](crate::cells::OrthoGrid)-like [`Partitioner`] `P`, along with their derivatives, using the [`Curve`] `C`.
+/// The output is a [`Vec4`] of the x weight, the y weight, the x derivative, and the y derivative, from [`SquareCell::interpolation_weights`].
+///
+/// This is a debugging tool for differentiable interpolation, like in [`MixCellValues`] with `DIFFERENTIATE` on.
+/// Visualizing it confirms that the curve is applied as expected.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use noiz::cell_noise::CellInterpolationWeights;
+/// let noise = Noise::<CellInterpolationWeights<OrthoGrid, Smoothstep>>::default();
+/// let weights = noise.sample_for::<Vec4>(Vec2::new(1.5, -2.5));
+/// ```
+#[derive(Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct CellInterpolationWeights<P, C> {
+    /// The [`Partitioner`].
+    pub cells: P,
+    /// The [`Curve`] used to make the weights.
+    pub curve: C,
+}
+
+impl<
+    W: WrappingAmount<IVec2>,
+    P: Partitioner<Vec2, Cell = SquareCell<Vec2, IVec2, W>>,
+    C: SampleDerivative<f32>,
+> NoiseFunction<Vec2> for CellInterpolationWeights<P, C>
+{
+    type Output = Vec4;
+
+    #[inline]
+    fn evaluate(&self, input: Vec2, _seeds: &mut NoiseRng) -> Self::Output {
+        let [x, y] = self
+            .cells
+            .partition(input)
+            .interpolation_weights(&self.curve);
+        Vec4::new(x.value, y.value, x.gradient, y.gradient)
     }
 }
 
-macro_rules! impl_simplectic_blend {
-    ($i:ty, $c:literal) => {
-        impl<V: Mul<f32, Output = V> + Default + AddAssign<V> + Copy> ValueBlender<$i, V>
-            for SimplecticBlend
-        where
-            $i: Mul<V, Output = $i> + Mul<f32, Output = $i>,
-        {
-            #[inline]
-            fn blend_values(
-                &self,
-                to_blend: impl Iterator<Item = (V, $i)>,
-                blending_half_radius: f32,
-            ) -> V {
-                let mut sum = V::default();
-                for (val, weight) in to_blend {
-                    sum +=
-                        val * general_simplex_weight(weight.length_squared(), blending_half_radius);
-                }
-                sum
-            }
+/// A [`NoiseFunction`] that works just like [`MixCellValues`] with `DIFFERENTIATE` on, but also computes the [Hessian](WithHessian), the second partial derivatives.
+/// This uses the [`SampleTwoDerivatives`] of the [`Curve`] `C`, so `C` should have a continuous second derivative, like [`DoubleSmoothstep`](crate::curves::DoubleSmoothstep).
+/// This is only implemented for [`OrthoGrid`](crate::cells::OrthoGrid)-like [`Partitioner`]s in 2d.
+///
+/// This is useful for detecting ridges and valleys, for example, via [`Laplacian`](crate::math_noise::Laplacian):
+///
+/// ```
+/// # use noiz::prelude::*;
+/// use noiz::{cell_noise::MixCellValuesHessian, curves::DoubleSmoothstep, math_noise::Laplacian};
+/// let noise = Noise::<(
+///     MixCellValuesHessian<OrthoGrid, DoubleSmoothstep, Random<SNorm, f32>>,
+///     Laplacian,
+/// )>::default();
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+#[derive(Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct MixCellValuesHessian<P, C, N> {
+    /// The [`Partitioner`].
+    pub cells: P,
+    /// The [`ConcreteAnyValueFromBits`].
+    pub noise: N,
+    /// The [`Curve`].
+    pub curve: C,
+}
+
+impl<
+    W: WrappingAmount<IVec2>,
+    P: Partitioner<Vec2, Cell = SquareCell<Vec2, IVec2, W>>,
+    C: SampleTwoDerivatives<f32>,
+    N: ConcreteAnyValueFromBits<Concrete = f32>,
+> NoiseFunction<Vec2> for MixCellValuesHessian<P, C, N>
+{
+    type Output = WithHessian<f32, Vec2, Mat2>;
+
+    #[inline]
+    fn evaluate(&self, input: Vec2, seeds: &mut NoiseRng) -> Self::Output {
+        let cell = self.cells.partition(input);
+        let mut corners = [0.0; 4];
+        for (corner, point) in corners.iter_mut().zip(cell.iter_points(*seeds)) {
+            *corner = self.noise.linear_equivalent_value(point.rough_id);
         }
+        let [ld, lu, rd, ru] = corners;
+        let mix_x = self
+            .curve
+            .sample_with_two_derivatives_unchecked(cell.offset.x);
+        let mix_y = self
+            .curve
+            .sample_with_two_derivatives_unchecked(cell.offset.y);
+
+        // bilinear coefficients
+        let along_x = rd - ld;
+        let along_y = lu - ld;
+        let cross = ld - rd - lu + ru;
+        let slope_x = along_x + cross * mix_y.value;
+        let slope_y = along_y + cross * mix_x.value;
+
+        let raw =
+            ld + along_x * mix_x.value + along_y * mix_y.value + cross * mix_x.value * mix_y.value;
+        let scale = self.noise.finishing_derivative();
+        let dxy = cross * mix_x.derivative * mix_y.derivative * scale;
+        WithHessian {
+            value: self.noise.finish_linear_equivalent_value(raw),
+            gradient: Vec2::new(slope_x * mix_x.derivative, slope_y * mix_y.derivative) * scale,
+            hessian: Mat2::from_cols(
+                Vec2::new(slope_x * mix_x.second_derivative * scale, dxy),
+                Vec2::new(dxy, slope_y * mix_y.second_derivative * scale),
+            ),
+        }
+    }
+}
 
-        impl<V: Mul<f32, Output = V> + Default + AddAssign<V> + Copy>
-            DifferentiableValueBlender<$i, V> for SimplecticBlend
-        where
-            $i: Mul<V, Output = $i> + Mul<f32, Output = $i>,
-        {
-            #[inline]
-            fn differential_blend_values(
-                &self,
-                to_blend: impl Iterator<Item = (V, $i)>,
-                blending_half_radius: f32,
-            ) -> WithGradient<V, $i> {
-                let mut sum = WithGradient {
-                    value: V::default(),
-                    gradient: <$i>::ZERO,
-                };
-                for (val, weight) in to_blend {
-                    let len_sqr = weight.length_squared();
-                    let falloff = general_simplex_weight(len_sqr, blending_half_radius);
-                    let d_falloff = weight
-                        * general_simplex_weight_derivative(len_sqr, blending_half_radius)
-                        * (-2.0 / blending_half_radius); // chain rule
-                    sum.value += val * falloff;
-                    sum.gradient += d_falloff * val;
-                }
-                sum
-            }
+/// A [`NoiseFunction`] that works just like [`MixCellValues`], but uses a different [`Curve`] per axis: `Cx` for x and `Cy` for y.
+/// This is only implemented for [`OrthoGrid`](crate::cells::OrthoGrid)-like [`Partitioner`]s in 2d.
+///
+/// This is useful for anisotropic smoothing, for example, to keep the noise sharp along x but smooth along y:
+///
+/// ```
+/// # use noiz::prelude::*;
+/// use noiz::{cell_noise::MixCellValuesAniso, curves::Linear};
+/// let noise = Noise::<MixCellValuesAniso<OrthoGrid, Linear, Smoothstep, Random<SNorm, f32>>>::default();
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+#[derive(Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct MixCellValuesAniso<P, Cx, Cy, N> {
+    /// The [`Partitioner`].
+    pub cells: P,
+    /// The [`ConcreteAnyValueFromBits`].
+    pub noise: N,
+    /// The [`Curve`] for the x axis.
+    pub curve_x: Cx,
+    /// The [`Curve`] for the y axis.
+    pub curve_y: Cy,
+}
+
+impl<
+    W: WrappingAmount<IVec2>,
+    P: Partitioner<Vec2, Cell = SquareCell<Vec2, IVec2, W>>,
+    Cx: Curve<f32>,
+    Cy: Curve<f32>,
+    N: ConcreteAnyValueFromBits<Concrete: VectorSpace>,
+> NoiseFunction<Vec2> for MixCellValuesAniso<P, Cx, Cy, N>
+{
+    type Output = N::Concrete;
+
+    #[inline]
+    fn evaluate(&self, input: Vec2, seeds: &mut NoiseRng) -> Self::Output {
+        let cell = self.cells.partition(input);
+        let mut corners = [N::Concrete::ZERO; 4];
+        for (corner, point) in corners.iter_mut().zip(cell.iter_points(*seeds)) {
+            *corner = self.noise.linear_equivalent_value(point.rough_id);
+        }
+        let [ld, lu, rd, ru] = corners;
+        let mix_x = self.curve_x.sample_unchecked(cell.offset.x);
+        let mix_y = self.curve_y.sample_unchecked(cell.offset.y);
+
+        // lerp
+        let l = ld.lerp(lu, mix_y);
+        let r = rd.lerp(ru, mix_y);
+        self.noise.finish_linear_equivalent_value(l.lerp(r, mix_x))
+    }
+}
+
+impl<P, Cx, Cy, N: NoiseRange> NoiseRange for MixCellValuesAniso<P, Cx, Cy, N> {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        // Interpolation never leaves the range of the values being interpolated.
+        self.noise.output_range(input)
+    }
+}
+
+/// A [`NoiseFunction`] that mixes a value sourced from a [`AnyValueFromBits`] `N` by a [`Curve`] `C` within some [`DomainCell`] form a [`Partitioner`] `P`.
+///
+/// This is similar to [`MixCellValues`] but more restricted. Instead of taking a [`ConcreteAnyValueFromBits`], this takes the more general [`AnyValueFromBits`].
+/// That allows the output of the noise to depend on the input's type!
+///
+/// This is most commonly used for very fast domain warping:
+///
+/// ```
+/// # use noiz::prelude::*;
+/// use noiz::cell_noise::MixCellValuesForDomain;
+/// let noise = Noise::<(
+///     Offset<MixCellValuesForDomain<OrthoGrid, Smoothstep, SNorm>>,
+///     common_noise::Perlin,
+/// )>::default();
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+///
+/// See also [`DomainWarp`](crate::layering::DomainWarp) and [`RandomElements`] as alternatives.
+///
+/// This also supports `DIFFERENTIATE` (off by default) similar to [`MixCellValues`], but this is not as useful here.
+#[derive(Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct MixCellValuesForDomain<P, C, N, const DIFFERENTIATE: bool = false> {
+    /// The [`Partitioner`].
+    pub cells: P,
+    /// The [`ConcreteAnyValueFromBits`].
+    pub noise: N,
+    /// The [`Curve`].
+    pub curve: C,
+}
+
+impl<
+    I: VectorSpace,
+    P: Partitioner<I, Cell: InterpolatableCell>,
+    C: Curve<f32>,
+    N: AnyValueFromBits<I>,
+> NoiseFunction<I> for MixCellValuesForDomain<P, C, N, false>
+{
+    type Output = I;
+
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        let segment = self.cells.partition(input);
+        let raw = segment.interpolate_within(
+            *seeds,
+            |point| self.noise.linear_equivalent_value(point.rough_id),
+            &self.curve,
+        );
+        self.noise.finish_linear_equivalent_value(raw)
+    }
+}
+
+impl<
+    I: VectorSpace,
+    P: Partitioner<I, Cell: DifferentiableCell>,
+    C: SampleDerivative<f32>,
+    N: AnyValueFromBits<I>,
+> NoiseFunction<I> for MixCellValuesForDomain<P, C, N, true>
+{
+    type Output = WithGradient<I, <P::Cell as DifferentiableCell>::Gradient<I>>;
+
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        let segment = self.cells.partition(input);
+        let WithGradient { value, gradient } = segment.interpolate_with_gradient(
+            *seeds,
+            |point| self.noise.linear_equivalent_value(point.rough_id),
+            &self.curve,
+            self.noise.finishing_derivative(),
+        );
+        WithGradient {
+            value: self.noise.finish_linear_equivalent_value(value),
+            gradient,
+        }
+    }
+}
+
+/// Allows blending between different values `V` where each values corresponds to a [`CellPoint<I>`](crate::cells::CellPoint).
+pub trait ValueBlender<I: VectorSpace, V> {
+    /// Blends together each value `V` of `to_blend` according to some weight `I`, where weights beyond the range of `blending_half_radius` are ignored.
+    /// `blending_half_radius` cuts off the blend before it hits discontinuities.
+    fn blend_values(&self, to_blend: impl Iterator<Item = (V, I)>, blending_half_radius: f32) -> V;
+}
+
+/// Same as [`ValueBlender`] but also differentiates the result.
+pub trait DifferentiableValueBlender<I: VectorSpace, V>: ValueBlender<I, V> {
+    /// Same as [`blend_values`](ValueBlender::blend_values) but also differentiates the result.
+    fn differential_blend_values(
+        &self,
+        to_blend: impl Iterator<Item = (V, I)>,
+        blending_half_radius: f32,
+    ) -> WithGradient<V, I>;
+}
+
+/// Similar to [`ValueBlender`] but specialized for blending gradient dot products.
+/// In other words, the weight has a role in the value, even before blending.
+pub trait GradientBlender<I: VectorSpace> {
+    /// Blends between gradient and offset pairs, returning a dot product blended value.
+    fn blend_gradients(
+        &self,
+        to_blend: impl Iterator<Item = (I, I)>,
+        blending_half_radius: f32,
+    ) -> f32;
+}
+
+/// Same as [`GradientBlender`] but also differentiates the result.
+pub trait DifferentiableGradientBlender<I: VectorSpace>: GradientBlender<I> {
+    /// Same as [`blend_gradients`](GradientBlender::blend_gradients) but also differentiates the result.
+    fn differential_blend_gradients(
+        &self,
+        to_blend: impl Iterator<Item = (I, I)>,
+        blending_half_radius: f32,
+    ) -> WithGradient<f32, I>;
+}
+
+/// A [`NoiseFunction`] that blends values sourced from a [`ConcreteAnyValueFromBits`] `N` by a [`ValueBlender`] `B` within some [`DomainCell`] form a [`Partitioner`] `P`.
+///
+/// This results in smooth blending between values. Note that this does *not* mix between values; it only blends them together so there isn't a sharp jump.
+/// To see this clearly, run the "show_noise" example.
+///
+/// Here's a way to hexagonally stacked circular values together:
+///
+/// ```
+/// # use noiz::prelude::*;
+/// let noise = Noise::<BlendCellValues<SimplexGrid, SimplecticBlend, Random<UNorm, f32>>>::default();
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+///
+/// You can also use this to make fun stars:
+///
+/// ```
+/// # use noiz::prelude::*;
+/// let noise = Noise::<BlendCellValues<Voronoi, DistanceBlend<ManhattanLength>, Random<UNorm, f32>>>::default();
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+///
+/// If you are interested in calculating the gradient of the noise as well, turn on `DIFFERENTIATE` (off by default), and use a [`DifferentiableValueBlender`].
+///
+/// ```
+/// # use noiz::prelude::*;
+/// let noise = Noise::<BlendCellValues<SimplexGrid, SimplecticBlend, Random<UNorm, f32>, true>>::default();
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+///
+/// This is typically used with [`NormedByDerivative`](crate::layering::NormedByDerivative).
+#[derive(Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct BlendCellValues<P, B, N, const DIFFERENTIATE: bool = false> {
+    /// The [`Partitioner`].
+    pub cells: P,
+    /// The [`ConcreteAnyValueFromBits`].
+    pub noise: N,
+    /// The [`ValueBlender`].
+    pub blender: B,
+}
+
+impl<
+    I: VectorSpace,
+    P: Partitioner<I, Cell: BlendableDomainCell>,
+    B: ValueBlender<I, N::Concrete>,
+    N: ConcreteAnyValueFromBits,
+> NoiseFunction<I> for BlendCellValues<P, B, N, false>
+{
+    type Output = N::Concrete;
+
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        self.evaluate_cell(&self.cells.partition(input), seeds)
+    }
+}
+
+impl<C: BlendableDomainCell, P, B: ValueBlender<C::Full, N::Concrete>, N: ConcreteAnyValueFromBits>
+    CellFunction<C> for BlendCellValues<P, B, N, false>
+{
+    type Output = N::Concrete;
+
+    #[inline]
+    fn evaluate_cell(&self, cell: &C, seeds: &mut NoiseRng) -> Self::Output {
+        let to_blend = cell.iter_points(*seeds).map(|p| {
+            // We can't use the `linear_equivalent_value` because the blend type is not linear.
+            let value = self.noise.any_value(p.rough_id);
+            (value, p.offset)
+        });
+        self.blender
+            .blend_values(to_blend, cell.blending_half_radius())
+    }
+}
+
+impl<
+    I: VectorSpace + Mul<N::Concrete, Output = I>,
+    P: Partitioner<I, Cell: BlendableDomainCell>,
+    B: DifferentiableValueBlender<I, N::Concrete>,
+    N: ConcreteAnyValueFromBits<Concrete: Copy>,
+> NoiseFunction<I> for BlendCellValues<P, B, N, true>
+{
+    type Output = WithGradient<N::Concrete, I>;
+
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        let cell = self.cells.partition(input);
+        let to_blend = cell.iter_points(*seeds).map(|p| {
+            // We can't use the `linear_equivalent_value` because the blend type is not linear.
+            let value = self.noise.any_value(p.rough_id);
+            (value, p.offset)
+        });
+        self.blender
+            .differential_blend_values(to_blend, cell.blending_half_radius())
+    }
+}
+
+/// A [`NoiseFunction`] that blends between two independent sets of cell values, `first` and `second`, by a `control` noise function.
+/// This is like two [`BlendCellValues`] lerped together, but it only partitions the domain once.
+///
+/// The `control` should output values in 0..=1, where 0 produces only `first` and 1 produces only `second`.
+/// The two value sets draw from different random bits, so they are independent even when `N1` and `N2` are the same type.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use noiz::cell_noise::BlendTwoCellValues;
+/// let noise = Noise::<
+///     BlendTwoCellValues<
+///         SimplexGrid,
+///         SimplecticBlend,
+///         Random<UNorm, f32>,
+///         Random<SNorm, f32>,
+///         common_noise::Value,
+///     >,
+/// >::default();
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+#[derive(Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct BlendTwoCellValues<P, B, N1, N2, C> {
+    /// The [`Partitioner`].
+    pub cells: P,
+    /// The [`ValueBlender`].
+    pub blender: B,
+    /// The [`ConcreteAnyValueFromBits`] used when `control` is 0.
+    pub first: N1,
+    /// The [`ConcreteAnyValueFromBits`] used when `control` is 1.
+    pub second: N2,
+    /// The [`NoiseFunction`] that picks how much of `second` to use.
+    pub control: C,
+}
+
+impl<
+    I: VectorSpace,
+    P: Partitioner<I, Cell: BlendableDomainCell>,
+    B: ValueBlender<I, N1::Concrete>,
+    N1: ConcreteAnyValueFromBits<Concrete: VectorSpace>,
+    N2: ConcreteAnyValueFromBits<Concrete = N1::Concrete>,
+    C: NoiseFunction<I, Output = f32>,
+> NoiseFunction<I> for BlendTwoCellValues<P, B, N1, N2, C>
+{
+    type Output = N1::Concrete;
+
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        let mut second_seeds = *seeds;
+        second_seeds.re_seed();
+        let mut control_seeds = second_seeds;
+        control_seeds.re_seed();
+        let t = self.control.evaluate(input, &mut control_seeds);
+
+        let cell = self.cells.partition(input);
+        let to_blend = cell.iter_points(*seeds).map(|p| {
+            let first = self.first.any_value(p.rough_id);
+            let second = self.second.any_value(second_seeds.rand_u32(p.rough_id));
+            (first.lerp(second, t), p.offset)
+        });
+        self.blender
+            .blend_values(to_blend, cell.blending_half_radius())
+    }
+}
+
+/// This trait facilitates generating gradients and computing their dot products.
+///
+/// If you're not sure which one to use, try [`QuickGradients`], a fast lookup table.
+pub trait GradientGenerator<I: VectorSpace> {
+    /// Gets the dot product of `I` with some gradient vector based on this seed.
+    /// Each element of `offset` can be assumed to be in -1..=1.
+    /// The dot product should be in (-1,1).
+    fn get_gradient_dot(&self, seed: u32, offset: I) -> f32;
+
+    /// Gets the gradient that would be used in [`get_gradient_dot`](GradientGenerator::get_gradient_dot).
+    fn get_gradient(&self, seed: u32) -> I;
+}
+
+/// A [`NoiseFunction`] that mixes gradients sourced from a [`GradientGenerator`] `G` by a [`Curve`] `C` within some [`DomainCell`] form a [`Partitioner`] `P`.
+///
+/// This is most commonly used for perlin noise:
+///
+/// ```
+/// # use noiz::prelude::*;
+/// let noise = Noise::<MixCellGradients<OrthoGrid, Smoothstep, QuickGradients>>::default();
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+///
+/// If you need smoother derivatives (ex: to compute analytical normals), try a smoother [`Curve`] like [`DoubleSmoothstep`](crate::curves::DoubleSmoothstep).
+/// Note that [`Linear`](crate::curves::Linear) is not appealing here.
+///
+/// If you are interested in calculating the gradient of the noise as well, turn on `DIFFERENTIATE` (off by default).
+///
+/// ```
+/// # use noiz::prelude::*;
+/// let noise = Noise::<MixCellGradients<OrthoGrid, Smoothstep, QuickGradients, true>>::default();
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+///
+/// This is typically used with [`NormedByDerivative`](crate::layering::NormedByDerivative).
+#[derive(Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct MixCellGradients<P, C, G, const DIFFERENTIATE: bool = false> {
+    /// The [`Partitioner`].
+    pub cells: P,
+    /// The [`GradientGenerator`].
+    pub gradients: G,
+    /// The [`Curve`].
+    pub curve: C,
+}
+
+impl<
+    I: ElementalVectorSpace,
+    P: Partitioner<I, Cell: InterpolatableCell>,
+    C: Curve<f32>,
+    G: GradientGenerator<I>,
+> NoiseFunction<I> for MixCellGradients<P, C, G, false>
+{
+    type Output = f32;
+
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        let segment = self.cells.partition(input);
+        segment.interpolate_within(
+            *seeds,
+            |point| {
+                self.gradients
+                    .get_gradient_dot(point.rough_id, point.offset)
+                    * I::SQRT_NUM_ELEMENTS
+            },
+            &self.curve,
+        )
+    }
+}
+
+impl<P, C, G> NoiseRange for MixCellGradients<P, C, G, false> {
+    #[inline]
+    fn output_range(&self, _input: OutputRange) -> OutputRange {
+        OutputRange::SNORM
+    }
+}
+
+/// A [`NoiseFunction`] that blends between value noise and perlin noise by [`mix`](PerlinValueMix::mix), like a slider between their characters.
+/// A `mix` of 0 is the same as [`MixCellValues`] with the values `N`, and a `mix` of 1 is the same as [`MixCellGradients`] with the [`GradientGenerator`] `G`.
+/// Values in between combine the blobby look of value noise with the directional look of perlin noise.
+///
+/// Both are computed from one partition of some [`Partitioner`] `P` and interpolated together by the [`Curve`] `C` in a single pass,
+/// so this is much cheaper than evaluating both noise functions separately.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// use noiz::cell_noise::PerlinValueMix;
+/// let noise = Noise::from(PerlinValueMix::<OrthoGrid> {
+///     mix: 0.25,
+///     ..Default::default()
+/// });
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct PerlinValueMix<P = OrthoGrid, C = Smoothstep, G = QuickGradients, N = Random<SNorm, f32>>
+{
+    /// The [`Partitioner`].
+    pub cells: P,
+    /// The [`Curve`].
+    pub curve: C,
+    /// The [`GradientGenerator`] for the perlin noise.
+    pub gradients: G,
+    /// The [`ConcreteAnyValueFromBits`] for the value noise.
+    pub values: N,
+    /// How much of the result comes from perlin noise instead of value noise, between 0 and 1.
+    /// Defaults to 0.5.
+    pub mix: f32,
+}
+
+impl<P: Default, C: Default, G: Default, N: Default> Default for PerlinValueMix<P, C, G, N> {
+    fn default() -> Self {
+        Self {
+            cells: P::default(),
+            curve: C::default(),
+            gradients: G::default(),
+            values: N::default(),
+            mix: 0.5,
+        }
+    }
+}
+
+impl<
+    I: ElementalVectorSpace,
+    P: Partitioner<I, Cell: InterpolatableCell>,
+    C: Curve<f32>,
+    G: GradientGenerator<I>,
+    N: ConcreteAnyValueFromBits<Concrete = f32>,
+> NoiseFunction<I> for PerlinValueMix<P, C, G, N>
+{
+    type Output = f32;
+
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        let segment = self.cells.partition(input);
+        // x is the value noise, and y is the perlin noise.
+        let raw = segment.interpolate_within(
+            *seeds,
+            |point| {
+                Vec2::new(
+                    self.values.linear_equivalent_value(point.rough_id),
+                    self.gradients
+                        .get_gradient_dot(point.rough_id, point.offset)
+                        * I::SQRT_NUM_ELEMENTS,
+                )
+            },
+            &self.curve,
+        );
+        let value = self.values.finish_linear_equivalent_value(raw.x);
+        value + (raw.y - value) * self.mix
+    }
+}
+
+impl<P, C, G, N: NoiseRange> NoiseRange for PerlinValueMix<P, C, G, N> {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        // A blend never leaves the combined range of what is being blended.
+        let values = self.values.output_range(input);
+        OutputRange {
+            min: values.min.min(-1.0),
+            max: values.max.max(1.0),
+        }
+    }
+}
+
+impl<
+    I: ElementalVectorSpace,
+    P: Partitioner<I, Cell: DifferentiableCell<Gradient<f32>: Into<I>>>,
+    C: SampleDerivative<f32>,
+    G: GradientGenerator<I>,
+> NoiseFunction<I> for MixCellGradients<P, C, G, true>
+{
+    type Output = WithGradient<f32, I>;
+
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        let segment = self.cells.partition(input);
+        let gradients = segment.interpolate_within(
+            *seeds,
+            |point| self.gradients.get_gradient(point.rough_id) * I::SQRT_NUM_ELEMENTS,
+            &self.curve,
+        );
+        let WithGradient { value, gradient } = segment.interpolate_with_gradient(
+            *seeds,
+            |point| {
+                self.gradients
+                    .get_gradient_dot(point.rough_id, point.offset)
+                    * I::SQRT_NUM_ELEMENTS
+            },
+            &self.curve,
+            1.0,
+        );
+        WithGradient {
+            value,
+            gradient: gradient.into() + gradients,
+        }
+    }
+}
+
+/// A [`NoiseFunction`] that mixes the gradient vectors themselves, sourced from a [`GradientGenerator`] `G`, by a [`Curve`] `C` within some [`DomainCell`] form a [`Partitioner`] `P`.
+/// Where [`MixCellGradients`] produces the interpolated dot products (perlin noise), this produces a smooth vector field.
+/// This is useful for flow maps and similar directional data.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// use noiz::cell_noise::CellGradientField;
+/// let noise = Noise::<CellGradientField<OrthoGrid, Smoothstep, QuickGradients>>::default();
+/// let flow: bevy_math::Vec2 = noise.sample(bevy_math::Vec2::new(1.5, -0.5));
+/// ```
+#[derive(Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct CellGradientField<P, C, G> {
+    /// The [`Partitioner`].
+    pub cells: P,
+    /// The [`GradientGenerator`].
+    pub gradients: G,
+    /// The [`Curve`].
+    pub curve: C,
+}
+
+impl<
+    I: VectorSpace,
+    P: Partitioner<I, Cell: InterpolatableCell>,
+    C: Curve<f32>,
+    G: GradientGenerator<I>,
+> NoiseFunction<I> for CellGradientField<P, C, G>
+{
+    type Output = I;
+
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        let segment = self.cells.partition(input);
+        segment.interpolate_within(
+            *seeds,
+            |point| self.gradients.get_gradient(point.rough_id),
+            &self.curve,
+        )
+    }
+}
+
+/// A [`NoiseFunction`] that blends gradients sourced from a [`GradientGenerator`] `G` by a [`GradientBlender`] `B` within some [`DomainCell`] form a [`Partitioner`] `P`.
+///
+/// This is typically used for simplex noise:
+///
+/// ```
+/// # use noiz::prelude::*;
+/// let noise = Noise::<BlendCellGradients<SimplexGrid, SimplecticBlend, QuickGradients>>::default();
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+///
+/// If you are interested in calculating the gradient of the noise as well, turn on `DIFFERENTIATE` (off by default), and use a  [`DifferentiableGradientBlender`].
+///
+/// ```
+/// # use noiz::prelude::*;
+/// let noise = Noise::<BlendCellGradients<SimplexGrid, SimplecticBlend, QuickGradients, true>>::default();
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+///
+/// This is typically used with [`NormedByDerivative`](crate::layering::NormedByDerivative).
+#[derive(Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct BlendCellGradients<P, B, G, const DIFFERENTIATE: bool = false> {
+    /// The [`Partitioner`].
+    pub cells: P,
+    /// The [`GradientGenerator`].
+    pub gradients: G,
+    /// The [`GradientBlender`].
+    pub blender: B,
+}
+
+impl<
+    I: VectorSpace,
+    P: Partitioner<I, Cell: BlendableDomainCell>,
+    B: GradientBlender<I>,
+    G: GradientGenerator<I>,
+> NoiseFunction<I> for BlendCellGradients<P, B, G, false>
+{
+    type Output = f32;
+
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        let cell = self.cells.partition(input);
+        let to_blend = cell.iter_points(*seeds).map(|p| {
+            let grad = self.gradients.get_gradient(p.rough_id);
+            (grad, p.offset)
+        });
+        let radius = cell.blending_half_radius();
+        self.blender.blend_gradients(to_blend, radius)
+    }
+}
+
+impl<P, G> NoiseRange for BlendCellGradients<P, SimplecticBlend, G, false> {
+    #[inline]
+    fn output_range(&self, _input: OutputRange) -> OutputRange {
+        OutputRange::SNORM
+    }
+}
+
+impl<
+    I: VectorSpace,
+    P: Partitioner<I, Cell: BlendableDomainCell>,
+    B: DifferentiableGradientBlender<I>,
+    G: GradientGenerator<I>,
+> NoiseFunction<I> for BlendCellGradients<P, B, G, true>
+{
+    type Output = WithGradient<f32, I>;
+
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        let cell = self.cells.partition(input);
+        let to_blend = cell.iter_points(*seeds).map(|p| {
+            let grad = self.gradients.get_gradient(p.rough_id);
+            (grad, p.offset)
+        });
+        let radius = cell.blending_half_radius();
+        self.blender.differential_blend_gradients(to_blend, radius)
+    }
+}
+
+/// A simple [`GradientGenerator`] that maps seeds directly to gradient vectors via a lookup table.
+/// This is the fastest provided [`GradientGenerator`].
+///
+/// The lookup table is shared for all dimensions to reduce memory.
+/// Instead of an expensive `%` to index the array, the bits are shifted within range `>>`.
+/// This has the unfortunate (but worth it) effect of making some values more likely than others in 3d.
+/// There are 12 vectors for 3d, but 16 possible indices, so 4 are weighted double the others.
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct QuickGradients;
+
+impl GradientGenerator<Vec2> for QuickGradients {
+    #[inline]
+    fn get_gradient_dot(&self, seed: u32, offset: Vec2) -> f32 {
+        GradientGenerator::<Vec2>::get_gradient(self, seed).dot(offset)
+    }
+
+    #[inline]
+    fn get_gradient(&self, seed: u32) -> Vec2 {
+        // SAFETY: Ensured by bit shift. Bit shift is better than bit and since the rng is cheap and puts more entropy in higher bits.
+        unsafe { *GRADIENT_TABLE.get_unchecked((seed >> 30) as usize) }.xy()
+    }
+}
+
+impl GradientGenerator<Vec3> for QuickGradients {
+    #[inline]
+    fn get_gradient_dot(&self, seed: u32, offset: Vec3) -> f32 {
+        GradientGenerator::<Vec3>::get_gradient(self, seed).dot(offset)
+    }
+
+    #[inline]
+    fn get_gradient(&self, seed: u32) -> Vec3 {
+        // SAFETY: Ensured by bit shift. Bit shift is better than bit and since the rng is cheap and puts more entropy in higher bits.
+        unsafe { *GRADIENT_TABLE.get_unchecked((seed >> 28) as usize) }.xyz()
+    }
+}
+
+impl GradientGenerator<Vec3A> for QuickGradients {
+    #[inline]
+    fn get_gradient_dot(&self, seed: u32, offset: Vec3A) -> f32 {
+        GradientGenerator::<Vec3A>::get_gradient(self, seed).dot(offset)
+    }
+
+    #[inline]
+    fn get_gradient(&self, seed: u32) -> Vec3A {
+        // SAFETY: Ensured by bit shift. Bit shift is better than bit and since the rng is cheap and puts more entropy in higher bits.
+        unsafe { *GRADIENT_TABLE.get_unchecked((seed >> 28) as usize) }
+            .xyz()
+            .into()
+    }
+}
+
+impl GradientGenerator<Vec4> for QuickGradients {
+    #[inline]
+    fn get_gradient_dot(&self, seed: u32, offset: Vec4) -> f32 {
+        GradientGenerator::<Vec4>::get_gradient(self, seed).dot(offset)
+    }
+
+    #[inline]
+    fn get_gradient(&self, seed: u32) -> Vec4 {
+        // SAFETY: Ensured by bit shift. Bit shift is better than bit and since the rng is cheap and puts more entropy in higher bits.
+        unsafe { *GRADIENT_TABLE.get_unchecked((seed >> 27) as usize) }
+    }
+}
+
+/// A [`GradientGenerator`] for a deliberately low-fidelity, retro look.
+/// Like [`QuickGradients`], this picks from a small set of directions,
+/// but this also quantizes the resulting dot product into [`steps`](SteppedGradients::steps) evenly spaced bands over `[-1, 1]`.
+/// A `steps` value less than 2 is treated as 2.
+///
+/// Note that [`get_gradient`](GradientGenerator::get_gradient) gives the unquantized direction,
+/// so derivatives computed with this ignore the stepping.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// use noiz::cell_noise::SteppedGradients;
+/// let noise = Noise::<MixCellGradients<OrthoGrid, Smoothstep, SteppedGradients>>::default();
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct SteppedGradients {
+    /// The number of distinct values the dot product can take.
+    pub steps: u32,
+}
+
+impl Default for SteppedGradients {
+    fn default() -> Self {
+        Self { steps: 4 }
+    }
+}
+
+impl GradientGenerator<Vec2> for SteppedGradients {
+    #[inline]
+    fn get_gradient_dot(&self, seed: u32, offset: Vec2) -> f32 {
+        let dot = GradientGenerator::<Vec2>::get_gradient(self, seed).dot(offset);
+        let levels = (self.steps.max(2) - 1) as f32;
+        let band = bevy_math::ops::floor((dot.clamp(-1.0, 1.0) + 1.0) * 0.5 * levels + 0.5);
+        band / levels * 2.0 - 1.0
+    }
+
+    #[inline]
+    fn get_gradient(&self, seed: u32) -> Vec2 {
+        GradientGenerator::<Vec2>::get_gradient(&QuickGradients, seed)
+    }
+}
+
+/// A table of normalized gradient vectors.
+/// This is meant to fit in a single page of memory and be reused by any kind of vector.
+/// Only -1, 0, and 1 are used so that the float multiplication is faster.
+///
+/// The first 4 are usable in 2d; the first 16 are usable in 3d (first 4 are repeated in the last 4, so only 12 are unique)
+///
+/// Inspired by similar tables in libnoise.
+const GRADIENT_TABLE: [Vec4; 32] = [
+    // 2d combinations (4)
+    Vec4::new(0.0, -1.0, -1.0, -1.0),
+    Vec4::new(0.0, 1.0, -1.0, -1.0),
+    Vec4::new(-1.0, 0.0, -1.0, -1.0),
+    Vec4::new(1.0, 0.0, -1.0, -1.0),
+    // 3d combinations (12, 8 more)
+    Vec4::new(0.0, -1.0, 1.0, -1.0),
+    Vec4::new(0.0, 1.0, 1.0, -1.0),
+    Vec4::new(-1.0, 0.0, 1.0, -1.0),
+    Vec4::new(1.0, 0.0, 1.0, -1.0),
+    // where z = 0
+    Vec4::new(1.0, 1.0, 0.0, -1.0),
+    Vec4::new(-1.0, 1.0, 0.0, -1.0),
+    Vec4::new(1.0, -1.0, 0.0, -1.0),
+    Vec4::new(-1.0, -1.0, 0.0, -1.0),
+    // 4d combinations (32, 20 more)
+    Vec4::new(0.0, -1.0, -1.0, 1.0), //
+    Vec4::new(0.0, 1.0, -1.0, 1.0),  //
+    Vec4::new(-1.0, 0.0, -1.0, 1.0), //
+    Vec4::new(1.0, 0.0, -1.0, 1.0), // These first 4 need 0 in x or y, so we can use binary ops to get the index for 3d.
+    Vec4::new(0.0, -1.0, 1.0, 1.0),
+    Vec4::new(0.0, 1.0, 1.0, 1.0),
+    Vec4::new(-1.0, 0.0, 1.0, 1.0),
+    Vec4::new(1.0, 0.0, 1.0, 1.0),
+    Vec4::new(1.0, 1.0, 0.0, 1.0),
+    Vec4::new(-1.0, 1.0, 0.0, 1.0),
+    Vec4::new(1.0, -1.0, 0.0, 1.0),
+    Vec4::new(-1.0, -1.0, 0.0, 1.0),
+    // where w = 0
+    Vec4::new(1.0, 1.0, 1.0, 0.0),
+    Vec4::new(1.0, 1.0, -1.0, 0.0),
+    Vec4::new(1.0, -1.0, 1.0, 0.0),
+    Vec4::new(1.0, -1.0, -1.0, 0.0),
+    Vec4::new(-1.0, 1.0, 1.0, 0.0),
+    Vec4::new(-1.0, 1.0, -1.0, 0.0),
+    Vec4::new(-1.0, -1.0, 1.0, 0.0),
+    Vec4::new(-1.0, -1.0, -1.0, 0.0),
+];
+
+/// A medium qualaty [`GradientGenerator`] that distributes normalized gradient vectors.
+/// This results in totally random gradient vectors (not from a lookup table).
+///
+/// However, this is not uniform because it normalizes vectors *in* a square *onto* a circle (and so on for higher dimensions).
+/// That creates undesirable bunching of gradients.
+/// If you want to fix this (with a performance hit) see [`QualityGradients`].
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct RandomGradients;
+
+macro_rules! impl_random_gradients {
+    ($t:ty) => {
+        impl GradientGenerator<$t> for RandomGradients {
+            #[inline]
+            fn get_gradient_dot(&self, seed: u32, offset: $t) -> f32 {
+                GradientGenerator::<$t>::get_gradient(self, seed).dot(offset)
+            }
+
+            #[inline]
+            fn get_gradient(&self, seed: u32) -> $t {
+                let v: $t = SNormSplit.linear_equivalent_value(seed);
+                v.normalize()
+            }
+        }
+    };
+}
+
+impl_random_gradients!(Vec2);
+impl_random_gradients!(Vec3);
+impl_random_gradients!(Vec3A);
+impl_random_gradients!(Vec4);
+
+/// A high quality (but slow) [`GradientGenerator`] that uniformly distributes normalized gradient vectors.
+/// Note that this is not yet implemented for [`Vec4`].
+// TODO: implement for 4d
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct QualityGradients;
+
+impl GradientGenerator<Vec2> for QualityGradients {
+    #[inline]
+    fn get_gradient_dot(&self, seed: u32, offset: Vec2) -> f32 {
+        GradientGenerator::<Vec2>::get_gradient(self, seed).dot(offset)
+    }
+
+    #[inline]
+    fn get_gradient(&self, seed: u32) -> Vec2 {
+        let angle: f32 = UNorm.any_value(seed);
+        Vec2::from_angle(angle * f32::consts::PI * 2.0)
+    }
+}
+
+impl GradientGenerator<Vec3> for QualityGradients {
+    #[inline]
+    fn get_gradient_dot(&self, seed: u32, offset: Vec3) -> f32 {
+        GradientGenerator::<Vec3>::get_gradient(self, seed).dot(offset)
+    }
+
+    #[inline]
+    fn get_gradient(&self, seed: u32) -> Vec3 {
+        let Vec2 { x, y } = UNorm.any_value(seed);
+        let theta = x * f32::consts::PI * 2.0;
+        let phi = y * f32::consts::PI;
+        Vec2::from_angle(theta).extend(phi.cos())
+    }
+}
+
+impl GradientGenerator<Vec3A> for QualityGradients {
+    #[inline]
+    fn get_gradient_dot(&self, seed: u32, offset: Vec3A) -> f32 {
+        GradientGenerator::<Vec3A>::get_gradient(self, seed).dot(offset)
+    }
+
+    #[inline]
+    fn get_gradient(&self, seed: u32) -> Vec3A {
+        GradientGenerator::<Vec3>::get_gradient(self, seed).into()
+    }
+}
+
+/// A [`ValueBlender`] that weighs each values by it's distance, as computed by a [`LengthFunction`].
+///
+/// This is mainly used for fun worly noise:
+///
+/// ```
+/// # use noiz::prelude::*;
+/// let noise = Noise::<BlendCellValues<Voronoi, DistanceBlend<ManhattanLength>, Random<UNorm, f32>>>::default();
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+///
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct DistanceBlend<L>(pub L);
+
+impl<V: Mul<f32, Output = V> + Default + AddAssign<V>, L: LengthFunction<I>, I: VectorSpace>
+    ValueBlender<I, V> for DistanceBlend<L>
+{
+    #[inline]
+    fn blend_values(&self, to_blend: impl Iterator<Item = (V, I)>, blending_half_radius: f32) -> V {
+        let mut sum = V::default();
+        let mut weight_sum = 0f32;
+        let mut cnt = 0;
+        let clamp_len = self.0.max_for_element_max(blending_half_radius);
+        for (val, weight) in to_blend {
+            let len = self.0.length_of(weight);
+            let weight = Smoothstep.sample_unchecked((clamp_len - len).max(0.0) / clamp_len);
+            sum += val * weight;
+            weight_sum += weight;
+            cnt += 1;
+        }
+        sum * (weight_sum / (cnt as f32 * clamp_len))
+    }
+}
+
+/// A [`ValueBlender`] that sums each value, weighted by a smooth falloff of its distance, as computed by a [`LengthFunction`] `L`.
+/// Unlike [`DistanceBlend`], this does not normalize the weights, so nearby values add together like metaballs, producing smooth, blobby fields.
+///
+/// The result is bounded by the sum of the values of nearby points, but it is not normalized.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use noiz::cell_noise::DistanceFalloffBlend;
+/// let noise = Noise::<BlendCellValues<Voronoi, DistanceFalloffBlend<EuclideanLength>, Random<UNorm, f32>>>::default();
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct DistanceFalloffBlend<L> {
+    /// The [`LengthFunction`] used to measure distance.
+    pub length: L,
+    /// The scale of the falloff radius relative to the blending radius.
+    /// Values in (0, 1] are valid; smaller values make smaller blobs.
+    /// Values higher than 1 will produce discontinuities.
+    pub falloff: f32,
+}
+
+impl<L: Default> Default for DistanceFalloffBlend<L> {
+    fn default() -> Self {
+        Self {
+            length: L::default(),
+            falloff: 1.0,
+        }
+    }
+}
+
+impl<V: Mul<f32, Output = V> + Default + AddAssign<V>, L: LengthFunction<I>, I: VectorSpace>
+    ValueBlender<I, V> for DistanceFalloffBlend<L>
+{
+    #[inline]
+    fn blend_values(&self, to_blend: impl Iterator<Item = (V, I)>, blending_half_radius: f32) -> V {
+        let mut sum = V::default();
+        let radius = self.length.max_for_element_max(blending_half_radius) * self.falloff;
+        for (val, offset) in to_blend {
+            let len = self.length.length_of(offset);
+            let weight = Smoothstep.sample_unchecked((radius - len).max(0.0) / radius);
+            sum += val * weight;
+        }
+        sum
+    }
+}
+
+/// A blender that defers to another blender `T` and scales its blending radius by some value.
+/// This works with [`GradientBlender`], [`DifferentiableGradientBlender`], [`ValueBlender`], and [`DifferentiableValueBlender`].
+#[derive(Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct LocalBlend<T> {
+    /// The inner blender.
+    pub blender: T,
+    /// The scale of the blending radius.
+    /// Values in (0, 1) will decrease the blending, producing more localized values.
+    /// Values higher than 1 will produce discontinuities.
+    /// Negative values have no meaning (and may have either no effect or look really cool/strange).
+    pub radius_scale: f32,
+}
+
+impl<V, I: VectorSpace, B: ValueBlender<I, V>> ValueBlender<I, V> for LocalBlend<B> {
+    #[inline]
+    fn blend_values(&self, to_blend: impl Iterator<Item = (V, I)>, blending_half_radius: f32) -> V {
+        self.blender
+            .blend_values(to_blend, blending_half_radius * self.radius_scale)
+    }
+}
+
+impl<V, I: VectorSpace, B: DifferentiableValueBlender<I, V>> DifferentiableValueBlender<I, V>
+    for LocalBlend<B>
+{
+    #[inline]
+    fn differential_blend_values(
+        &self,
+        to_blend: impl Iterator<Item = (V, I)>,
+        blending_half_radius: f32,
+    ) -> WithGradient<V, I> {
+        self.blender
+            .differential_blend_values(to_blend, blending_half_radius * self.radius_scale)
+    }
+}
+
+impl<I: VectorSpace, B: GradientBlender<I>> GradientBlender<I> for LocalBlend<B> {
+    #[inline]
+    fn blend_gradients(
+        &self,
+        to_blend: impl Iterator<Item = (I, I)>,
+        blending_half_radius: f32,
+    ) -> f32 {
+        self.blender
+            .blend_gradients(to_blend, blending_half_radius * self.radius_scale)
+    }
+}
+
+impl<I: VectorSpace, B: DifferentiableGradientBlender<I>> DifferentiableGradientBlender<I>
+    for LocalBlend<B>
+{
+    #[inline]
+    fn differential_blend_gradients(
+        &self,
+        to_blend: impl Iterator<Item = (I, I)>,
+        blending_half_radius: f32,
+    ) -> WithGradient<f32, I> {
+        self.blender
+            .differential_blend_gradients(to_blend, blending_half_radius * self.radius_scale)
+    }
+}
+
+/// A [`GradientBlender`] and [`ValueBlender`] built for the [`SimplexGrid`](crate::cells::SimplexGrid) for simplex noise that smoothly blends values in a pleasant way.
+/// This also implements [`DifferentiableGradientBlender`] and [`DifferentiableValueBlender`].
+/// The analytic gradient of each point's contribution is the derivative of its falloff, `(1 - |offset|² / r)⁴`, times its value or gradient dot product,
+/// plus the falloff times the gradient vector itself, summed over the points of the cell.
+///
+/// This can also be used to make "even" blending in [`BlendCellValues`].
+/// If you're not sure which blender to use, start with this one.
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct SimplecticBlend;
+
+#[inline]
+fn general_simplex_weight(length_sqrd: f32, blending_half_radius: f32) -> f32 {
+    // We do the unorm mapping here instead of later to prevent precision issues.
+    let weight_unorm = (blending_half_radius - length_sqrd) / blending_half_radius;
+    if weight_unorm <= 0.0 {
+        0.0
+    } else {
+        let s = weight_unorm * weight_unorm;
+        s * s
+    }
+}
+
+#[inline]
+fn general_simplex_weight_derivative(length_sqrd: f32, blending_half_radius: f32) -> f32 {
+    // We do the unorm mapping here instead of later to prevent precision issues.
+    let weight_unorm = (blending_half_radius - length_sqrd) / blending_half_radius;
+    if weight_unorm <= 0.0 {
+        0.0
+    } else {
+        (weight_unorm * weight_unorm) * (weight_unorm * 4.0)
+    }
+}
+
+macro_rules! impl_simplectic_blend {
+    ($i:ty, $c:literal) => {
+        impl<V: Mul<f32, Output = V> + Default + AddAssign<V> + Copy> ValueBlender<$i, V>
+            for SimplecticBlend
+        where
+            $i: Mul<V, Output = $i> + Mul<f32, Output = $i>,
+        {
+            #[inline]
+            fn blend_values(
+                &self,
+                to_blend: impl Iterator<Item = (V, $i)>,
+                blending_half_radius: f32,
+            ) -> V {
+                let mut sum = V::default();
+                for (val, weight) in to_blend {
+                    sum +=
+                        val * general_simplex_weight(weight.length_squared(), blending_half_radius);
+                }
+                sum
+            }
+        }
+
+        impl<V: Mul<f32, Output = V> + Default + AddAssign<V> + Copy>
+            DifferentiableValueBlender<$i, V> for SimplecticBlend
+        where
+            $i: Mul<V, Output = $i> + Mul<f32, Output = $i>,
+        {
+            #[inline]
+            fn differential_blend_values(
+                &self,
+                to_blend: impl Iterator<Item = (V, $i)>,
+                blending_half_radius: f32,
+            ) -> WithGradient<V, $i> {
+                let mut sum = WithGradient {
+                    value: V::default(),
+                    gradient: <$i>::ZERO,
+                };
+                for (val, weight) in to_blend {
+                    let len_sqr = weight.length_squared();
+                    let falloff = general_simplex_weight(len_sqr, blending_half_radius);
+                    let d_falloff = weight
+                        * general_simplex_weight_derivative(len_sqr, blending_half_radius)
+                        * (-2.0 / blending_half_radius); // chain rule
+                    sum.value += val * falloff;
+                    sum.gradient += d_falloff * val;
+                }
+                sum
+            }
+        }
+
+        impl GradientBlender<$i> for SimplecticBlend {
+            #[inline]
+            fn blend_gradients(
+                &self,
+                to_blend: impl Iterator<Item = ($i, $i)>,
+                blending_half_radius: f32,
+            ) -> f32 {
+                let mut sum = 0.0;
+                let blending_half_radius_sqrd = blending_half_radius * blending_half_radius;
+                let counter_dot_product =
+                    ($c * blending_half_radius_sqrd * blending_half_radius_sqrd);
+                for (grad, offset) in to_blend {
+                    let dot = grad.dot(offset);
+                    let len_sqr = offset.length_squared();
+                    let falloff = general_simplex_weight(len_sqr, blending_half_radius);
+                    sum += dot * falloff;
+                }
+                sum *= counter_dot_product;
+                sum
+            }
+        }
+
+        impl DifferentiableGradientBlender<$i> for SimplecticBlend {
+            #[inline]
+            fn differential_blend_gradients(
+                &self,
+                to_blend: impl Iterator<Item = ($i, $i)>,
+                blending_half_radius: f32,
+            ) -> WithGradient<f32, $i> {
+                let mut sum = WithGradient::<f32, $i>::default();
+                let blending_half_radius_sqrd = blending_half_radius * blending_half_radius;
+                let counter_dot_product =
+                    ($c * blending_half_radius_sqrd * blending_half_radius_sqrd);
+                for (grad, offset) in to_blend {
+                    let dot = grad.dot(offset);
+                    let len_sqr = offset.length_squared();
+                    let falloff = general_simplex_weight(len_sqr, blending_half_radius);
+                    let d_falloff = offset
+                        * general_simplex_weight_derivative(len_sqr, blending_half_radius)
+                        * (-2.0 / blending_half_radius); // chain rule
+                    sum += WithGradient {
+                        value: dot * falloff,
+                        gradient: grad * falloff + dot * d_falloff, // product rule
+                    };
+                }
+                sum *= counter_dot_product;
+                sum
+            }
+        }
+    };
+}
+
+// constants adapted from libnoise
+impl_simplectic_blend!(Vec2, 99.836_85);
+impl_simplectic_blend!(Vec3, 76.883_76);
+impl_simplectic_blend!(Vec3A, 76.883_76);
+impl_simplectic_blend!(Vec4, 62.795_597);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Noise, Sampleable, SampleableFor, ScalableNoise,
+        cells::{ExactF2Voronoi, PeriodicVoronoi, SimplexGrid},
+        lengths::EuclideanSqrdLength,
+        math_noise::{Abs, ApproxSdf, Laplacian, SlopeMagnitude, TerrainNormal},
+        misc_noise::{Cast, Remap, WarpedDiff},
+        prelude::{
+            Billow, FractalLayers, LayeredNoise, Masked, Normed, NormedByDerivative, Octave,
+            PeakDerivativeContribution, Persistence, SNormToUNorm, UNormToSNorm,
+            common_noise::{FbmWithNormals, PerlinWithDerivative, ValueWithDerivative},
+        },
+        rng::UNorm,
+    };
+
+    /// Amount we step to approximate gradient. This must be significantly smaller than the
+    /// noise features to be any sort of accurate.
+    const STEP: f32 = 1e-4;
+    /// Epsilon for gradient approximation comparison.
+    const EPSILON: f32 = 1e-2;
+
+    fn test_grads_2d(noise: impl SampleableFor<Vec2, WithGradient<f32, Vec2>> + ScalableNoise) {
+        let mut failure = false;
+        for x in -10..=10 {
+            for y in -10..=10 {
+                let point = Vec2::new(x as f32, y as f32) * 0.2;
+                let result = noise.sample(point);
+                let approximate_gradient = Vec2::new(
+                    noise.sample(point + STEP * Vec2::X).value
+                        - noise.sample(point - STEP * Vec2::X).value,
+                    noise.sample(point + STEP * Vec2::Y).value
+                        - noise.sample(point - STEP * Vec2::Y).value,
+                ) / (STEP * 2.0);
+                let analytical_gradient = result.gradient;
+                if approximate_gradient.distance(result.gradient) > EPSILON {
+                    let dir_approx = approximate_gradient.normalize_or_zero();
+                    let dir_analytical = analytical_gradient.normalize_or_zero();
+                    println!(
+                        "Gradient mismatch at point {point:?}: approximate: {approximate_gradient:?} dir {dir_approx:?}, analytical: {analytical_gradient:?} dir {dir_analytical:?}"
+                    );
+                    failure = true;
+                }
+            }
+        }
+        if failure {
+            panic!("Simplex gradients failed at the above points.");
+        }
+    }
+
+    fn test_grads_3d(noise: impl SampleableFor<Vec3, WithGradient<f32, Vec3>> + ScalableNoise) {
+        let mut failure = false;
+        for x in -5..=5 {
+            for y in -5..=5 {
+                for z in -5..=5 {
+                    let point = Vec3::new(x as f32, y as f32, z as f32) * 0.23;
+                    let result = noise.sample(point);
+                    let approximate_gradient = Vec3::new(
+                        noise.sample(point + STEP * Vec3::X).value
+                            - noise.sample(point - STEP * Vec3::X).value,
+                        noise.sample(point + STEP * Vec3::Y).value
+                            - noise.sample(point - STEP * Vec3::Y).value,
+                        noise.sample(point + STEP * Vec3::Z).value
+                            - noise.sample(point - STEP * Vec3::Z).value,
+                    ) / (STEP * 2.0);
+                    if approximate_gradient.distance(result.gradient) > EPSILON {
+                        println!(
+                            "Gradient mismatch at point {point:?}: approximate: {approximate_gradient:?}, analytical: {:?}",
+                            result.gradient
+                        );
+                        failure = true;
+                    }
+                }
+            }
+        }
+        if failure {
+            panic!("3d gradients failed at the above points.");
+        }
+    }
+
+    #[test]
+    fn test_simplex_gradients() {
+        test_grads_2d(Noise::<
+            BlendCellGradients<SimplexGrid, SimplecticBlend, QuickGradients, true>,
+        >::default());
+    }
+
+    #[test]
+    fn test_simplex_value_gradients() {
+        test_grads_2d(Noise::<
+            BlendCellValues<SimplexGrid, SimplecticBlend, Random<SNorm, f32>, true>,
+        >::default());
+    }
+
+    #[test]
+    fn test_simplex_gradients_3d() {
+        test_grads_3d(Noise::<
+            BlendCellGradients<SimplexGrid, SimplecticBlend, QuickGradients, true>,
+        >::default());
+    }
+
+    #[test]
+    fn test_simplex_value_gradients_3d() {
+        test_grads_3d(Noise::<
+            BlendCellValues<SimplexGrid, SimplecticBlend, Random<SNorm, f32>, true>,
+        >::default());
+    }
+
+    #[test]
+    fn test_mix_gradients() {
+        test_grads_2d(Noise::<
+            MixCellGradients<OrthoGrid, Smoothstep, QuickGradients, true>,
+        >::default());
+    }
+
+    #[test]
+    fn test_mix_value_gradients() {
+        test_grads_2d(Noise::<
+            MixCellValues<OrthoGrid, Smoothstep, Random<SNorm, f32>, true>,
+        >::default());
+    }
+
+    #[test]
+    fn test_cosine_value_gradients() {
+        use crate::curves::Cosine;
+        assert_eq!(Cosine.sample_unchecked(0.0), 0.0);
+        assert!((Cosine.sample_unchecked(1.0) - 1.0).abs() < 1e-6);
+        assert!((Cosine.sample_unchecked(0.5) - 0.5).abs() < 1e-6);
+        for t in [0.0, 1.0] {
+            let derivative = Cosine.sample_with_derivative_unchecked(t).derivative;
+            assert!(derivative.abs() < 1e-5);
+        }
+        test_grads_2d(Noise::<
+            MixCellValues<OrthoGrid, Cosine, Random<SNorm, f32>, true>,
+        >::default());
+    }
+
+    #[test]
+    fn test_warped_gradients() {
+        test_grads_2d(Noise::<
+            WarpedDiff<
+                MixCellValuesForDomain<OrthoGrid, Smoothstep, SNorm, true>,
+                MixCellGradients<OrthoGrid, Smoothstep, QuickGradients, true>,
+            >,
+        >::default());
+    }
+
+    #[test]
+    fn test_mask_gradients() {
+        test_grads_2d(Noise::<
+            Masked<
+                MixCellGradients<OrthoGrid, Smoothstep, QuickGradients, true>,
+                BlendCellGradients<SimplexGrid, SimplecticBlend, QuickGradients, true>,
+            >,
+        >::default());
+    }
+
+    #[test]
+    fn test_fbm_gradients() {
+        test_grads_2d(Noise::<
+            LayeredNoise<
+                Normed<WithGradient<f32, Vec2>>,
+                Persistence,
+                FractalLayers<
+                    Octave<BlendCellGradients<SimplexGrid, SimplecticBlend, QuickGradients, true>>,
+                >,
+            >,
+        >::default());
+    }
+
+    #[test]
+    fn test_fbm_with_normals_gradients() {
+        // Fewer octaves keep the finite differences accurate enough to compare against.
+        fn fbm<T: Default>() -> Noise<FbmWithNormals<T>> {
+            Noise::from(LayeredNoise::new(
+                Normed::default(),
+                Persistence::default(),
+                FractalLayers {
+                    amount: 4,
+                    ..Default::default()
+                },
+            ))
+        }
+        test_grads_2d(fbm::<PerlinWithDerivative>());
+        test_grads_2d(fbm::<ValueWithDerivative>());
+    }
+
+    #[test]
+    #[ignore = "This is close to correct, but not quite. But the math seems to be correct. Probably, the gradients are correct but the function is not classically differentiable."]
+    fn test_erosion_approx_fbm_gradients() {
+        test_grads_2d(Noise::<
+            LayeredNoise<
+                NormedByDerivative<
+                    WithGradient<f32, Vec2>,
+                    EuclideanSqrdLength,
+                    PeakDerivativeContribution,
+                >,
+                Persistence,
+                FractalLayers<
+                    Octave<BlendCellGradients<SimplexGrid, SimplecticBlend, QuickGradients, true>>,
+                >,
+            >,
+        >::from(LayeredNoise::new(
+            NormedByDerivative::default().with_falloff(1.0),
+            Default::default(),
+            FractalLayers {
+                layer: Octave::default(),
+                lacunarity: 1.0,
+                amount: 2,
+            },
+        )));
+    }
+
+    #[test]
+    #[ignore = "abs is not mathematically rigorously differentiable"]
+    fn test_billowing_gradients() {
+        test_grads_2d(Noise::<(
+            BlendCellGradients<SimplexGrid, SimplecticBlend, QuickGradients, true>,
+            Billow,
+        )>::default());
+    }
+
+    #[test]
+    #[ignore = "abs is not mathematically rigorously differentiable"]
+    fn test_abs_gradients() {
+        test_grads_2d(Noise::<(
+            BlendCellGradients<SimplexGrid, SimplecticBlend, QuickGradients, true>,
+            Abs,
+        )>::default());
+    }
+
+    #[test]
+    fn test_unorm_to_snorm_gradients() {
+        test_grads_2d(Noise::<(
+            BlendCellGradients<SimplexGrid, SimplecticBlend, QuickGradients, true>,
+            UNormToSNorm,
+        )>::default());
+    }
+
+    #[test]
+    fn test_slope_magnitude() {
+        // Smoothstep flattens out at the lattice points, so the slope should vanish there.
+        let flat = Noise::<(
+            ValueWithDerivative,
+            Cast<WithGradient<f32, Vec2>>,
+            SlopeMagnitude,
+        )>::default();
+        for x in -4..=4 {
+            for y in -4..=4 {
+                let slope: f32 = flat.sample(Vec2::new(x as f32, y as f32));
+                assert!(
+                    slope.abs() < 1e-5,
+                    "slope {slope} at lattice point ({x}, {y})"
+                );
+            }
+        }
+
+        let analytic = Noise::<PerlinWithDerivative>::default();
+        let slope = Noise::<(PerlinWithDerivative, SlopeMagnitude)>::default();
+        for x in -10..=10 {
+            for y in -10..=10 {
+                let point = Vec2::new(x as f32, y as f32) * 0.23;
+                let expected: WithGradient<f32, Vec2> = analytic.sample(point);
+                let result: f32 = slope.sample(point);
+                assert!((result - expected.gradient.length()).abs() < 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_approx_sdf() {
+        let noise = Noise::<(PerlinWithDerivative, ApproxSdf)>::default();
+        let sdf = |point: Vec3| -> f32 { noise.sample(point) };
+        let mut crossings = 0;
+        for ray in 0..8 {
+            let origin = Vec3::new(ray as f32 * 1.37, -0.4 * ray as f32, 0.21);
+            let direction = Vec3::new(1.0, 0.7, -0.3).normalize();
+            for step in 0..100 {
+                let mut start = origin + direction * (step as f32 * 0.05);
+                let mut end = start + direction * 0.05;
+                if sdf(start).signum() == sdf(end).signum() {
+                    continue;
+                }
+
+                // Find the surface and check the field is distance-like there.
+                for _ in 0..20 {
+                    let middle = (start + end) * 0.5;
+                    if sdf(middle).signum() == sdf(start).signum() {
+                        start = middle;
+                    } else {
+                        end = middle;
+                    }
+                }
+                let surface = (start + end) * 0.5;
+                let gradient = Vec3::new(
+                    sdf(surface + STEP * Vec3::X) - sdf(surface - STEP * Vec3::X),
+                    sdf(surface + STEP * Vec3::Y) - sdf(surface - STEP * Vec3::Y),
+                    sdf(surface + STEP * Vec3::Z) - sdf(surface - STEP * Vec3::Z),
+                ) / (STEP * 2.0);
+                assert!(
+                    (gradient.length() - 1.0).abs() < 0.05,
+                    "sdf gradient length {} at {surface:?}",
+                    gradient.length()
+                );
+                crossings += 1;
+            }
+        }
+        assert!(crossings > 0);
+    }
+
+    #[test]
+    fn test_remap_gradients() {
+        test_grads_2d(Noise::<(
+            ValueWithDerivative,
+            Cast<WithGradient<f32, Vec2>>,
+            Remap<Smoothstep>,
+        )>::default());
+        test_grads_2d(Noise::<(
+            PerlinWithDerivative,
+            SNormToUNorm,
+            Remap<Smoothstep>,
+        )>::default());
+    }
+
+    #[test]
+    fn test_cell_border() {
+        let edges = Noise::<DistanceToEdge<Voronoi>>::default();
+        let border_fraction = |width: f32| {
+            let border = Noise::<CellBorder<Voronoi>>::from(CellBorder {
+                edges: DistanceToEdge::default(),
+                width,
+            });
+            let mut on_border = 0;
+            for x in 0..100 {
+                for y in 0..100 {
+                    let point = Vec2::new(x as f32, y as f32) * 0.073;
+                    let value: f32 = border.sample(point);
+                    let distance: f32 = edges.sample(point);
+                    if distance > width {
+                        assert_eq!(value, 0.0);
+                    } else {
+                        assert_eq!(value, 1.0);
+                        on_border += 1;
+                    }
+                }
+            }
+            on_border as f32 / 10_000.0
+        };
+
+        let thin = border_fraction(0.05);
+        let thick = border_fraction(0.1);
+        assert!(thin > 0.0);
+        assert!(
+            thick > thin * 1.5 && thick < thin * 2.5,
+            "thin: {thin}, thick: {thick}"
+        );
+    }
+
+    #[test]
+    fn test_stepped_gradients() {
+        for steps in [2, 3, 5] {
+            let gradients = SteppedGradients { steps };
+            for seed in [0, 1 << 30, 2 << 30, 3 << 30] {
+                let mut values = [0.0f32; 21 * 21];
+                for x in -10..=10 {
+                    for y in -10..=10 {
+                        let offset = Vec2::new(x as f32, y as f32) * 0.1;
+                        values[((x + 10) * 21 + y + 10) as usize] =
+                            gradients.get_gradient_dot(seed, offset);
+                    }
+                }
+                values.sort_by(f32::total_cmp);
+                let mut distinct = 1;
+                for pair in values.windows(2) {
+                    if pair[0] != pair[1] {
+                        distinct += 1;
+                    }
+                }
+                assert_eq!(distinct, steps);
+            }
+        }
+    }
+
+    #[test]
+    fn test_value_hessian() {
+        use crate::curves::DoubleSmoothstep;
+
+        let hessian =
+            Noise::<MixCellValuesHessian<OrthoGrid, DoubleSmoothstep, Random<SNorm, f32>>>::default(
+            );
+        let gradient =
+            Noise::<MixCellValues<OrthoGrid, DoubleSmoothstep, Random<SNorm, f32>, true>>::default(
+            );
+        let laplacian = Noise::<(
+            MixCellValuesHessian<OrthoGrid, DoubleSmoothstep, Random<SNorm, f32>>,
+            Laplacian,
+        )>::default();
+        for x in -10..=10 {
+            for y in -10..=10 {
+                let point = Vec2::new(x as f32, y as f32) * 0.2 + 0.05;
+                let result: WithHessian<f32, Vec2, Mat2> = hessian.sample(point);
+                let expected: WithGradient<f32, Vec2> = gradient.sample(point);
+                assert!((result.value - expected.value).abs() < 1e-5);
+                assert!(result.gradient.distance(expected.gradient) < 1e-4);
+
+                let grad_at = |offset: Vec2| -> Vec2 {
+                    let sample: WithGradient<f32, Vec2> = gradient.sample(point + offset);
+                    sample.gradient
+                };
+                let approximate = Mat2::from_cols(
+                    (grad_at(STEP * Vec2::X) - grad_at(-STEP * Vec2::X)) / (STEP * 2.0),
+                    (grad_at(STEP * Vec2::Y) - grad_at(-STEP * Vec2::Y)) / (STEP * 2.0),
+                );
+                assert!(
+                    approximate.abs_diff_eq(result.hessian, EPSILON * 10.0),
+                    "Hessian mismatch at {point:?}: approximate: {approximate:?}, analytical: {:?}",
+                    result.hessian
+                );
+
+                let trace: f32 = laplacian.sample(point);
+                assert!(
+                    (trace - (approximate.x_axis.x + approximate.y_axis.y)).abs() < EPSILON * 10.0
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_palette_per_cell() {
+        let palette = [0.0, 0.25, 0.5, 1.0];
+        let noise = Noise::from(PalettePerCell {
+            cells: OrthoGrid(()),
+            values: palette,
+        });
+        let mut used = [false; 4];
+        for x in -10..10 {
+            for y in -10..10 {
+                let corner = Vec2::new(x as f32, y as f32);
+                let value: f32 = noise.sample(corner + 0.1);
+                let index = palette.iter().position(|v| *v == value).unwrap();
+                used[index] = true;
+                for offset in [
+                    Vec2::new(0.9, 0.2),
+                    Vec2::new(0.5, 0.5),
+                    Vec2::new(0.3, 0.8),
+                ] {
+                    let other: f32 = noise.sample(corner + offset);
+                    assert_eq!(value, other);
+                }
+            }
+        }
+        assert!(used.into_iter().all(|used| used));
+    }
+
+    #[test]
+    fn test_voronoi_radius() {
+        fn max_jump<const RADIUS: u32>(step: f32) -> f32 {
+            let voronoi = Voronoi::<false, OrthoGrid, RADIUS>::default_with_randomness(2.0);
+            let nearest = |point: Vec2| {
+                voronoi
+                    .partition(point)
+                    .iter_points(NoiseRng(0))
+                    .map(|point| point.offset.length())
+                    .fold(f32::INFINITY, f32::min)
+            };
+            let mut max_jump = 0.0f32;
+            for y in 0..10 {
+                let mut previous = nearest(Vec2::new(0.0, y as f32 * 1.3));
+                for x in 1..2000 {
+                    let current = nearest(Vec2::new(x as f32 * step, y as f32 * 1.3));
+                    max_jump = max_jump.max((current - previous).abs());
+                    previous = current;
+                }
+            }
+            max_jump
+        }
+
+        // The distance to the nearest point can not change faster than the sample moves.
+        let step = 0.01;
+        assert!(max_jump::<1>(step) > step * 1.5);
+        assert!(max_jump::<3>(step) <= step * 1.5);
+    }
+
+    #[test]
+    fn test_aniso_matches_isotropic() {
+        use crate::curves::Linear;
+        let iso = Noise::<MixCellValues<OrthoGrid, Linear, Random<SNorm, f32>>>::default();
+        let aniso =
+            Noise::<MixCellValuesAniso<OrthoGrid, Linear, Linear, Random<SNorm, f32>>>::default();
+        for x in -20..20 {
+            for y in -20..20 {
+                let point = Vec2::new(x as f32 * 0.37, y as f32 * 0.29);
+                let expected: f32 = iso.sample(point);
+                let actual: f32 = aniso.sample(point);
+                assert!((expected - actual).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_crackle_reaches_full_brightness() {
+        fn percentile_99(mut values: Vec<f32>) -> f32 {
+            values.sort_by(f32::total_cmp);
+            values[values.len() * 99 / 100]
+        }
+
+        let noise = Noise::<PerCellPointDistances<Voronoi, EuclideanLength, Crackle>>::default();
+        let mut values = Vec::new();
+        for x in 0..400 {
+            for y in 0..400 {
+                let value: f32 = noise.sample(Vec2::new(x as f32, y as f32) * 0.1);
+                assert!((0.0..=1.0).contains(&value));
+                values.push(value);
+            }
+        }
+        let high = percentile_99(values);
+        assert!(high > 0.9, "2d crackle 99th percentile was {high}");
+
+        let noise = Noise::from(PerCellPointDistances {
+            worley_mode: Crackle::EUCLIDEAN_3D,
+            ..PerCellPointDistances::<Voronoi, EuclideanLength, Crackle>::default()
+        });
+        let mut values = Vec::new();
+        for x in 0..60 {
+            for y in 0..60 {
+                for z in 0..60 {
+                    let value: f32 = noise.sample(Vec3::new(x as f32, y as f32, z as f32) * 0.2);
+                    values.push(value);
+                }
+            }
+        }
+        let high = percentile_99(values);
+        assert!(high > 0.9, "3d crackle 99th percentile was {high}");
+    }
+
+    #[test]
+    fn test_shared_partition_matches_separate() {
+        let shared = Noise::<
+            SharedPartition<
+                Voronoi,
+                (
+                    PerCellPointDistances<(), EuclideanLength, WorleyLeastDistance>,
+                    PerNearestPoint<(), EuclideanLength, Random<UNorm, f32>>,
+                ),
+            >,
+        >::default();
+        let distances =
+            Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleyLeastDistance>>::default(
+            );
+        let values =
+            Noise::<PerNearestPoint<Voronoi, EuclideanLength, Random<UNorm, f32>>>::default();
+        for x in -20..20 {
+            for y in -20..20 {
+                let point = Vec2::new(x as f32, y as f32) * 0.37;
+                let (distance, value): (f32, f32) = shared.sample(point);
+                assert_eq!(distance, distances.sample_for::<f32>(point));
+                assert_eq!(value, values.sample_for::<f32>(point));
+            }
+        }
+    }
+
+    #[test]
+    fn test_anisotropic_worley() {
+        let isotropic =
+            Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleyLeastDistance>>::default(
+            );
+        let unstretched = Noise::<AnisotropicWorley<Voronoi, EuclideanLength>>::default();
+        for x in -20..20 {
+            for y in -20..20 {
+                let point = Vec2::new(x as f32, y as f32) * 0.37;
+                let expected: f32 = isotropic.sample(point);
+                let actual: f32 = unstretched.sample(point);
+                assert!((expected - actual).abs() < 1e-6);
+            }
+        }
+
+        // Elongated cells change more slowly along their direction than across it.
+        let stretched = Noise::from(AnisotropicWorley {
+            stretch: 4.0,
+            ..AnisotropicWorley::<Voronoi<false, OrthoGrid, 3>, EuclideanLength>::default()
+        });
+        let mut along = 0.0;
+        let mut across = 0.0;
+        for x in 0..100 {
+            for y in 0..100 {
+                let point = Vec2::new(x as f32, y as f32) * 0.31;
+                let value: f32 = stretched.sample(point);
+                along += (stretched.sample_for::<f32>(point + Vec2::X * 0.05) - value).abs();
+                across += (stretched.sample_for::<f32>(point + Vec2::Y * 0.05) - value).abs();
+            }
+        }
+        assert!(along * 2.0 < across, "along: {along}, across: {across}");
+    }
+
+    #[test]
+    fn test_cell_offset() {
+        let noise = Noise::<CellOffset<OrthoGrid>>::default();
+        for x in -40..40 {
+            for y in -40..40 {
+                let point = Vec2::new(x as f32, y as f32) * 0.13;
+                let offset: Vec2 = noise.sample(point);
+                assert!(offset.cmpge(Vec2::ZERO).all() && offset.cmplt(Vec2::ONE).all());
+                assert!((offset - (point - point.floor())).length() < 1e-5);
+            }
+        }
+
+        // Within a cell, the offset moves exactly with the sample.
+        let start = Vec3::new(2.1, -3.7, 0.05);
+        let start_offset: Vec3 = noise.sample(start);
+        for step in 0..10 {
+            let delta = Vec3::new(0.07, 0.02, 0.09) * step as f32;
+            let offset: Vec3 = noise.sample(start + delta);
+            assert!((offset - start_offset - delta).length() < 1e-5);
+        }
+
+        let offset: Vec4 = noise.sample(Vec4::new(-0.25, 7.5, 1.0, -3.0));
+        assert!((offset - Vec4::new(0.75, 0.5, 0.0, 0.0)).length() < 1e-6);
+    }
+
+    #[test]
+    fn test_squared_worley_normalized() {
+        let noise =
+            Noise::<PerCellPointDistances<Voronoi, EuclideanSqrdLength, WorleyLeastDistance>>::default();
+        let euclidean =
+            Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleyLeastDistance>>::default(
+            );
+        for x in -30..30 {
+            for y in -30..30 {
+                let point = Vec2::new(x as f32, y as f32) * 0.23;
+                let value: f32 = noise.sample(point);
+                assert!((0.0..=1.0).contains(&value));
+                // Both are normalized by their own maximum, so the squared version is the square of the euclidean one.
+                let expected = euclidean.sample_for::<f32>(point).powi(2);
+                assert!((value - expected).abs() < 1e-4);
+            }
+        }
+        for x in -8..8 {
+            for y in -8..8 {
+                for z in -8..8 {
+                    let value: f32 = noise.sample(Vec3::new(x as f32, y as f32, z as f32) * 0.23);
+                    assert!((0.0..=1.0).contains(&value));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_cell_gradient_field_continuous() {
+        let noise = Noise::<CellGradientField<OrthoGrid, Smoothstep, QuickGradients>>::default();
+        const GAP: f32 = 1e-3;
+        for cell in -5..5 {
+            for along in 0..20 {
+                let along = along as f32 * 0.173 - 1.7;
+                let boundary = cell as f32;
+                // across vertical and horizontal cell edges
+                for (a, b) in [
+                    (
+                        Vec2::new(boundary - GAP, along),
+                        Vec2::new(boundary + GAP, along),
+                    ),
+                    (
+                        Vec2::new(along, boundary - GAP),
+                        Vec2::new(along, boundary + GAP),
+                    ),
+                ] {
+                    let a: Vec2 = noise.sample(a);
+                    let b: Vec2 = noise.sample(b);
+                    assert!((a - b).length() < 1e-2, "{a} vs {b} at cell {cell}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_blend_two_cell_values_control_extremes() {
+        let only_first = BlendTwoCellValues {
+            cells: SimplexGrid,
+            blender: SimplecticBlend,
+            first: Random::<UNorm, f32>::default(),
+            second: Random::<SNorm, f32>::default(),
+            control: |_: Vec2, _: &mut NoiseRng| 0.0,
+        };
+        let only_second = BlendTwoCellValues {
+            cells: SimplexGrid,
+            blender: SimplecticBlend,
+            first: Random::<UNorm, f32>::default(),
+            second: Random::<SNorm, f32>::default(),
+            control: |_: Vec2, _: &mut NoiseRng| 1.0,
+        };
+        let first = BlendCellValues::<SimplexGrid, SimplecticBlend, Random<UNorm, f32>> {
+            cells: SimplexGrid,
+            noise: Random::default(),
+            blender: SimplecticBlend,
+        };
+        for x in -20..20 {
+            for y in -20..20 {
+                let point = Vec2::new(x as f32, y as f32) * 0.37;
+                let seeds = NoiseRng(7);
+                assert_eq!(
+                    only_first.evaluate(point, &mut { seeds }),
+                    first.evaluate(point, &mut { seeds })
+                );
+
+                let mut second_seeds = seeds;
+                second_seeds.re_seed();
+                let cell = SimplexGrid.partition(point);
+                let to_blend = cell.iter_points(seeds).map(|p| {
+                    let value: f32 = Random::<SNorm, f32>::default()
+                        .any_value(second_seeds.rand_u32(p.rough_id));
+                    (value, p.offset)
+                });
+                let expected = SimplecticBlend.blend_values(to_blend, cell.blending_half_radius());
+                assert_eq!(only_second.evaluate(point, &mut { seeds }), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_worley_constructor_matches_default() {
+        let built = Noise::from(PerCellPointDistances::worley(
+            EuclideanLength,
+            WorleyDifference,
+        ));
+        let manual =
+            Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleyDifference>>::default();
+        for x in -20..20 {
+            for y in -20..20 {
+                let point = Vec2::new(x as f32, y as f32) * 0.37;
+                assert_eq!(
+                    built.sample_for::<f32>(point),
+                    manual.sample_for::<f32>(point)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_worley_distances_ordered() {
+        let distances = Noise::<WorleyDistances<Voronoi, EuclideanLength>>::default();
+        let least =
+            Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleyLeastDistance>>::default(
+            );
+        for x in -30..30 {
+            for y in -30..30 {
+                let point = Vec2::new(x as f32, y as f32) * 0.23;
+                let f1_f2 = distances.sample_for::<Vec2>(point);
+                assert!(f1_f2.x <= f1_f2.y);
+                assert!(f1_f2.x >= 0.0);
+                // Both share the same normalization, which is twice that of the nearest distance.
+                let f1 = least.sample_for::<f32>(point);
+                assert!((f1_f2.x * 2.0 - f1).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_smooth_per_cell_border_is_average() {
+        let noise = SmoothPerCell::<OrthoGrid, EuclideanLength, Random<UNorm, f32>>::default();
+        let values = Random::<UNorm, f32>::default();
+        let seeds = NoiseRng(3);
+        for y in 1..10 {
+            // On the border between the corners (0, 0) and (1, 0).
+            let point = Vec2::new(0.5, y as f32 * 0.04);
+            let cell = OrthoGrid(()).partition(point);
+            let mut points = cell
+                .iter_points(seeds)
+                .map(|p| (p.offset.length(), p.rough_id))
+                .collect::<Vec<_>>();
+            points.sort_by(|a, b| a.0.total_cmp(&b.0));
+            let average = (values.evaluate(points[0].1, &mut { seeds })
+                + values.evaluate(points[1].1, &mut { seeds }))
+                * 0.5;
+            assert!((noise.evaluate(point, &mut { seeds }) - average).abs() < 1e-6);
         }
 
-        impl GradientBlender<$i> for SimplecticBlend {
-            #[inline]
-            fn blend_gradients(
-                &self,
-                to_blend: impl Iterator<Item = ($i, $i)>,
-                blending_half_radius: f32,
-            ) -> f32 {
-                let mut sum = 0.0;
-                let blending_half_radius_sqrd = blending_half_radius * blending_half_radius;
-                let counter_dot_product =
-                    ($c * blending_half_radius_sqrd * blending_half_radius_sqrd);
-                for (grad, offset) in to_blend {
-                    let dot = grad.dot(offset);
-                    let len_sqr = offset.length_squared();
-                    let falloff = general_simplex_weight(len_sqr, blending_half_radius);
-                    sum += dot * falloff;
+        // Near a point, this is just that point's value.
+        let hard =
+            Noise::<PerNearestPoint<OrthoGrid, EuclideanLength, Random<UNorm, f32>>>::default();
+        let soft =
+            Noise::<SmoothPerCell<OrthoGrid, EuclideanLength, Random<UNorm, f32>>>::default();
+        let point = Vec2::new(3.1, -2.05);
+        assert_eq!(hard.sample_for::<f32>(point), soft.sample_for::<f32>(point));
+    }
+
+    #[test]
+    fn test_point_mask_one_centered_dot_per_cell() {
+        let voronoi: Voronoi = Voronoi::default_with_randomness(0.5);
+        let mask = PointMask {
+            cells: voronoi,
+            length_mode: EuclideanLength,
+            radius: 0.1,
+        };
+        let seeds = NoiseRng(0);
+        // Each dot's center and the sum and count of the samples within it.
+        let mut dots: Vec<(Vec2, Vec2, u32)> = Vec::new();
+        for x in 0..200 {
+            for y in 0..200 {
+                let sample = Vec2::new(x as f32, y as f32) * 0.02 + 0.005;
+                if mask.evaluate(sample, &mut { seeds }) == 0.0 {
+                    continue;
+                }
+                let nearest = voronoi
+                    .partition(sample)
+                    .iter_points(seeds)
+                    .min_by(|a, b| a.offset.length().total_cmp(&b.offset.length()))
+                    .unwrap();
+                let center = sample - nearest.offset;
+                match dots.iter_mut().find(|dot| (dot.0 - center).length() < 1e-4) {
+                    Some(dot) => {
+                        dot.1 += sample;
+                        dot.2 += 1;
+                    }
+                    None => dots.push((center, sample, 1)),
                 }
-                sum *= counter_dot_product;
-                sum
             }
         }
 
-        impl DifferentiableGradientBlender<$i> for SimplecticBlend {
-            #[inline]
-            fn differential_blend_gradients(
-                &self,
-                to_blend: impl Iterator<Item = ($i, $i)>,
-                blending_half_radius: f32,
-            ) -> WithGradient<f32, $i> {
-                let mut sum = WithGradient::<f32, $i>::default();
-                let blending_half_radius_sqrd = blending_half_radius * blending_half_radius;
-                let counter_dot_product =
-                    ($c * blending_half_radius_sqrd * blending_half_radius_sqrd);
-                for (grad, offset) in to_blend {
-                    let dot = grad.dot(offset);
-                    let len_sqr = offset.length_squared();
-                    let falloff = general_simplex_weight(len_sqr, blending_half_radius);
-                    let d_falloff = offset
-                        * general_simplex_weight_derivative(len_sqr, blending_half_radius)
-                        * (-2.0 / blending_half_radius); // chain rule
-                    sum += WithGradient {
-                        value: dot * falloff,
-                        gradient: grad * falloff + dot * d_falloff, // product rule
-                    };
+        // With a randomness of 0.5, each point is in the lower half of its cell, so the middle 4 cells contain exactly 4 points.
+        let inner = dots
+            .iter()
+            .filter(|dot| dot.0.cmpge(Vec2::ONE).all() && dot.0.cmplt(Vec2::splat(3.0)).all())
+            .collect::<Vec<_>>();
+        assert_eq!(inner.len(), 4);
+        for (center, sum, count) in inner {
+            assert!((*sum / *count as f32 - *center).length() < 0.02);
+            assert_eq!(mask.evaluate(*center, &mut { seeds }), 1.0);
+        }
+    }
+
+    #[test]
+    fn test_simplex_normals_match_finite_differences() {
+        let mut failures = 0;
+        for seed in 0..4 {
+            let noise = Noise {
+                noise: (
+                    BlendCellGradients::<SimplexGrid, SimplecticBlend, QuickGradients, true>::default(),
+                    TerrainNormal::default(),
+                ),
+                seed: NoiseRng(seed),
+                frequency: 1.0,
+            };
+            let values = Noise {
+                noise: BlendCellGradients::<SimplexGrid, SimplecticBlend, QuickGradients>::default(
+                ),
+                seed: NoiseRng(seed),
+                frequency: 1.0,
+            };
+            for x in -30..30 {
+                for y in -30..30 {
+                    let point = Vec2::new(x as f32 * 0.0713 + 0.01, y as f32 * 0.0537 - 0.02);
+                    let height = |offset: Vec2| values.sample_for::<f32>(point + offset);
+                    let approximate_gradient = Vec2::new(
+                        height(STEP * Vec2::X) - height(-STEP * Vec2::X),
+                        height(STEP * Vec2::Y) - height(-STEP * Vec2::Y),
+                    ) / (STEP * 2.0);
+                    let approximate_normal = (-approximate_gradient).extend(1.0).normalize();
+                    let normal: Vec3 = noise.sample(point);
+                    if normal.distance(approximate_normal) > EPSILON {
+                        println!(
+                            "Normal mismatch at point {point:?} with seed {seed}: approximate: {approximate_normal:?}, analytical: {normal:?}"
+                        );
+                        failures += 1;
+                    }
                 }
-                sum *= counter_dot_product;
-                sum
             }
         }
-    };
-}
-
-// constants adapted from libnoise
-impl_simplectic_blend!(Vec2, 99.836_85);
-impl_simplectic_blend!(Vec3, 76.883_76);
-impl_simplectic_blend!(Vec3A, 76.883_76);
-impl_simplectic_blend!(Vec4, 62.795_597);
+        assert_eq!(failures, 0, "Simplex normals failed at the above points.");
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::{
-        Noise, SampleableFor, ScalableNoise,
-        cells::{OrthoGrid, SimplexGrid},
-        lengths::EuclideanSqrdLength,
-        math_noise::Abs,
-        prelude::{
-            Billow, FractalLayers, LayeredNoise, Masked, Normed, NormedByDerivative, Octave,
-            PeakDerivativeContribution, Persistence, UNormToSNorm,
-        },
-        rng::{Random, SNorm},
-    };
+    #[test]
+    fn test_exact_f2_voronoi_continuous() {
+        assert_eq!(<Voronoi>::exact_f2_radius(1.0, 2), 3);
+        assert_eq!(<Voronoi>::exact_f2_radius(1.0, 3), 3);
+        assert_eq!(<Voronoi>::exact_f2_radius(1.0, 4), 3);
+
+        let noise =
+            Noise::<PerCellPointDistances<ExactF2Voronoi, EuclideanLength, WorleyDifference>>::default();
+        let step = 0.005;
+        for y in 0..200 {
+            let mut previous: f32 = noise.sample(Vec2::new(-2.0, y as f32 * 0.02 - 2.0));
+            for x in 1..800 {
+                let point = Vec2::new(x as f32 * step - 2.0, y as f32 * 0.02 - 2.0);
+                let value: f32 = noise.sample(point);
+                // Each distance moves at most `step`, so their difference moves at most twice that.
+                assert!(
+                    (value - previous).abs() <= step * 2.0 + 1e-4,
+                    "Discontinuity from {previous} to {value} at {point:?}"
+                );
+                previous = value;
+            }
+        }
+    }
 
-    /// Amount we step to approximate gradient. This must be significantly smaller than the
-    /// noise features to be any sort of accurate.
-    const STEP: f32 = 1e-4;
-    /// Epsilon for gradient approximation comparison.
-    const EPSILON: f32 = 1e-2;
+    #[test]
+    fn test_nearest_cell_info_consistent() {
+        let info = Noise::<NearestCellInfo<Voronoi, EuclideanLength>>::default();
+        let voronoi: Voronoi = Voronoi::default();
+        let ids = Noise::from(PerNearestPoint {
+            cells: voronoi,
+            length_mode: EuclideanLength,
+            noise: |id: u32, _: &mut NoiseRng| id,
+        });
+        let distances =
+            Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleyLeastDistance>>::default(
+            );
+        let max_distance = LengthFunction::<Vec2>::max_for_element_max(
+            &EuclideanLength,
+            voronoi
+                .partition(Vec2::ZERO)
+                .nearest_1d_point_always_within(),
+        );
+        for x in -20..20 {
+            for y in -20..20 {
+                let point = Vec2::new(x as f32, y as f32) * 0.37;
+                let nearest = info.sample_for::<CellPoint<Vec2>>(point);
+                assert_eq!(nearest.rough_id, ids.sample_for::<u32>(point));
+                let distance = distances.sample_for::<f32>(point) * max_distance;
+                assert!((nearest.offset.length() - distance).abs() < 1e-5);
+            }
+        }
+    }
 
-    fn test_grads_2d(noise: impl SampleableFor<Vec2, WithGradient<f32, Vec2>> + ScalableNoise) {
-        let mut failure = false;
+    #[test]
+    fn test_worley_gradients() {
+        let noise = Noise::<
+            PerCellPointDistances<Voronoi, EuclideanLength, WorleyLeastDistance, true>,
+        >::default();
+        let plain =
+            Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleyLeastDistance>>::default(
+            );
+        let distances = Noise::<WorleyDistances<Voronoi, EuclideanLength>>::default();
         for x in -10..=10 {
             for y in -10..=10 {
-                let point = Vec2::new(x as f32, y as f32) * 0.2;
-                let result = noise.sample(point);
+                let point = Vec2::new(x as f32, y as f32) * 0.23 + 0.01;
+                // Skip points near the borders between cells, where the gradient is undefined.
+                let f1_f2: Vec2 = distances.sample(point);
+                if f1_f2.y - f1_f2.x < 0.01 {
+                    continue;
+                }
+                let result: WithGradient<f32, Vec2> = noise.sample(point);
+                assert!((result.value - plain.sample_for::<f32>(point)).abs() < 1e-6);
                 let approximate_gradient = Vec2::new(
-                    noise.sample(point + STEP * Vec2::X).value
-                        - noise.sample(point - STEP * Vec2::X).value,
-                    noise.sample(point + STEP * Vec2::Y).value
-                        - noise.sample(point - STEP * Vec2::Y).value,
+                    plain.sample_for::<f32>(point + STEP * Vec2::X)
+                        - plain.sample_for::<f32>(point - STEP * Vec2::X),
+                    plain.sample_for::<f32>(point + STEP * Vec2::Y)
+                        - plain.sample_for::<f32>(point - STEP * Vec2::Y),
                 ) / (STEP * 2.0);
-                let analytical_gradient = result.gradient;
-                if approximate_gradient.distance(result.gradient) > EPSILON {
-                    let dir_approx = approximate_gradient.normalize_or_zero();
-                    let dir_analytical = analytical_gradient.normalize_or_zero();
-                    println!(
-                        "Gradient mismatch at point {point:?}: approximate: {approximate_gradient:?} dir {dir_approx:?}, analytical: {analytical_gradient:?} dir {dir_analytical:?}"
+                assert!(
+                    approximate_gradient.distance(result.gradient) < EPSILON,
+                    "Gradient mismatch at {point:?}: {approximate_gradient:?} vs {:?}",
+                    result.gradient
+                );
+            }
+        }
+
+        for x in -5..=5 {
+            for y in -5..=5 {
+                for z in -5..=5 {
+                    let point = Vec3::new(x as f32, y as f32, z as f32) * 0.23 + 0.01;
+                    let f1_f2: Vec2 = distances.sample(point);
+                    if f1_f2.y - f1_f2.x < 0.01 {
+                        continue;
+                    }
+                    let result: WithGradient<f32, Vec3> = noise.sample(point);
+                    let approximate_gradient = Vec3::new(
+                        plain.sample_for::<f32>(point + STEP * Vec3::X)
+                            - plain.sample_for::<f32>(point - STEP * Vec3::X),
+                        plain.sample_for::<f32>(point + STEP * Vec3::Y)
+                            - plain.sample_for::<f32>(point - STEP * Vec3::Y),
+                        plain.sample_for::<f32>(point + STEP * Vec3::Z)
+                            - plain.sample_for::<f32>(point - STEP * Vec3::Z),
+                    ) / (STEP * 2.0);
+                    assert!(
+                        approximate_gradient.distance(result.gradient) < EPSILON,
+                        "Gradient mismatch at {point:?}: {approximate_gradient:?} vs {:?}",
+                        result.gradient
                     );
-                    failure = true;
                 }
             }
         }
-        if failure {
-            panic!("Simplex gradients failed at the above points.");
+    }
+
+    #[test]
+    fn test_cubic_cell_values_smoother_than_bilinear() {
+        use crate::curves::Linear;
+        let cubic = Noise::<CubicCellValues<OrthoGrid, Random<UNorm, f32>>>::default();
+        let bilinear = Noise::<MixCellValues<OrthoGrid, Linear, Random<UNorm, f32>>>::default();
+        let step = 1e-3;
+        // The change in slope from one side of a cell border to the other.
+        let slope_jump = |noise: &dyn Fn(Vec2) -> f32, border: Vec2| {
+            let left = (noise(border) - noise(border - Vec2::X * step)) / step;
+            let right = (noise(border + Vec2::X * step) - noise(border)) / step;
+            (right - left).abs()
+        };
+        let mut cubic_total = 0.0;
+        let mut bilinear_total = 0.0;
+        for x in -10..10 {
+            let border = Vec2::new(x as f32, x as f32 * 0.37 + 0.3);
+            let cubic_jump = slope_jump(&|p| cubic.sample_for::<f32>(p), border);
+            assert!(
+                cubic_jump < 0.05,
+                "Slope jumps by {cubic_jump} at {border:?}"
+            );
+            cubic_total += cubic_jump;
+            bilinear_total += slope_jump(&|p| bilinear.sample_for::<f32>(p), border);
         }
+        assert!(bilinear_total > cubic_total * 10.0);
+
+        // Cubic interpolation still passes through the lattice values.
+        let corner = Vec2::new(3.0, -2.0);
+        assert!(
+            (cubic.sample_for::<f32>(corner) - bilinear.sample_for::<f32>(corner)).abs() < 1e-5
+        );
     }
 
     #[test]
-    fn test_simplex_gradients() {
-        test_grads_2d(Noise::<
-            BlendCellGradients<SimplexGrid, SimplecticBlend, QuickGradients, true>,
-        >::default());
+    fn test_raw_bits_matches_per_cell() {
+        let raw = Noise::<(RawBits<OrthoGrid>, Random<UNorm, f32>)>::default();
+        let per_cell = Noise::<PerCell<OrthoGrid, Random<UNorm, f32>>>::default();
+        for i in 0..20 {
+            let loc = Vec2::new(i as f32 * 0.7 - 7.0, i as f32 * 1.3);
+            assert_eq!(raw.sample_for::<f32>(loc), per_cell.sample_for::<f32>(loc));
+        }
     }
 
     #[test]
-    fn test_simplex_value_gradients() {
-        test_grads_2d(Noise::<
-            BlendCellValues<SimplexGrid, SimplecticBlend, Random<SNorm, f32>, true>,
-        >::default());
+    fn test_multi_worley_matches_separate() {
+        let multi = Noise::<
+            MultiWorley<Voronoi, EuclideanLength, (WorleyLeastDistance, WorleyDifference)>,
+        >::default();
+        // Too small to hold the 9 points of a 2d voronoi cell.
+        let overflowing = Noise::<
+            MultiWorley<Voronoi, EuclideanLength, (WorleyLeastDistance, WorleyDifference), 4>,
+        >::default();
+        let least =
+            Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleyLeastDistance>>::default(
+            );
+        let difference =
+            Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleyDifference>>::default();
+        for i in 0..20 {
+            let loc = Vec2::new(i as f32 * 0.7 - 7.0, i as f32 * 1.3);
+            let expected = (
+                least.sample_for::<f32>(loc),
+                difference.sample_for::<f32>(loc),
+            );
+            assert_eq!(multi.sample_for::<(f32, f32)>(loc), expected);
+            assert_eq!(overflowing.sample_for::<(f32, f32)>(loc), expected);
+        }
     }
 
     #[test]
-    fn test_mix_gradients() {
-        test_grads_2d(Noise::<
-            MixCellGradients<OrthoGrid, Smoothstep, QuickGradients, true>,
-        >::default());
+    fn test_additive_worley_falloff_and_sum() {
+        // Without randomness, the points sit exactly on the integer lattice.
+        let isolated = Noise::from(AdditiveWorley {
+            cells: <Voronoi>::default_with_randomness(0.0),
+            length_mode: EuclideanLength,
+            falloff: 0.5,
+        });
+        let point = Vec2::new(2.0, 3.0);
+        assert!((isolated.sample_for::<f32>(point) - 1.0).abs() < 1e-5);
+        for angle in 0..8 {
+            let direction = Vec2::from_angle(angle as f32 * 0.785);
+            for (distance, expected) in [(0.1, 0.8), (0.25, 0.5), (0.4, 0.2), (0.5, 0.0)] {
+                let value = isolated.sample_for::<f32>(point + direction * distance);
+                assert!(
+                    (value - expected).abs() < 1e-4,
+                    "{value} at distance {distance} should be {expected}"
+                );
+            }
+        }
+
+        let overlapping = Noise::from(AdditiveWorley {
+            cells: <Voronoi>::default_with_randomness(0.0),
+            length_mode: EuclideanLength,
+            falloff: 1.0,
+        });
+        let between = overlapping.sample_for::<f32>(Vec2::new(2.5, 3.0));
+        assert!(
+            (between - 1.0).abs() < 1e-4,
+            "Two halves should sum to 1, got {between}"
+        );
+        let near_one = overlapping.sample_for::<f32>(Vec2::new(2.25, 3.0));
+        assert!((near_one - 1.0).abs() < 1e-4);
+        let center = overlapping.sample_for::<f32>(Vec2::new(2.5, 3.5));
+        let diagonal = 1.0 - Vec2::splat(0.5).length();
+        assert!((center - diagonal * 4.0).abs() < 1e-4);
     }
 
     #[test]
-    fn test_mix_value_gradients() {
-        test_grads_2d(Noise::<
-            MixCellValues<OrthoGrid, Smoothstep, Random<SNorm, f32>, true>,
-        >::default());
+    fn test_cell_size_estimate_grows_with_area() {
+        use std::collections::{HashMap, HashSet};
+
+        let estimate = Noise::<CellSizeEstimate<Voronoi, EuclideanLength>>::default();
+        let nearest = Noise::<NearestCellInfo<Voronoi, EuclideanLength>>::default();
+        const SIZE: i32 = 200;
+        const RESOLUTION: f32 = 10.0;
+        // The sample count and summed estimate for each cell.
+        let mut cells = HashMap::<u32, (u32, f32)>::new();
+        let mut on_border = HashSet::new();
+        for x in 0..SIZE {
+            for y in 0..SIZE {
+                let point = Vec2::new(x as f32, y as f32) / RESOLUTION;
+                let id = nearest.sample_for::<CellPoint<Vec2>>(point).rough_id;
+                if x == 0 || y == 0 || x == SIZE - 1 || y == SIZE - 1 {
+                    on_border.insert(id);
+                }
+                let cell = cells.entry(id).or_default();
+                cell.0 += 1;
+                cell.1 += estimate.sample_for::<f32>(point);
+            }
+        }
+
+        let samples: Vec<(f32, f32)> = cells
+            .into_iter()
+            .filter(|(id, _)| !on_border.contains(id))
+            .map(|(_, (count, total))| {
+                (
+                    count as f32 / (RESOLUTION * RESOLUTION),
+                    total / count as f32,
+                )
+            })
+            .collect();
+        assert!(samples.len() > 100);
+        let n = samples.len() as f32;
+        let mean_area = samples.iter().map(|s| s.0).sum::<f32>() / n;
+        let mean_estimate = samples.iter().map(|s| s.1).sum::<f32>() / n;
+        let (mut covariance, mut area_variance, mut estimate_variance) = (0.0, 0.0, 0.0);
+        for (area, estimate) in samples {
+            covariance += (area - mean_area) * (estimate - mean_estimate);
+            area_variance += (area - mean_area) * (area - mean_area);
+            estimate_variance += (estimate - mean_estimate) * (estimate - mean_estimate);
+        }
+        let correlation = covariance / (area_variance * estimate_variance).sqrt();
+        assert!(
+            correlation > 0.5,
+            "Estimates only correlate with cell area by {correlation}"
+        );
     }
 
     #[test]
-    fn test_mask_gradients() {
-        test_grads_2d(Noise::<
-            Masked<
-                MixCellGradients<OrthoGrid, Smoothstep, QuickGradients, true>,
-                BlendCellGradients<SimplexGrid, SimplecticBlend, QuickGradients, true>,
-            >,
-        >::default());
+    fn test_worley_polar() {
+        let polar = Noise::<WorleyPolar<Voronoi, EuclideanLength>>::default();
+        let nearest = Noise::<NearestCellInfo<Voronoi, EuclideanLength>>::default();
+        let worley = Noise::<crate::prelude::common_noise::Worley>::default();
+        for y in [0.13, 1.71] {
+            let mut previous: Option<(u32, Vec2)> = None;
+            for x in 0..400 {
+                let point = Vec2::new(x as f32 * 0.01, y);
+                let result = polar.sample_for::<Vec2>(point);
+                assert!((result.x - worley.sample_for::<f32>(point)).abs() < 1e-6);
+                assert!((0.0..=1.0).contains(&result.y));
+                let info = nearest.sample_for::<CellPoint<Vec2>>(point);
+                if let Some((previous_id, previous_result)) = previous {
+                    let change = (result.y - previous_result.y).abs();
+                    // Within a cell, the angle only jumps where it wraps around.
+                    // Very close to the point, it changes quickly, so those are skipped.
+                    if previous_id == info.rough_id && change < 0.9 && info.offset.length() > 0.1 {
+                        assert!(change < 0.05, "Angle jumped by {change} at {point:?}");
+                    }
+                }
+                previous = Some((info.rough_id, result));
+            }
+        }
     }
 
     #[test]
-    fn test_fbm_gradients() {
-        test_grads_2d(Noise::<
-            LayeredNoise<
-                Normed<WithGradient<f32, Vec2>>,
-                Persistence,
-                FractalLayers<
-                    Octave<BlendCellGradients<SimplexGrid, SimplecticBlend, QuickGradients, true>>,
-                >,
-            >,
-        >::default());
+    fn test_craters_profile() {
+        // Without randomness, the craters are centered exactly on the integer lattice.
+        let craters = Noise::from(Craters {
+            cells: <Voronoi>::default_with_randomness(0.0),
+            length_mode: EuclideanLength,
+            radius: 0.2,
+            rim: 0.25,
+            depth: 1.0,
+        });
+        let center = Vec2::new(2.0, 3.0);
+        assert!((craters.sample_for::<f32>(center) + 1.0).abs() < 1e-5);
+        let floor = craters.sample_for::<f32>(center + Vec2::new(0.1, 0.0));
+        assert!(floor < 0.0 && floor > -1.0, "Floor is at {floor}");
+        let rim = craters.sample_for::<f32>(center + Vec2::new(0.0, -0.2));
+        assert!((rim - 0.25).abs() < 1e-4, "Rim is at {rim}");
+        let outer_slope = craters.sample_for::<f32>(center + Vec2::new(-0.3, 0.0));
+        assert!(outer_slope > 0.0 && outer_slope < 0.25);
+        for exterior in [
+            Vec2::new(2.5, 3.0),
+            Vec2::new(2.5, 3.5),
+            Vec2::new(2.0, 3.45),
+        ] {
+            assert_eq!(craters.sample_for::<f32>(exterior), 0.0);
+        }
     }
 
     #[test]
-    #[ignore = "This is close to correct, but not quite. But the math seems to be correct. Probably, the gradients are correct but the function is not classically differentiable."]
-    fn test_erosion_approx_fbm_gradients() {
-        test_grads_2d(Noise::<
-            LayeredNoise<
-                NormedByDerivative<
-                    WithGradient<f32, Vec2>,
-                    EuclideanSqrdLength,
-                    PeakDerivativeContribution,
-                >,
-                Persistence,
-                FractalLayers<
-                    Octave<BlendCellGradients<SimplexGrid, SimplecticBlend, QuickGradients, true>>,
-                >,
-            >,
-        >::from(LayeredNoise::new(
-            NormedByDerivative::default().with_falloff(1.0),
-            Default::default(),
-            FractalLayers {
-                layer: Octave::default(),
-                lacunarity: 1.0,
-                amount: 2,
+    fn test_organic_worley() {
+        let plain =
+            Noise::<PerCellPointDistances<Voronoi, EuclideanLength, WorleyLeastDistance>>::default(
+            );
+        let unwarped = Noise::from(
+            OrganicWorley::<Voronoi, EuclideanLength, WorleyLeastDistance> {
+                warp_strength: 0.0,
+                ..Default::default()
             },
-        )));
+        );
+        for x in -20..=20 {
+            for y in -20..=20 {
+                let p = Vec2::new(x as f32, y as f32) * 0.37;
+                let a: f32 = plain.sample(p);
+                let b: f32 = unwarped.sample(p);
+                assert_eq!(a, b, "strength 0 should match plain worley at {p}");
+            }
+        }
+
+        // With points on the lattice, the plain border between cells 2 and 3 is the straight line x = 2.5.
+        let grid = PerCellPointDistances {
+            cells: <Voronoi>::default_with_randomness(0.0),
+            length_mode: EuclideanLength,
+            worley_mode: WorleyLeastDistance,
+        };
+        let plain = Noise::from(grid);
+        let warped = Noise::from(OrganicWorley {
+            worley: grid,
+            warp:
+                RandomElements::<MixCellGradients<OrthoGrid, Smoothstep, QuickGradients>>::default(),
+            warp_strength: 0.3,
+        });
+        let border_x = |noise: &dyn Fn(Vec2) -> f32, y: f32| {
+            let mut best = (0.0, f32::MIN);
+            for i in 0..=200 {
+                let x = 2.0 + i as f32 * 0.005;
+                let value = noise(Vec2::new(x, y));
+                if value > best.1 {
+                    best = (x, value);
+                }
+            }
+            best.0
+        };
+        let (mut plain_min, mut plain_max) = (f32::MAX, f32::MIN);
+        let (mut warped_min, mut warped_max) = (f32::MAX, f32::MIN);
+        for j in 0..=160 {
+            let y = 2.6 + j as f32 * 0.005;
+            let p = border_x(&|p| plain.sample(p), y);
+            let w = border_x(&|p| warped.sample(p), y);
+            plain_min = plain_min.min(p);
+            plain_max = plain_max.max(p);
+            warped_min = warped_min.min(w);
+            warped_max = warped_max.max(w);
+        }
+        assert!(
+            plain_max - plain_min < 0.011,
+            "plain borders should be straight"
+        );
+        assert!(warped_max - warped_min > 0.02, "warped borders should bend");
     }
 
     #[test]
-    #[ignore = "abs is not mathematically rigorously differentiable"]
-    fn test_billowing_gradients() {
-        test_grads_2d(Noise::<(
-            BlendCellGradients<SimplexGrid, SimplecticBlend, QuickGradients, true>,
-            Billow,
-        )>::default());
+    fn test_periodic_voronoi_tiles() {
+        let period = IVec2::new(5, 3);
+        let noise = Noise::<
+            PerCellPointDistances<PeriodicVoronoi, EuclideanLength, WorleyLeastDistance>,
+        >::from(PerCellPointDistances {
+            cells: PeriodicVoronoi::periodic(period),
+            length_mode: EuclideanLength,
+            worley_mode: WorleyLeastDistance,
+        });
+        for x in -20..=20 {
+            for y in -20..=20 {
+                let loc = Vec2::new(x as f32, y as f32) * 0.37;
+                let value: f32 = noise.sample(loc);
+                for shift in [
+                    period.as_vec2(),
+                    -period.as_vec2(),
+                    Vec2::new(period.x as f32, 0.0) * 2.0,
+                ] {
+                    let tiled: f32 = noise.sample(loc + shift);
+                    assert!(
+                        (value - tiled).abs() < 1e-4,
+                        "{value} at {loc} but {tiled} after shifting by {shift}"
+                    );
+                }
+            }
+        }
     }
 
     #[test]
-    #[ignore = "abs is not mathematically rigorously differentiable"]
-    fn test_abs_gradients() {
-        test_grads_2d(Noise::<(
-            BlendCellGradients<SimplexGrid, SimplecticBlend, QuickGradients, true>,
-            Abs,
-        )>::default());
+    fn test_cell_interpolation_weights() {
+        let noise = Noise::<CellInterpolationWeights<OrthoGrid, Smoothstep>>::default();
+        for x in -20..=20 {
+            for y in -20..=20 {
+                let point = Vec2::new(x as f32, y as f32) * 0.37;
+                let weights: Vec4 = noise.sample(point);
+                let offset = point - point.floor();
+                for (axis, t) in offset.to_array().into_iter().enumerate() {
+                    let expected = Smoothstep.sample_with_derivative_unchecked(t);
+                    assert!((weights[axis] - expected.value).abs() < 1e-6);
+                    assert!((weights[axis + 2] - expected.derivative).abs() < 1e-6);
+                    // Smoothstep's derivative is 6t(1 - t).
+                    assert!((weights[axis + 2] - 6.0 * t * (1.0 - t)).abs() < 1e-5);
+                }
+            }
+        }
     }
 
     #[test]
-    fn test_unorm_to_snorm_gradients() {
-        test_grads_2d(Noise::<(
-            BlendCellGradients<SimplexGrid, SimplecticBlend, QuickGradients, true>,
-            UNormToSNorm,
-        )>::default());
+    fn test_perlin_value_mix_endpoints() {
+        let value = Noise::<MixCellValues<OrthoGrid, Smoothstep, Random<SNorm, f32>>>::default();
+        let perlin = Noise::<MixCellGradients<OrthoGrid, Smoothstep, QuickGradients>>::default();
+        let mix = |mix: f32| {
+            Noise::from(PerlinValueMix::<OrthoGrid> {
+                mix,
+                ..Default::default()
+            })
+        };
+        let (all_value, all_perlin, half) = (mix(0.0), mix(1.0), mix(0.5));
+        for x in -20..=20 {
+            for y in -20..=20 {
+                let point = Vec2::new(x as f32, y as f32) * 0.37;
+                let expected_value: f32 = value.sample(point);
+                let expected_perlin: f32 = perlin.sample(point);
+                let sampled: f32 = all_value.sample(point);
+                assert!((sampled - expected_value).abs() < 1e-6);
+                let sampled: f32 = all_perlin.sample(point);
+                assert!((sampled - expected_perlin).abs() < 1e-6);
+                let sampled: f32 = half.sample(point);
+                assert!((sampled - (expected_value + expected_perlin) * 0.5).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_worley_output_range_by_mode() {
+        let normalized =
+            PerCellPointDistances::<Voronoi, EuclideanLength, WorleyLeastDistance>::default();
+        assert!(normalized.output_range(OutputRange::UNKNOWN) == OutputRange::UNORM);
+
+        let smoothed = PerCellPointDistances::<
+            Voronoi,
+            EuclideanLength,
+            WorleyNearestSmoothMinN<crate::curves::CubicSMin, 3>,
+        >::default();
+        assert!(smoothed.output_range(OutputRange::UNKNOWN) == OutputRange::UNKNOWN);
+        let anisotropic = AnisotropicWorley::<
+            Voronoi,
+            EuclideanLength,
+            WorleySmoothMin<crate::curves::CubicSMin>,
+        >::default();
+        assert!(anisotropic.output_range(OutputRange::UNKNOWN) == OutputRange::UNKNOWN);
+    }
+
+    #[test]
+    fn test_smooth_min_n_triple_junction() {
+        use crate::curves::CubicSMin;
+
+        let two = WorleyNearestSmoothMinN::<CubicSMin, 2> {
+            smoothing_inverse_radius: 0.1,
+            ..Default::default()
+        };
+        let three = WorleyNearestSmoothMinN::<CubicSMin, 3> {
+            smoothing_inverse_radius: 0.1,
+            ..Default::default()
+        };
+        let two =
+            |points: [Vec2; 3]| two.evaluate_worley(points.into_iter(), &EuclideanLength, 1.0, 1.0);
+        let three = |points: [Vec2; 3]| {
+            three.evaluate_worley(points.into_iter(), &EuclideanLength, 1.0, 1.0)
+        };
+
+        // When the third point is far away, it doesn't contribute.
+        let edge = [
+            Vec2::new(0.5, 0.0),
+            Vec2::new(-0.5, 0.0),
+            Vec2::new(0.0, 5.0),
+        ];
+        assert!((two(edge) - three(edge)).abs() < 1e-5);
+
+        // At a triple junction, all three points are equidistant, so all three should be smoothed.
+        let junction = [
+            Vec2::from_angle(0.0) * 0.5,
+            Vec2::from_angle(f32::consts::TAU / 3.0) * 0.5,
+            Vec2::from_angle(f32::consts::TAU * 2.0 / 3.0) * 0.5,
+        ];
+        let faceted = two(junction);
+        let rounded = three(junction);
+        assert!(
+            rounded < faceted - 1e-3,
+            "The third point was not smoothed in: {rounded} vs {faceted}"
+        );
+    }
+
+    #[test]
+    fn test_cell_blend_weights() {
+        let noise = Noise::<CellBlendWeights<OrthoGrid, Smoothstep>>::default();
+        for x in -10..=10 {
+            for y in -10..=10 {
+                let point = Vec2::new(x as f32, y as f32) * 0.37;
+                let corners: CellCornerWeights = noise.sample(point);
+                let total: f32 = corners.weights.iter().sum();
+                assert!(
+                    (total - 1.0).abs() < 1e-5,
+                    "Weights sum to {total} at {point:?}"
+                );
+            }
+        }
+
+        let on_corner: CellCornerWeights = noise.sample(Vec2::new(3.0, -5.0));
+        assert_eq!(on_corner.weights, [1.0, 0.0, 0.0, 0.0]);
+        let centered: CellCornerWeights = noise.sample(Vec2::new(3.5, -4.5));
+        for weight in centered.weights {
+            assert!((weight - 0.25).abs() < 1e-5);
+        }
+        assert_eq!(on_corner.rough_ids, centered.rough_ids);
+    }
+
+    #[test]
+    fn test_distance_falloff_blend() {
+        let noise = Noise::<
+            BlendCellValues<Voronoi, DistanceFalloffBlend<EuclideanLength>, Random<UNorm, f32>>,
+        >::default();
+        let mut previous: f32 = noise.sample(Vec2::new(-3.0, 0.3));
+        for step in 1..=6000 {
+            let point = Vec2::new(-3.0 + step as f32 * 1e-3, 0.3 + step as f32 * 3e-4);
+            let value: f32 = noise.sample(point);
+            // Only the 9 points in the neighborhood can contribute, each with a weight of at most 1.
+            assert!(
+                (0.0..9.0).contains(&value),
+                "Value {value} is out of bounds at {point:?}"
+            );
+            assert!(
+                (value - previous).abs() < 0.05,
+                "Discontinuity from {previous} to {value} at {point:?}"
+            );
+            previous = value;
+        }
     }
 }
@@ -7,7 +7,7 @@ use bevy_math::{
     curve::derivatives::SampleDerivative,
 };
 
-use crate::rng::{AnyValueFromBits, NoiseRng, NoiseRngInput, UNorm, UNormHalf};
+use crate::rng::{NoiseRng, NoiseRngInput};
 
 /// Represents a portion or cell of some larger domain and a position within that cell.
 ///
@@ -102,6 +102,30 @@ pub struct WithGradient<T, G> {
     pub gradient: G,
 }
 
+/// A value `T` with its gradient `G` and Hessian `H`, the matrix of its second partial derivatives.
+#[derive(Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct WithHessian<T, G, H> {
+    /// The value.
+    pub value: T,
+    /// The gradient of the value.
+    pub gradient: G,
+    /// The Hessian of the value.
+    pub hessian: H,
+}
+
+impl<T, G, H> From<WithHessian<T, G, H>> for WithGradient<T, G> {
+    #[inline]
+    fn from(value: WithHessian<T, G, H>) -> Self {
+        Self {
+            value: value.value,
+            gradient: value.gradient,
+        }
+    }
+}
+
 impl<T: Add<T, Output = T>, G: Add<G, Output = G>> Add<Self> for WithGradient<T, G> {
     type Output = Self;
 
@@ -405,6 +429,29 @@ impl<W: WrappingAmount<IVec2>> SquareCell<Vec2, IVec2, W> {
         }
     }
 
+    /// Iterates the 4x4 neighborhood of lattice points around this cell, from `floored - 1` to `floored + 2` along each axis, with x changing fastest.
+    /// This is the neighborhood needed for cubic interpolation, like in [`CubicCellValues`](crate::cell_noise::CubicCellValues).
+    #[inline]
+    pub fn iter_cubic_points(&self, rng: NoiseRng) -> impl Iterator<Item = CellPoint<Vec2>> {
+        (0..16).map(move |i| self.point_at_offset(rng, IVec2::new(i % 4 - 1, i / 4 - 1)))
+    }
+
+    /// Samples `curve` at the [`offset`](Self::offset) along each axis, producing the interpolation weight and its derivative for x and y.
+    /// These are the weights used by [`interpolate_within`](InterpolatableCell::interpolate_within) and [`interpolation_gradient`](DifferentiableCell::interpolation_gradient).
+    #[inline]
+    pub fn interpolation_weights(
+        &self,
+        curve: &impl SampleDerivative<f32>,
+    ) -> [WithGradient<f32, f32>; 2] {
+        self.offset.to_array().map(|t| {
+            let sample = curve.sample_with_derivative_unchecked(t);
+            WithGradient {
+                value: sample.value,
+                gradient: sample.derivative,
+            }
+        })
+    }
+
     #[inline]
     fn corners_map<T>(&self, rng: NoiseRng, mut f: impl FnMut(CellPoint<Vec2>) -> T) -> [T; 4] {
         [
@@ -462,10 +509,7 @@ impl<W: WrappingAmount<IVec2>> DifferentiableCell for SquareCell<Vec2, IVec2, W>
     ) -> Self::Gradient<T> {
         // points
         let [ld, lu, rd, ru] = self.corners_map(rng, f);
-        let [mix_x, mix_y] = self
-            .offset
-            .to_array()
-            .map(|t| curve.sample_with_derivative_unchecked(t));
+        let [mix_x, mix_y] = self.interpolation_weights(curve);
 
         // derivatives
         let ld_lu = ld - lu;
@@ -474,8 +518,8 @@ impl<W: WrappingAmount<IVec2>> DifferentiableCell for SquareCell<Vec2, IVec2, W>
         let lu_ru = lu - ru;
 
         // lerp
-        let dx = ld_rd.lerp(lu_ru, mix_y.value) * mix_x.derivative;
-        let dy = ld_lu.lerp(rd_ru, mix_x.value) * mix_y.derivative;
+        let dx = ld_rd.lerp(lu_ru, mix_y.value) * mix_x.gradient;
+        let dy = ld_lu.lerp(rd_ru, mix_x.value) * mix_y.gradient;
         [-dx * gradient_scale, -dy * gradient_scale]
     }
 }
@@ -1380,15 +1424,21 @@ impl Partitioner<Vec4> for SimplexGrid {
 /// Typically, this happens when a noise function depends on multiple nearby points instead of just the closest.
 /// If something looks strange, turn it off, and it might help.
 /// This option is included because, where it doesn't artifact, it can greatly improve performance.
+///
+/// When `HALF_SCALE` is off, `RADIUS` (defaults to 1) controls how many surrounding cells are searched along each axis:
+/// 1 searches a 3x3 area, 2 searches a 5x5 area, etc.
+/// With a [`randomness`](Voronoi::randomness) over 1, the true nearest point can be further away than the default search covers, producing discontinuities.
+/// Increasing `RADIUS` fixes this at the cost of performance.
+/// When `HALF_SCALE` is on, only the default `RADIUS` of 1 is supported, since only the positive surrounding cells are searched.
 #[derive(Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub struct Voronoi<const HALF_SCALE: bool = false, P = OrthoGrid> {
+pub struct Voronoi<const HALF_SCALE: bool = false, P = OrthoGrid, const RADIUS: u32 = 1> {
     /// The inner [`Partitioner`] that will have its [`DomainCell`]'s [`CellPoint`]s moved
     pub partitoner: P,
     /// How much each [`CellPoint`]s will be moved.
-    /// Values that are not clamped to 0 and 1 are not invalid but will produce discontinuities.
+    /// Values that are not clamped to 0 and 1 are not invalid but will produce discontinuities unless `RADIUS` is increased.
     /// It is recommended to keep this between 0 and 1/
     ///
     /// If this is less than 0.5, and `HALF_SCALE` is off (default),
@@ -1396,7 +1446,9 @@ pub struct Voronoi<const HALF_SCALE: bool = false, P = OrthoGrid> {
     pub randomness: f32,
 }
 
-impl<P: Default, const HALF_SCALE: bool> Default for Voronoi<HALF_SCALE, P> {
+impl<P: Default, const HALF_SCALE: bool, const RADIUS: u32> Default
+    for Voronoi<HALF_SCALE, P, RADIUS>
+{
     fn default() -> Self {
         Self {
             partitoner: P::default(),
@@ -1405,12 +1457,12 @@ impl<P: Default, const HALF_SCALE: bool> Default for Voronoi<HALF_SCALE, P> {
     }
 }
 
-impl<T: VectorSpace, P: Partitioner<T>, const HALF_SCALE: bool> Partitioner<T>
-    for Voronoi<HALF_SCALE, P>
+impl<T: VectorSpace, P: Partitioner<T>, const HALF_SCALE: bool, const RADIUS: u32> Partitioner<T>
+    for Voronoi<HALF_SCALE, P, RADIUS>
 where
-    VoronoiCell<HALF_SCALE, P::Cell>: DomainCell<Full = T>,
+    VoronoiCell<HALF_SCALE, P::Cell, RADIUS>: DomainCell<Full = T>,
 {
-    type Cell = VoronoiCell<HALF_SCALE, P::Cell>;
+    type Cell = VoronoiCell<HALF_SCALE, P::Cell, RADIUS>;
 
     #[inline]
     fn partition(&self, full: T) -> Self::Cell {
@@ -1421,7 +1473,7 @@ where
     }
 }
 
-impl<P, const HALF_SCALE: bool> Voronoi<HALF_SCALE, P> {
+impl<P, const HALF_SCALE: bool, const RADIUS: u32> Voronoi<HALF_SCALE, P, RADIUS> {
     /// Constructs a new [`Voronoi`] with this `randomness` and a default partitioner.
     /// See [`randomness`](Voronoi::randomness) for details.
     #[inline]
@@ -1434,13 +1486,73 @@ impl<P, const HALF_SCALE: bool> Voronoi<HALF_SCALE, P> {
             randomness,
         }
     }
+
+    /// Computes the smallest `RADIUS` that guarantees the true nearest and next nearest points are always searched,
+    /// when `HALF_SCALE` is off and [`randomness`](Voronoi::randomness) is at most `randomness` in `dimensions` dimensions.
+    ///
+    /// The next nearest point is never further than `sqrt((1 + randomness)² + (dimensions - 1) * max(1, randomness)²)`,
+    /// since the point of the sample's own cell and that of a neighbor along one axis are always that close.
+    /// Points outside the searched area are never closer than `RADIUS + 1 - max(1, randomness)`, so the radius is chosen to keep them beyond that.
+    /// See also [`ExactF2Voronoi`], which uses the result for a `randomness` of 1.
+    pub fn exact_f2_radius(randomness: f32, dimensions: u32) -> u32 {
+        let randomness = randomness.max(0.0);
+        let along_axis = 1.0 + randomness;
+        let other_axes = randomness.max(1.0);
+        let next_nearest_bound = bevy_math::ops::sqrt(
+            along_axis * along_axis + (dimensions.max(1) - 1) as f32 * other_axes * other_axes,
+        );
+        bevy_math::ops::ceil(next_nearest_bound + other_axes - 1.0) as u32
+    }
+}
+
+/// A [`Voronoi`] graph that searches enough surrounding cells to always find the true nearest and next nearest points,
+/// for any [`randomness`](Voronoi::randomness) up to 1 in 2, 3, or 4 dimensions.
+///
+/// The standard 3x3 search can miss the next nearest point under high randomness,
+/// which makes worley modes like [`WorleyDifference`](crate::cell_noise::WorleyDifference) discontinuous.
+/// This fixes that by searching a 7x7 area (in 2d), which is much slower.
+/// For other randomness bounds, use [`Voronoi::exact_f2_radius`] to pick a `RADIUS`.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// use noiz::{cell_noise::WorleyDifference, cells::ExactF2Voronoi};
+/// let noise = Noise::<PerCellPointDistances<ExactF2Voronoi, EuclideanLength, WorleyDifference>>::default();
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+pub type ExactF2Voronoi<P = OrthoGrid> = Voronoi<false, P, 3>;
+
+/// A 2d [`Voronoi`] graph whose points repeat every [`IVec2`] period, for seamlessly tiling cellular noise.
+/// Since each point is hashed from its wrapped grid coordinate, the graph at `loc` is exactly the same as at `loc + period`.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// use noiz::cells::PeriodicVoronoi;
+/// let noise = Noise::<PerCellPointDistances<PeriodicVoronoi, EuclideanLength, WorleyLeastDistance>>::from(PerCellPointDistances {
+///     cells: PeriodicVoronoi::periodic(bevy_math::IVec2::new(8, 4)),
+///     length_mode: EuclideanLength,
+///     worley_mode: WorleyLeastDistance,
+/// });
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+pub type PeriodicVoronoi<const RADIUS: u32 = 1> = Voronoi<false, OrthoGrid<IVec2>, RADIUS>;
+
+impl<const HALF_SCALE: bool, const RADIUS: u32> Voronoi<HALF_SCALE, OrthoGrid<IVec2>, RADIUS> {
+    /// Constructs a new [`Voronoi`] with full randomness whose points repeat every `period` cells.
+    /// See also [`PeriodicVoronoi`].
+    #[inline]
+    pub fn periodic(period: IVec2) -> Self {
+        Self {
+            partitoner: OrthoGrid(period),
+            randomness: 1.0,
+        }
+    }
 }
 
 /// A [`DomainCell`] that wraps an inner [`DomainCell`] and nudges each [`CellPoint`]s by some value.
 /// See [`Voronoi`] for details.
 /// This is currently only implemented for [`SquareCell`]s.
 #[derive(Default, Clone, Copy, PartialEq)]
-pub struct VoronoiCell<const HALF_SCALE: bool, C> {
+pub struct VoronoiCell<const HALF_SCALE: bool, C, const RADIUS: u32 = 1> {
     /// The inner cell that will have it's [`CellPoint`]s moved
     pub cell: C,
     /// How much the [`CellPoint`]s will be moved.
@@ -1448,8 +1560,8 @@ pub struct VoronoiCell<const HALF_SCALE: bool, C> {
     pub randomness: f32,
 }
 
-impl<C: BlendableDomainCell, const HALF_SCALE: bool> BlendableDomainCell
-    for VoronoiCell<HALF_SCALE, C>
+impl<C: BlendableDomainCell, const HALF_SCALE: bool, const RADIUS: u32> BlendableDomainCell
+    for VoronoiCell<HALF_SCALE, C, RADIUS>
 where
     Self: DomainCell,
 {
@@ -1459,7 +1571,8 @@ where
     }
 }
 
-impl<C: WorleyDomainCell, const HALF_SCALE: bool> WorleyDomainCell for VoronoiCell<HALF_SCALE, C>
+impl<C: WorleyDomainCell, const HALF_SCALE: bool, const RADIUS: u32> WorleyDomainCell
+    for VoronoiCell<HALF_SCALE, C, RADIUS>
 where
     Self: DomainCell,
 {
@@ -1474,6 +1587,38 @@ where
     }
 }
 
+/// Iterates every offset within `radius` cells along each axis, with x changing fastest.
+#[inline(always)]
+fn voronoi_neighborhood_2d(radius: u32) -> impl Iterator<Item = IVec2> {
+    let radius = radius as i32;
+    let side = radius * 2 + 1;
+    (0..side * side).map(move |i| IVec2::new(i % side, i / side) - radius)
+}
+
+/// Iterates every offset within `radius` cells along each axis, with x changing fastest.
+#[inline(always)]
+fn voronoi_neighborhood_3d(radius: u32) -> impl Iterator<Item = IVec3> {
+    let radius = radius as i32;
+    let side = radius * 2 + 1;
+    (0..side * side * side)
+        .map(move |i| IVec3::new(i % side, i / side % side, i / (side * side)) - radius)
+}
+
+/// Iterates every offset within `radius` cells along each axis, with x changing fastest.
+#[inline(always)]
+fn voronoi_neighborhood_4d(radius: u32) -> impl Iterator<Item = IVec4> {
+    let radius = radius as i32;
+    let side = radius * 2 + 1;
+    (0..side * side * side * side).map(move |i| {
+        IVec4::new(
+            i % side,
+            i / side % side,
+            i / (side * side) % side,
+            i / (side * side * side),
+        ) - radius
+    })
+}
+
 /// We use this as an xor. The number doesn't matter as long as it is unique (relative to other numbers used like this) and changes some bits in every part of the u32;
 const VORONOI_RNG_DIFF: u32 = 0b_011010011010110110110100110101001;
 
@@ -1487,10 +1632,10 @@ impl<W: WrappingAmount<IVec2>> DomainCell for VoronoiCell<true, SquareCell<Vec2,
 
     #[inline]
     fn iter_points(&self, rng: NoiseRng) -> impl Iterator<Item = CellPoint<Self::Full>> {
-        self.cell.iter_points(rng).map(|mut point| {
-            let push_between_1_and_half: Vec2 =
-                UNormHalf.any_value(point.rough_id ^ VORONOI_RNG_DIFF);
-            point.offset -= push_between_1_and_half * self.randomness;
+        self.cell.iter_points(rng).map(move |mut point| {
+            let push_between_0_and_half: Vec2 =
+                rng.rand_unorm_vec2(point.rough_id ^ VORONOI_RNG_DIFF) * 0.5;
+            point.offset -= push_between_0_and_half * self.randomness;
             point
         })
     }
@@ -1506,10 +1651,10 @@ impl<W: WrappingAmount<IVec3>> DomainCell for VoronoiCell<true, SquareCell<Vec3,
 
     #[inline]
     fn iter_points(&self, rng: NoiseRng) -> impl Iterator<Item = CellPoint<Self::Full>> {
-        self.cell.iter_points(rng).map(|mut point| {
-            let push_between_1_and_half: Vec3 =
-                UNormHalf.any_value(point.rough_id ^ VORONOI_RNG_DIFF);
-            point.offset -= push_between_1_and_half * self.randomness;
+        self.cell.iter_points(rng).map(move |mut point| {
+            let push_between_0_and_half: Vec3 =
+                rng.rand_unorm_vec3(point.rough_id ^ VORONOI_RNG_DIFF) * 0.5;
+            point.offset -= push_between_0_and_half * self.randomness;
             point
         })
     }
@@ -1525,10 +1670,10 @@ impl<W: WrappingAmount<IVec3>> DomainCell for VoronoiCell<true, SquareCell<Vec3A
 
     #[inline]
     fn iter_points(&self, rng: NoiseRng) -> impl Iterator<Item = CellPoint<Self::Full>> {
-        self.cell.iter_points(rng).map(|mut point| {
-            let push_between_1_and_half: Vec3A =
-                UNormHalf.any_value(point.rough_id ^ VORONOI_RNG_DIFF);
-            point.offset -= push_between_1_and_half * self.randomness;
+        self.cell.iter_points(rng).map(move |mut point| {
+            let push_between_0_and_half: Vec3A =
+                Vec3A::from(rng.rand_unorm_vec3(point.rough_id ^ VORONOI_RNG_DIFF)) * 0.5;
+            point.offset -= push_between_0_and_half * self.randomness;
             point
         })
     }
@@ -1544,16 +1689,18 @@ impl<W: WrappingAmount<IVec4>> DomainCell for VoronoiCell<true, SquareCell<Vec4,
 
     #[inline]
     fn iter_points(&self, rng: NoiseRng) -> impl Iterator<Item = CellPoint<Self::Full>> {
-        self.cell.iter_points(rng).map(|mut point| {
-            let push_between_1_and_half: Vec4 =
-                UNormHalf.any_value(point.rough_id ^ VORONOI_RNG_DIFF);
-            point.offset -= push_between_1_and_half * self.randomness;
+        self.cell.iter_points(rng).map(move |mut point| {
+            let push_between_0_and_half: Vec4 =
+                rng.rand_unorm_vec4(point.rough_id ^ VORONOI_RNG_DIFF) * 0.5;
+            point.offset -= push_between_0_and_half * self.randomness;
             point
         })
     }
 }
 
-impl<W: WrappingAmount<IVec2>> DomainCell for VoronoiCell<false, SquareCell<Vec2, IVec2, W>> {
+impl<W: WrappingAmount<IVec2>, const RADIUS: u32> DomainCell
+    for VoronoiCell<false, SquareCell<Vec2, IVec2, W>, RADIUS>
+{
     type Full = Vec2;
 
     #[inline]
@@ -1563,29 +1710,18 @@ impl<W: WrappingAmount<IVec2>> DomainCell for VoronoiCell<false, SquareCell<Vec2
 
     #[inline]
     fn iter_points(&self, rng: NoiseRng) -> impl Iterator<Item = CellPoint<Self::Full>> {
-        // TODO: Improve these with generators when they land.
-        [
-            IVec2::new(-1, -1),
-            IVec2::new(0, -1),
-            IVec2::new(1, -1),
-            IVec2::new(-1, 0),
-            IVec2::new(0, 0),
-            IVec2::new(1, 0),
-            IVec2::new(-1, 1),
-            IVec2::new(0, 1),
-            IVec2::new(1, 1),
-        ]
-        .into_iter()
-        .map(move |offset| {
+        voronoi_neighborhood_2d(RADIUS).map(move |offset| {
             let mut point = self.cell.point_at_offset(rng, offset);
-            let push_between_0_and_1: Vec2 = UNorm.any_value(point.rough_id ^ VORONOI_RNG_DIFF);
+            let push_between_0_and_1: Vec2 = rng.rand_unorm_vec2(point.rough_id ^ VORONOI_RNG_DIFF);
             point.offset -= push_between_0_and_1 * self.randomness;
             point
         })
     }
 }
 
-impl<W: WrappingAmount<IVec3>> DomainCell for VoronoiCell<false, SquareCell<Vec3, IVec3, W>> {
+impl<W: WrappingAmount<IVec3>, const RADIUS: u32> DomainCell
+    for VoronoiCell<false, SquareCell<Vec3, IVec3, W>, RADIUS>
+{
     type Full = Vec3;
 
     #[inline]
@@ -1595,46 +1731,18 @@ impl<W: WrappingAmount<IVec3>> DomainCell for VoronoiCell<false, SquareCell<Vec3
 
     #[inline]
     fn iter_points(&self, rng: NoiseRng) -> impl Iterator<Item = CellPoint<Self::Full>> {
-        [
-            IVec3::new(-1, -1, -1),
-            IVec3::new(0, -1, -1),
-            IVec3::new(1, -1, -1),
-            IVec3::new(-1, 0, -1),
-            IVec3::new(0, 0, -1),
-            IVec3::new(1, 0, -1),
-            IVec3::new(-1, 1, -1),
-            IVec3::new(0, 1, -1),
-            IVec3::new(1, 1, -1),
-            IVec3::new(-1, -1, 0),
-            IVec3::new(0, -1, 0),
-            IVec3::new(1, -1, 0),
-            IVec3::new(-1, 0, 0),
-            IVec3::new(0, 0, 0),
-            IVec3::new(1, 0, 0),
-            IVec3::new(-1, 1, 0),
-            IVec3::new(0, 1, 0),
-            IVec3::new(1, 1, 0),
-            IVec3::new(-1, -1, 1),
-            IVec3::new(0, -1, 1),
-            IVec3::new(1, -1, 1),
-            IVec3::new(-1, 0, 1),
-            IVec3::new(0, 0, 1),
-            IVec3::new(1, 0, 1),
-            IVec3::new(-1, 1, 1),
-            IVec3::new(0, 1, 1),
-            IVec3::new(1, 1, 1),
-        ]
-        .into_iter()
-        .map(move |offset| {
+        voronoi_neighborhood_3d(RADIUS).map(move |offset| {
             let mut point = self.cell.point_at_offset(rng, offset);
-            let push_between_0_and_1: Vec3 = UNorm.any_value(point.rough_id ^ VORONOI_RNG_DIFF);
+            let push_between_0_and_1: Vec3 = rng.rand_unorm_vec3(point.rough_id ^ VORONOI_RNG_DIFF);
             point.offset -= push_between_0_and_1 * self.randomness;
             point
         })
     }
 }
 
-impl<W: WrappingAmount<IVec3>> DomainCell for VoronoiCell<false, SquareCell<Vec3A, IVec3, W>> {
+impl<W: WrappingAmount<IVec3>, const RADIUS: u32> DomainCell
+    for VoronoiCell<false, SquareCell<Vec3A, IVec3, W>, RADIUS>
+{
     type Full = Vec3A;
 
     #[inline]
@@ -1644,46 +1752,20 @@ impl<W: WrappingAmount<IVec3>> DomainCell for VoronoiCell<false, SquareCell<Vec3
 
     #[inline]
     fn iter_points(&self, rng: NoiseRng) -> impl Iterator<Item = CellPoint<Self::Full>> {
-        [
-            IVec3::new(-1, -1, -1),
-            IVec3::new(0, -1, -1),
-            IVec3::new(1, -1, -1),
-            IVec3::new(-1, 0, -1),
-            IVec3::new(0, 0, -1),
-            IVec3::new(1, 0, -1),
-            IVec3::new(-1, 1, -1),
-            IVec3::new(0, 1, -1),
-            IVec3::new(1, 1, -1),
-            IVec3::new(-1, -1, 0),
-            IVec3::new(0, -1, 0),
-            IVec3::new(1, -1, 0),
-            IVec3::new(-1, 0, 0),
-            IVec3::new(0, 0, 0),
-            IVec3::new(1, 0, 0),
-            IVec3::new(-1, 1, 0),
-            IVec3::new(0, 1, 0),
-            IVec3::new(1, 1, 0),
-            IVec3::new(-1, -1, 1),
-            IVec3::new(0, -1, 1),
-            IVec3::new(1, -1, 1),
-            IVec3::new(-1, 0, 1),
-            IVec3::new(0, 0, 1),
-            IVec3::new(1, 0, 1),
-            IVec3::new(-1, 1, 1),
-            IVec3::new(0, 1, 1),
-            IVec3::new(1, 1, 1),
-        ]
-        .into_iter()
-        .map(move |offset| {
+        voronoi_neighborhood_3d(RADIUS).map(move |offset| {
             let mut point = self.cell.point_at_offset(rng, offset);
-            let push_between_0_and_1: Vec3A = UNorm.any_value(point.rough_id ^ VORONOI_RNG_DIFF);
+            let push_between_0_and_1: Vec3A = rng
+                .rand_unorm_vec3(point.rough_id ^ VORONOI_RNG_DIFF)
+                .into();
             point.offset -= push_between_0_and_1 * self.randomness;
             point
         })
     }
 }
 
-impl<W: WrappingAmount<IVec4>> DomainCell for VoronoiCell<false, SquareCell<Vec4, IVec4, W>> {
+impl<W: WrappingAmount<IVec4>, const RADIUS: u32> DomainCell
+    for VoronoiCell<false, SquareCell<Vec4, IVec4, W>, RADIUS>
+{
     type Full = Vec4;
 
     #[inline]
@@ -1693,93 +1775,9 @@ impl<W: WrappingAmount<IVec4>> DomainCell for VoronoiCell<false, SquareCell<Vec4
 
     #[inline]
     fn iter_points(&self, rng: NoiseRng) -> impl Iterator<Item = CellPoint<Self::Full>> {
-        [
-            IVec4::new(-1, -1, -1, -1),
-            IVec4::new(0, -1, -1, -1),
-            IVec4::new(1, -1, -1, -1),
-            IVec4::new(-1, 0, -1, -1),
-            IVec4::new(0, 0, -1, -1),
-            IVec4::new(1, 0, -1, -1),
-            IVec4::new(-1, 1, -1, -1),
-            IVec4::new(0, 1, -1, -1),
-            IVec4::new(1, 1, -1, -1),
-            IVec4::new(-1, -1, 0, -1),
-            IVec4::new(0, -1, 0, -1),
-            IVec4::new(1, -1, 0, -1),
-            IVec4::new(-1, 0, 0, -1),
-            IVec4::new(0, 0, 0, -1),
-            IVec4::new(1, 0, 0, -1),
-            IVec4::new(-1, 1, 0, -1),
-            IVec4::new(0, 1, 0, -1),
-            IVec4::new(1, 1, 0, -1),
-            IVec4::new(-1, -1, 1, -1),
-            IVec4::new(0, -1, 1, -1),
-            IVec4::new(1, -1, 1, -1),
-            IVec4::new(-1, 0, 1, -1),
-            IVec4::new(0, 0, 1, -1),
-            IVec4::new(1, 0, 1, -1),
-            IVec4::new(-1, 1, 1, -1),
-            IVec4::new(0, 1, 1, -1),
-            IVec4::new(1, 1, 1, -1),
-            IVec4::new(-1, -1, -1, 0),
-            IVec4::new(0, -1, -1, 0),
-            IVec4::new(1, -1, -1, 0),
-            IVec4::new(-1, 0, -1, 0),
-            IVec4::new(0, 0, -1, 0),
-            IVec4::new(1, 0, -1, 0),
-            IVec4::new(-1, 1, -1, 0),
-            IVec4::new(0, 1, -1, 0),
-            IVec4::new(1, 1, -1, 0),
-            IVec4::new(-1, -1, 0, 0),
-            IVec4::new(0, -1, 0, 0),
-            IVec4::new(1, -1, 0, 0),
-            IVec4::new(-1, 0, 0, 0),
-            IVec4::new(0, 0, 0, 0),
-            IVec4::new(1, 0, 0, 0),
-            IVec4::new(-1, 1, 0, 0),
-            IVec4::new(0, 1, 0, 0),
-            IVec4::new(1, 1, 0, 0),
-            IVec4::new(-1, -1, 1, 0),
-            IVec4::new(0, -1, 1, 0),
-            IVec4::new(1, -1, 1, 0),
-            IVec4::new(-1, 0, 1, 0),
-            IVec4::new(0, 0, 1, 0),
-            IVec4::new(1, 0, 1, 0),
-            IVec4::new(-1, 1, 1, 0),
-            IVec4::new(0, 1, 1, 0),
-            IVec4::new(1, 1, 1, 0),
-            IVec4::new(-1, -1, -1, 1),
-            IVec4::new(0, -1, -1, 1),
-            IVec4::new(1, -1, -1, 1),
-            IVec4::new(-1, 0, -1, 1),
-            IVec4::new(0, 0, -1, 1),
-            IVec4::new(1, 0, -1, 1),
-            IVec4::new(-1, 1, -1, 1),
-            IVec4::new(0, 1, -1, 1),
-            IVec4::new(1, 1, -1, 1),
-            IVec4::new(-1, -1, 0, 1),
-            IVec4::new(0, -1, 0, 1),
-            IVec4::new(1, -1, 0, 1),
-            IVec4::new(-1, 0, 0, 1),
-            IVec4::new(0, 0, 0, 1),
-            IVec4::new(1, 0, 0, 1),
-            IVec4::new(-1, 1, 0, 1),
-            IVec4::new(0, 1, 0, 1),
-            IVec4::new(1, 1, 0, 1),
-            IVec4::new(-1, -1, 1, 1),
-            IVec4::new(0, -1, 1, 1),
-            IVec4::new(1, -1, 1, 1),
-            IVec4::new(-1, 0, 1, 1),
-            IVec4::new(0, 0, 1, 1),
-            IVec4::new(1, 0, 1, 1),
-            IVec4::new(-1, 1, 1, 1),
-            IVec4::new(0, 1, 1, 1),
-            IVec4::new(1, 1, 1, 1),
-        ]
-        .into_iter()
-        .map(move |offset| {
+        voronoi_neighborhood_4d(RADIUS).map(move |offset| {
             let mut point = self.cell.point_at_offset(rng, offset);
-            let push_between_0_and_1: Vec4 = UNorm.any_value(point.rough_id ^ VORONOI_RNG_DIFF);
+            let push_between_0_and_1: Vec4 = rng.rand_unorm_vec4(point.rough_id ^ VORONOI_RNG_DIFF);
             point.offset -= push_between_0_and_1 * self.randomness;
             point
         })
@@ -1,8 +1,13 @@
 //! Contains a variety of curves built to work well with noise.
 
+use core::f32::consts::PI;
+
 use bevy_math::{
-    Curve, VectorSpace, WithDerivative,
-    curve::{Interval, derivatives::SampleDerivative},
+    Curve, VectorSpace, WithDerivative, WithTwoDerivatives,
+    curve::{
+        Interval,
+        derivatives::{SampleDerivative, SampleTwoDerivatives},
+    },
 };
 
 /// Linear interpolation.
@@ -34,6 +39,17 @@ impl SampleDerivative<f32> for Linear {
     }
 }
 
+impl SampleTwoDerivatives<f32> for Linear {
+    #[inline]
+    fn sample_with_two_derivatives_unchecked(&self, t: f32) -> WithTwoDerivatives<f32> {
+        WithTwoDerivatives {
+            value: self.sample_unchecked(t),
+            derivative: 1.0,
+            second_derivative: 0.0,
+        }
+    }
+}
+
 /// Smoothstep interpolation. This has a smooth derivative.
 #[derive(Default, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
@@ -80,6 +96,17 @@ impl SampleDerivative<f32> for Smoothstep {
     }
 }
 
+impl SampleTwoDerivatives<f32> for Smoothstep {
+    #[inline]
+    fn sample_with_two_derivatives_unchecked(&self, t: f32) -> WithTwoDerivatives<f32> {
+        WithTwoDerivatives {
+            value: self.sample_unchecked(t),
+            derivative: 6.0 * t - 6.0 * t * t,
+            second_derivative: 6.0 - 12.0 * t,
+        }
+    }
+}
+
 /// Smoothstep interpolation composed on itself. This has a smooth second derivative.
 #[derive(Default, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
@@ -113,6 +140,20 @@ impl SampleDerivative<f32> for DoubleSmoothstep {
     }
 }
 
+impl SampleTwoDerivatives<f32> for DoubleSmoothstep {
+    #[inline]
+    fn sample_with_two_derivatives_unchecked(&self, t: f32) -> WithTwoDerivatives<f32> {
+        let inner = Smoothstep.sample_with_two_derivatives_unchecked(t);
+        let outer = Smoothstep.sample_with_two_derivatives_unchecked(inner.value);
+        WithTwoDerivatives {
+            value: outer.value,
+            derivative: outer.derivative * inner.derivative,
+            second_derivative: outer.second_derivative * inner.derivative * inner.derivative
+                + outer.derivative * inner.second_derivative,
+        }
+    }
+}
+
 /// Smoothstep interpolation composed on itself twice. This has a smooth third derivative.
 #[derive(Default, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
@@ -146,6 +187,47 @@ impl SampleDerivative<f32> for TripleSmoothstep {
     }
 }
 
+/// Cosine interpolation, `(1 - cos(πt)) / 2`. This has a smooth derivative and produces a soft, retro look.
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Cosine;
+
+impl Curve<f32> for Cosine {
+    #[inline]
+    fn domain(&self) -> Interval {
+        Interval::UNIT
+    }
+
+    #[inline]
+    fn sample_unchecked(&self, t: f32) -> f32 {
+        (1.0 - bevy_math::ops::cos(t * PI)) * 0.5
+    }
+}
+
+impl SampleDerivative<f32> for Cosine {
+    #[inline]
+    fn sample_with_derivative_unchecked(&self, t: f32) -> WithDerivative<f32> {
+        WithDerivative {
+            value: self.sample_unchecked(t),
+            derivative: bevy_math::ops::sin(t * PI) * (PI * 0.5),
+        }
+    }
+}
+
+impl SampleTwoDerivatives<f32> for Cosine {
+    #[inline]
+    fn sample_with_two_derivatives_unchecked(&self, t: f32) -> WithTwoDerivatives<f32> {
+        let (sin, cos) = bevy_math::ops::sin_cos(t * PI);
+        WithTwoDerivatives {
+            value: (1.0 - cos) * 0.5,
+            derivative: sin * (PI * 0.5),
+            second_derivative: cos * (PI * PI * 0.5),
+        }
+    }
+}
+
 /// Represents a way to smoothly take the minimum between two numbers.
 /// This is useful for a variety of math, but is intended for use with [`WorleySmoothMin`](crate::cell_noise::WorleySmoothMin).
 pub trait SmoothMin {
@@ -7,12 +7,15 @@ use core::{
 };
 
 use crate::{
-    NoiseFunction,
+    NoiseFunction, NoiseRange, OutputRange,
     cells::WithGradient,
     lengths::{DifferentiableLengthFunction, LengthFunction},
     rng::NoiseRng,
 };
-use bevy_math::{Curve, VectorSpace, WithDerivative, curve::derivatives::SampleDerivative};
+use bevy_math::{
+    Curve, Mat2, Quat, Vec2, Vec3, Vec3A, VectorSpace, WithDerivative,
+    curve::derivatives::SampleDerivative,
+};
 
 /// This represents the context of some [`LayerResult`].
 /// This may store metadata collected in [`LayerOperation::prepare`].
@@ -22,6 +25,13 @@ pub trait LayerResultContext {
     fn expect_weight(&mut self, weight: f32);
 }
 
+/// A [`LayerResultContext`] that can forget the weights it was told to expect, so it can be prepared again.
+/// This lets [`LayeredNoise`] implement [`SetOctaves`].
+pub trait ResetLayerResultContext: LayerResultContext {
+    /// Forgets all weights from [`expect_weight`](LayerResultContext::expect_weight), as if it was never prepared.
+    fn reset(&mut self);
+}
+
 /// A [`LayerResultContext`] that works for inputs of type `I`.
 pub trait LayerResultContextFor<I>: LayerResultContext {
     /// The result the context makes.
@@ -57,6 +67,11 @@ pub trait LayerWeightsSettings {
 
     /// Prepares a new [`LayerWeights`] for a sample.
     fn start_weights(&self) -> Self::Weights;
+
+    /// Informs these settings of how many layers they weight, as counted by [`LayerOperation::layer_count`].
+    /// This is called before each preparation.
+    #[inline]
+    fn learn_layers(&mut self, _layers: u32) {}
 }
 
 /// Specifies that this generates configurable weights for different layers of noise.
@@ -72,6 +87,14 @@ pub trait LayerWeights {
 pub trait LayerOperation<R: LayerResultContext, W: LayerWeights> {
     /// Prepares the result context `R` for this noise. This is like a dry run of the noise to try to precompute anything it needs.
     fn prepare(&self, result_context: &mut R, weights: &mut W);
+
+    /// Counts how many layers this operation weights, which is how many times [`prepare`](LayerOperation::prepare) calls [`LayerWeights::next_weight`].
+    /// This has no side effects, so it can be called before preparing.
+    /// Defaults to 0, which is correct for operations that do not contribute to the result.
+    #[inline]
+    fn layer_count(&self) -> u32 {
+        0
+    }
 }
 
 /// Specifies that this [`LayerOperation`] can be done on type `I`.
@@ -97,6 +120,11 @@ macro_rules! impl_all_operation_tuples {
                 self.$f.prepare(result_context, weights);
                 $(self.$nf.prepare(result_context, weights);)*
             }
+
+            #[inline]
+            fn layer_count(&self) -> u32 {
+                self.$f.layer_count() $(+ self.$nf.layer_count())*
+            }
         }
 
         impl<I: VectorSpace, R: LayerResult, W: LayerWeights, $i: LayerOperationFor<I, R, W>, $($ni: LayerOperationFor<I, R, W>),* > LayerOperationFor<I, R, W> for ($i, $($ni),*) {
@@ -212,11 +240,9 @@ impl<R: LayerResultContext, W: LayerWeightsSettings, N: LayerOperation<R, W::Wei
 {
     /// Constructs a [`LayeredNoise`] from this [`LayerResultContext`], [`LayerWeightsSettings`], and [`LayerOperation`].
     /// These values can not be directly accessed once set to preserve internal invariants crated in [`LayerOperation::prepare`]/
-    pub fn new(result_settings: R, weight_settings: W, noise: N) -> Self {
-        // prepare
+    pub fn new(result_settings: R, mut weight_settings: W, noise: N) -> Self {
         let mut result_context = result_settings;
-        let mut weights = weight_settings.start_weights();
-        noise.prepare(&mut result_context, &mut weights);
+        prepare_layers(&mut result_context, &mut weight_settings, &noise);
 
         // construct
         Self {
@@ -227,6 +253,21 @@ impl<R: LayerResultContext, W: LayerWeightsSettings, N: LayerOperation<R, W::Wei
     }
 }
 
+/// Prepares `result_context` for `noise`, letting `weight_settings` learn how many layers it weights first.
+fn prepare_layers<
+    R: LayerResultContext,
+    W: LayerWeightsSettings,
+    N: LayerOperation<R, W::Weights>,
+>(
+    result_context: &mut R,
+    weight_settings: &mut W,
+    noise: &N,
+) {
+    weight_settings.learn_layers(noise.layer_count());
+    let mut weights = weight_settings.start_weights();
+    noise.prepare(result_context, &mut weights);
+}
+
 impl<
     I: VectorSpace,
     R: LayerResultContextFor<I>,
@@ -265,6 +306,68 @@ impl<
     }
 }
 
+impl<W, N: NoiseRange> NoiseRange for LayeredNoise<Normed<f32>, W, FractalLayers<Octave<N>>> {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        // A weighted average never leaves the range of the values being averaged.
+        self.noise.layer.0.output_range(input)
+    }
+}
+
+impl<W, N: NoiseRange, Rot> NoiseRange
+    for LayeredNoise<Normed<f32>, W, RotatedLayers<Octave<N>, Rot>>
+{
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        // A weighted average never leaves the range of the values being averaged.
+        self.noise.layers.layer.0.output_range(input)
+    }
+}
+
+impl<T: Default> LayeredNoise<Normed<f32>, Persistence, RotatedLayers<Octave<T>, Mat2>> {
+    /// Constructs fractal brownian motion of 2d noise `T` with this many `octaves`, rotating each layer by the [`GOLDEN_ANGLE`].
+    /// This uses a lacunarity of 2 and a [`Persistence`] of 0.5, which is the fbm most users want, but without axis aligned artifacts.
+    ///
+    /// ```
+    /// # use noiz::prelude::*;
+    /// let noise = Noise::from(common_noise::RotatedFbm::<common_noise::Perlin>::rotated_fbm(8));
+    /// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+    /// ```
+    pub fn rotated_fbm(octaves: u32) -> Self {
+        Self::new(
+            Normed::default(),
+            Persistence::default(),
+            RotatedLayers {
+                layers: FractalLayers {
+                    layer: Octave::default(),
+                    lacunarity: 2.0,
+                    amount: octaves,
+                },
+                rotation: Mat2::from_angle(GOLDEN_ANGLE),
+            },
+        )
+    }
+}
+
+impl<
+    R: ResetLayerResultContext,
+    W: LayerWeightsSettings,
+    N: LayerOperation<R, W::Weights> + SetOctaves,
+    const DONT_FINISH: bool,
+> SetOctaves for LayeredNoise<R, W, N, DONT_FINISH>
+{
+    fn set_octaves(&mut self, octaves: u32) {
+        self.noise.set_octaves(octaves);
+        // prepare again from the stored weight settings
+        self.result_context.reset();
+        prepare_layers(
+            &mut self.result_context,
+            &mut self.weight_settings,
+            &self.noise,
+        );
+    }
+}
+
 /// Represents a [`LayerOperationFor`] that contributes to the result via a [`NoiseFunction`] `T`.
 /// This is the most common kind of [`LayerOperation`]. Without at least one octave layer, a [`LayeredNoise`] will not produce a meaningful result.
 #[derive(Default, Clone, Copy, PartialEq, Eq)]
@@ -278,6 +381,11 @@ impl<T, R: LayerResultContext, W: LayerWeights> LayerOperation<R, W> for Octave<
     fn prepare(&self, result_context: &mut R, weights: &mut W) {
         result_context.expect_weight(weights.next_weight());
     }
+
+    #[inline]
+    fn layer_count(&self) -> u32 {
+        1
+    }
 }
 
 impl<T: NoiseFunction<I>, I: VectorSpace, R: LayerResultFor<T::Output>, W: LayerWeights>
@@ -424,6 +532,11 @@ impl<T: LayerOperation<R, PersistenceWeights>, R: LayerResultContext>
         weights.persistence.0 /= self.config;
         weights.next /= self.config;
     }
+
+    #[inline]
+    fn layer_count(&self) -> u32 {
+        self.configured.layer_count()
+    }
 }
 
 impl<T: LayerOperationFor<I, R, PersistenceWeights>, I: VectorSpace, R: LayerResult>
@@ -463,6 +576,7 @@ impl<T: LayerOperationFor<I, R, PersistenceWeights>, I: VectorSpace, R: LayerRes
 /// # let val = fbm_perlin_noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
 /// ```
 ///
+/// To rotate the sample location between layers, see [`RotatedLayers`].
 #[derive(Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
@@ -491,6 +605,28 @@ impl<T: Default> Default for FractalLayers<T> {
     }
 }
 
+/// Represents something with a number of octaves that can be changed at runtime, like [`FractalLayers`].
+/// This is implemented for [`LayeredNoise`] too, which resets and prepares its [`ResetLayerResultContext`] again so that normalization stays correct.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// use noiz::layering::SetOctaves;
+/// let mut noise = Noise::<common_noise::Fbm<common_noise::Perlin>>::default();
+/// noise.noise.set_octaves(4);
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+pub trait SetOctaves {
+    /// Changes the number of octaves to `octaves`.
+    fn set_octaves(&mut self, octaves: u32);
+}
+
+impl<T> SetOctaves for FractalLayers<T> {
+    #[inline]
+    fn set_octaves(&mut self, octaves: u32) {
+        self.amount = octaves;
+    }
+}
+
 impl<T: LayerOperation<R, W>, R: LayerResultContext, W: LayerWeights> LayerOperation<R, W>
     for FractalLayers<T>
 {
@@ -500,6 +636,11 @@ impl<T: LayerOperation<R, W>, R: LayerResultContext, W: LayerWeights> LayerOpera
             self.layer.prepare(result_context, weights);
         }
     }
+
+    #[inline]
+    fn layer_count(&self) -> u32 {
+        self.layer.layer_count() * self.amount
+    }
 }
 
 impl<
@@ -568,6 +709,166 @@ impl<'a, T, R: FractalLayerResultCompatible<T>> LayerResultFor<T> for FractalLay
     }
 }
 
+/// Represents a [`LayerOperation`] that works just like [`FractalLayers`], but rotates the sample location by a [`LayerRotation`] `R` between layers.
+/// Grid based noise repeated at integer lacunarities lines up its grids between layers, which can make axis aligned artifacts more visible.
+/// Rotating between layers breaks that up.
+///
+/// ```
+/// # use bevy_math::prelude::*;
+/// # use noiz::prelude::*;
+/// let rotated_fbm = Noise::from(LayeredNoise::new(
+///     Normed::<f32>::default(),
+///     Persistence(0.5),
+///     RotatedLayers {
+///         layers: FractalLayers {
+///             layer: Octave::<common_noise::Perlin>::default(),
+///             lacunarity: 2.0,
+///             amount: 8,
+///         },
+///         rotation: Quat::from_axis_angle(Vec3::ONE.normalize(), 0.7),
+///     },
+/// ));
+/// # let val = rotated_fbm.sample_for::<f32>(bevy_math::Vec3::ZERO);
+/// ```
+///
+/// Gradients are not rotated back, so this only supports layers that produce [`VectorSpace`] values.
+/// Using it with differentiated noise, like [`WithGradient`] values, will not compile.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct RotatedLayers<T, R> {
+    /// The [`FractalLayers`] to rotate between.
+    pub layers: FractalLayers<T>,
+    /// The [`LayerRotation`] to apply to the sample location between each layer.
+    pub rotation: R,
+}
+
+impl<T: Default, R: Default> Default for RotatedLayers<T, R> {
+    fn default() -> Self {
+        Self {
+            layers: FractalLayers::default(),
+            rotation: R::default(),
+        }
+    }
+}
+
+impl<T, R> SetOctaves for RotatedLayers<T, R> {
+    #[inline]
+    fn set_octaves(&mut self, octaves: u32) {
+        self.layers.set_octaves(octaves);
+    }
+}
+
+impl<T: LayerOperation<R, W>, R: LayerResultContext, W: LayerWeights, Rot> LayerOperation<R, W>
+    for RotatedLayers<T, Rot>
+{
+    #[inline]
+    fn prepare(&self, result_context: &mut R, weights: &mut W) {
+        self.layers.prepare(result_context, weights);
+    }
+
+    #[inline]
+    fn layer_count(&self) -> u32 {
+        self.layers.layer_count()
+    }
+}
+
+impl<
+    I: VectorSpace,
+    T: for<'a> LayerOperationFor<I, RotatedLayeredResult<'a, R>, W>,
+    R: LayerResult,
+    W: LayerWeights,
+    Rot: LayerRotation<I>,
+> LayerOperationFor<I, R, W> for RotatedLayers<T, Rot>
+{
+    #[inline]
+    fn do_noise_op(
+        &self,
+        seeds: &mut NoiseRng,
+        working_loc: &mut I,
+        result: &mut R,
+        weights: &mut W,
+    ) {
+        let mut result = RotatedLayeredResult {
+            result,
+            artificial_frequency: 1.0,
+        };
+        self.layers
+            .layer
+            .do_noise_op(seeds, working_loc, &mut result, weights);
+        for _ in 1..self.layers.amount {
+            *working_loc = self.rotation.rotate(*working_loc * self.layers.lacunarity);
+            result.artificial_frequency *= self.layers.lacunarity;
+            self.layers
+                .layer
+                .do_noise_op(seeds, working_loc, &mut result, weights);
+        }
+    }
+}
+
+/// Represents a rotation of a sample location `I` that [`RotatedLayers`] applies between layers.
+pub trait LayerRotation<I> {
+    /// Rotates `loc`.
+    fn rotate(&self, loc: I) -> I;
+}
+
+/// The golden angle in radians, about 137.5 degrees.
+/// Rotating by this between layers means no two layers are ever rotated to nearly the same angle, which hides axis aligned artifacts well.
+pub const GOLDEN_ANGLE: f32 = 2.399_963;
+
+impl LayerRotation<Vec2> for Mat2 {
+    #[inline]
+    fn rotate(&self, loc: Vec2) -> Vec2 {
+        *self * loc
+    }
+}
+
+impl LayerRotation<Vec3> for Quat {
+    #[inline]
+    fn rotate(&self, loc: Vec3) -> Vec3 {
+        *self * loc
+    }
+}
+
+impl LayerRotation<Vec3A> for Quat {
+    #[inline]
+    fn rotate(&self, loc: Vec3A) -> Vec3A {
+        *self * loc
+    }
+}
+
+/// A result used in [`RotatedLayers`] to wrap an inner result type.
+/// Since gradients would need to be rotated back, this only accepts [`VectorSpace`] values, which have no gradients.
+pub struct RotatedLayeredResult<'a, R> {
+    result: &'a mut R,
+    artificial_frequency: f32,
+}
+
+impl<'a, R: LayerResult> LayerResult for RotatedLayeredResult<'a, R> {
+    type Output = &'a mut R;
+
+    #[inline]
+    fn add_unexpected_weight_to_total(&mut self, weight: f32) {
+        self.result.add_unexpected_weight_to_total(weight);
+    }
+
+    #[inline]
+    fn finish(self, _rng: &mut NoiseRng) -> Self::Output {
+        self.result
+    }
+}
+
+impl<'a, T: VectorSpace, R: FractalLayerResultCompatible<T>> LayerResultFor<T>
+    for RotatedLayeredResult<'a, R>
+{
+    #[inline]
+    fn include_value(&mut self, value: T, weight: f32) {
+        self.result
+            .include_fractal_value(value, weight, self.artificial_frequency);
+    }
+}
+
 /// A [`LayerWeightsSettings`] for [`PersistenceWeights`].
 /// This is a very common weight system, as it can produce fractal noise easily.
 /// If you're not sure which one to use, use this one.
@@ -575,6 +876,10 @@ impl<'a, T, R: FractalLayerResultCompatible<T>> LayerResultFor<T> for FractalLay
 ///
 /// Values greater than 1 make later octaves weigh more, while values less than 1 make earlier octaves weigh more.
 /// A value of 1 makes all octaves equally weighted. Values of 0 or nan have no defined meaning.
+/// Negative values alternate the sign of each octave, letting octaves subtract from each other.
+/// Since [`Normed`] divides by the sum of the weights, pair negative values with [`AbsNormed`] to keep the result bounded.
+/// Use [`Persistence::new`] to validate this in debug builds.
+/// In other libraries, this is commonly called "gain".
 #[derive(Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
@@ -589,6 +894,26 @@ impl Default for Persistence {
 impl Persistence {
     /// Makes every octave get the same weight.
     pub const CONSTANT: Self = Self(1.0);
+
+    /// Constructs a new [`Persistence`] from its `gain`, the weight multiplier between each octave.
+    /// Other libraries, like FastNoiseLite, commonly call this "gain".
+    ///
+    /// The `gain` must be positive and finite.
+    /// To alternate the sign of each octave with a negative gain, construct a [`Persistence`] directly and see [`AbsNormed`].
+    #[inline]
+    pub fn new(gain: f32) -> Self {
+        debug_assert!(
+            gain > 0.0 && gain.is_finite(),
+            "Persistence must be positive and finite, but was {gain}."
+        );
+        Self(gain)
+    }
+
+    /// Gets the weight multiplier between each octave. See also [`new`](Self::new).
+    #[inline]
+    pub fn gain(&self) -> f32 {
+        self.0
+    }
 }
 
 /// The [`LayerWeights`] for [`Persistence`].
@@ -619,6 +944,144 @@ impl LayerWeightsSettings for Persistence {
     }
 }
 
+/// A [`LayerWeightsSettings`] that weights each layer by the Hurst exponent [`h`](FbmH::h).
+/// The weight of layer `i` is `lacunarity^(-i * h)`.
+/// This is the same as a [`Persistence`] of `lacunarity^(-h)`,
+/// but it keeps the spectral shape consistent when the lacunarity changes.
+///
+/// [`lacunarity`](FbmH::lacunarity) should match the lacunarity of the layers being weighted, ex: [`FractalLayers::lacunarity`].
+/// Larger values of `h` produce smoother results, and smaller values produce rougher results.
+///
+/// ```
+/// # use bevy_math::prelude::*;
+/// # use noiz::{prelude::*, layering::FbmH};
+/// let noise = Noise::<LayeredNoise<
+///     Normed<f32>,
+///     FbmH,
+///     FractalLayers<Octave<common_noise::Perlin>>,
+/// >>::default();
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct FbmH {
+    /// The Hurst exponent.
+    pub h: f32,
+    /// The frequency multiplier between each layer.
+    pub lacunarity: f32,
+}
+
+impl Default for FbmH {
+    fn default() -> Self {
+        Self {
+            h: 1.0,
+            lacunarity: 2.0,
+        }
+    }
+}
+
+impl FbmH {
+    /// Gets the [`Persistence`] that produces the same weights.
+    #[inline]
+    pub fn persistence(&self) -> Persistence {
+        Persistence(bevy_math::ops::powf(self.lacunarity, -self.h))
+    }
+}
+
+impl LayerWeightsSettings for FbmH {
+    type Weights = PersistenceWeights;
+
+    #[inline]
+    fn start_weights(&self) -> Self::Weights {
+        self.persistence().start_weights()
+    }
+}
+
+/// A [`LayerWeightsSettings`] for [`CurveLayerWeights`], where the weights are sampled from a [`Curve`] `C`.
+/// This gives full control over the spectral shape of fractal noise, for example, emphasizing middle frequencies.
+///
+/// With `n` layers, the weight of layer `i` is `curve.sample_clamped(i / (n - 1))`, so the curve should generally be defined over `[0, 1]`.
+/// The first layer samples the start of the curve, and the last layer samples the end.
+/// If there is only one layer, it samples the end of the curve.
+/// `n` is counted when the [`LayeredNoise`] is prepared, so it always matches the layers being weighted, ex: [`FractalLayers::amount`].
+///
+/// ```
+/// # use bevy_math::prelude::*;
+/// # use noiz::{prelude::*, layering::CurveWeights};
+/// // Later octaves are weighted more than earlier ones.
+/// let noise = Noise::<LayeredNoise<
+///     Normed<f32>,
+///     CurveWeights<Linear>,
+///     FractalLayers<Octave<common_noise::Perlin>>,
+/// >>::default();
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct CurveWeights<C> {
+    /// The [`Curve`] that produces the weight of each layer.
+    pub curve: C,
+    /// The total number of layers that are weighted, learned in [`LayerWeightsSettings::learn_layers`].
+    layers: u32,
+}
+
+impl<C: Default> Default for CurveWeights<C> {
+    fn default() -> Self {
+        Self::new(C::default())
+    }
+}
+
+impl<C> CurveWeights<C> {
+    /// Constructs a new [`CurveWeights`] that samples the weight of each layer from `curve`.
+    pub fn new(curve: C) -> Self {
+        Self { curve, layers: 0 }
+    }
+}
+
+/// The [`LayerWeights`] for [`CurveWeights`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct CurveLayerWeights<C> {
+    curve: C,
+    t: f32,
+    step: f32,
+}
+
+impl<C: Curve<f32>> LayerWeights for CurveLayerWeights<C> {
+    #[inline]
+    fn next_weight(&mut self) -> f32 {
+        let result = self.curve.sample_clamped(self.t);
+        self.t += self.step;
+        result
+    }
+}
+
+impl<C: Curve<f32> + Clone> LayerWeightsSettings for CurveWeights<C> {
+    type Weights = CurveLayerWeights<C>;
+
+    #[inline]
+    fn start_weights(&self) -> Self::Weights {
+        let (t, step) = if self.layers > 1 {
+            (0.0, 1.0 / (self.layers - 1) as f32)
+        } else {
+            (1.0, 0.0)
+        };
+        CurveLayerWeights {
+            curve: self.curve.clone(),
+            t,
+            step,
+        }
+    }
+
+    #[inline]
+    fn learn_layers(&mut self, layers: u32) {
+        self.layers = layers;
+    }
+}
+
 /// A [`LayerResultContext`] that will normalize the results into a weighted average.
 /// This is a good default for most noise functions.
 /// This is a building block for traditional fractal brownian motion. See also [`FractalLayers`].
@@ -654,6 +1117,16 @@ where
     }
 }
 
+impl<T> ResetLayerResultContext for Normed<T>
+where
+    NormedResult<T>: LayerResult,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.total_weights = 0.0;
+    }
+}
+
 impl<T: Default, I> LayerResultContextFor<I> for Normed<T>
 where
     NormedResult<T>: LayerResult,
@@ -731,6 +1204,303 @@ where
     }
 }
 
+/// A [`LayerResultContext`] that works just like [`Normed`], but normalizes by the sum of the absolute values of the weights.
+/// This keeps results bounded when some weights are negative, for example, when using a negative [`Persistence`] to alternate the sign of each octave.
+/// When all weights are positive, this is identical to [`Normed`].
+///
+/// ```
+/// # use bevy_math::prelude::*;
+/// # use noiz::{prelude::*, layering::AbsNormed};
+/// let alternating = Noise::<LayeredNoise<
+///     AbsNormed<f32>,
+///     Persistence,
+///     FractalLayers<Octave<common_noise::Perlin>>,
+/// >>::from(LayeredNoise::new(
+///     AbsNormed::default(),
+///     Persistence(-0.5),
+///     FractalLayers::default(),
+/// ));
+/// # let val = alternating.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct AbsNormed<T>(Normed<T>);
+
+impl<T> Default for AbsNormed<T> {
+    fn default() -> Self {
+        Self(Normed::default())
+    }
+}
+
+impl<T> LayerResultContext for AbsNormed<T>
+where
+    NormedResult<T>: LayerResult,
+{
+    #[inline]
+    fn expect_weight(&mut self, weight: f32) {
+        self.0.expect_weight(weight.abs());
+    }
+}
+
+impl<T> ResetLayerResultContext for AbsNormed<T>
+where
+    NormedResult<T>: LayerResult,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+
+impl<T: Default, I> LayerResultContextFor<I> for AbsNormed<T>
+where
+    NormedResult<T>: LayerResult,
+{
+    type Result = AbsNormedResult<T>;
+
+    #[inline]
+    fn start_result(&self) -> Self::Result {
+        AbsNormedResult(LayerResultContextFor::<I>::start_result(&self.0))
+    }
+}
+
+/// The in-progress result of an [`AbsNormed`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct AbsNormedResult<T>(NormedResult<T>);
+
+impl<T> LayerResult for AbsNormedResult<T>
+where
+    NormedResult<T>: LayerResult,
+{
+    type Output = <NormedResult<T> as LayerResult>::Output;
+
+    #[inline]
+    fn add_unexpected_weight_to_total(&mut self, weight: f32) {
+        self.0.add_unexpected_weight_to_total(weight.abs());
+    }
+
+    #[inline]
+    fn finish(self, rng: &mut NoiseRng) -> Self::Output {
+        self.0.finish(rng)
+    }
+}
+
+impl<T, I> LayerResultFor<I> for AbsNormedResult<T>
+where
+    NormedResult<T>: LayerResultFor<I>,
+{
+    #[inline]
+    fn include_value(&mut self, value: I, weight: f32) {
+        self.0.include_value(value, weight);
+    }
+}
+
+impl<T, I> FractalLayerResultCompatible<I> for AbsNormedResult<T>
+where
+    NormedResult<T>: FractalLayerResultCompatible<I>,
+{
+    #[inline]
+    fn include_fractal_value(&mut self, value: I, weight: f32, artificial_frequency: f32) {
+        self.0
+            .include_fractal_value(value, weight, artificial_frequency);
+    }
+}
+
+/// A [`LayerResultContext`] that collects the raw, unweighted value of each octave into an array of `N` values, in order.
+/// This is useful for analyzing the output of each octave or for blending them in a custom way.
+/// Octaves beyond the first `N` are ignored, and if there are fewer than `N`, the rest are left as 0.
+///
+/// ```
+/// # use bevy_math::prelude::*;
+/// # use noiz::{prelude::*, layering::OctaveArray};
+/// let noise = Noise::<LayeredNoise<
+///     OctaveArray<4>,
+///     Persistence,
+///     FractalLayers<Octave<common_noise::Perlin>>,
+/// >>::default();
+/// let octaves: [f32; 4] = noise.sample(Vec2::new(1.5, -0.5));
+/// ```
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct OctaveArray<const N: usize>;
+
+impl<const N: usize> LayerResultContext for OctaveArray<N> {
+    #[inline]
+    fn expect_weight(&mut self, _weight: f32) {}
+}
+
+impl<const N: usize> ResetLayerResultContext for OctaveArray<N> {
+    #[inline]
+    fn reset(&mut self) {}
+}
+
+impl<const N: usize, I> LayerResultContextFor<I> for OctaveArray<N> {
+    type Result = OctaveArrayResult<N>;
+
+    #[inline]
+    fn start_result(&self) -> Self::Result {
+        OctaveArrayResult {
+            values: [0.0; N],
+            next: 0,
+        }
+    }
+}
+
+/// The in-progress result of a [`OctaveArray`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct OctaveArrayResult<const N: usize> {
+    values: [f32; N],
+    next: usize,
+}
+
+impl<const N: usize> LayerResult for OctaveArrayResult<N> {
+    type Output = [f32; N];
+
+    #[inline]
+    fn add_unexpected_weight_to_total(&mut self, _weight: f32) {}
+
+    #[inline]
+    fn finish(self, _rng: &mut NoiseRng) -> Self::Output {
+        self.values
+    }
+}
+
+impl<const N: usize> LayerResultFor<f32> for OctaveArrayResult<N> {
+    #[inline]
+    fn include_value(&mut self, value: f32, _weight: f32) {
+        if let Some(slot) = self.values.get_mut(self.next) {
+            *slot = value;
+        }
+        self.next += 1;
+    }
+}
+
+impl<const N: usize> FractalLayerResultCompatible<f32> for OctaveArrayResult<N> {
+    #[inline]
+    fn include_fractal_value(&mut self, value: f32, weight: f32, _artificial_frequency: f32) {
+        self.include_value(value, weight);
+    }
+}
+
+/// A [`LayerResultContext`] that multiplies octaves together instead of adding them, producing multifractal noise.
+/// Each octave contributes a factor of `value * weight + offset`, so detail is scaled by the value of the layers before it.
+/// This gives the characteristic multifractal terrain, where lowlands are smooth and highlands are rough.
+///
+/// The product is divided by its greatest possible value, `(offset + |weight|)` multiplied across every octave, assuming octaves produce SNorm values.
+/// When [`offset`](Multifractal::offset) is at least the greatest weight, each factor is positive, so the result is between 0 and 1.
+/// Smaller offsets produce more extreme results and can even flip the sign.
+///
+/// The weights of each octave are usually made by [`FbmH`], which provides the standard `H` parameter of multifractals.
+///
+/// ```
+/// # use bevy_math::prelude::*;
+/// # use noiz::{prelude::*, layering::{FbmH, Multifractal}};
+/// let noise = Noise::<LayeredNoise<
+///     Multifractal,
+///     FbmH,
+///     FractalLayers<Octave<common_noise::Perlin>>,
+/// >>::default();
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Multifractal {
+    offset: f32,
+    max_product: f32,
+}
+
+impl Default for Multifractal {
+    fn default() -> Self {
+        Self::new(1.0)
+    }
+}
+
+impl Multifractal {
+    /// Constructs a new [`Multifractal`] with this `offset`, which is added to each octave before it is multiplied in.
+    #[inline]
+    pub fn new(offset: f32) -> Self {
+        Self {
+            offset,
+            max_product: 1.0,
+        }
+    }
+
+    /// Gets the offset added to each octave before it is multiplied in.
+    #[inline]
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+}
+
+impl LayerResultContext for Multifractal {
+    #[inline]
+    fn expect_weight(&mut self, weight: f32) {
+        self.max_product *= self.offset + weight.abs();
+    }
+}
+
+impl ResetLayerResultContext for Multifractal {
+    #[inline]
+    fn reset(&mut self) {
+        self.max_product = 1.0;
+    }
+}
+
+impl<I> LayerResultContextFor<I> for Multifractal {
+    type Result = MultifractalResult;
+
+    #[inline]
+    fn start_result(&self) -> Self::Result {
+        MultifractalResult {
+            offset: self.offset,
+            max_product: self.max_product,
+            running_product: 1.0,
+        }
+    }
+}
+
+/// The in-progress result of a [`Multifractal`].
+#[derive(Clone, Copy, PartialEq)]
+pub struct MultifractalResult {
+    offset: f32,
+    max_product: f32,
+    running_product: f32,
+}
+
+impl LayerResult for MultifractalResult {
+    type Output = f32;
+
+    #[inline]
+    fn add_unexpected_weight_to_total(&mut self, weight: f32) {
+        self.max_product *= self.offset + weight.abs();
+    }
+
+    #[inline]
+    fn finish(self, _rng: &mut NoiseRng) -> Self::Output {
+        self.running_product / self.max_product
+    }
+}
+
+impl LayerResultFor<f32> for MultifractalResult {
+    #[inline]
+    fn include_value(&mut self, value: f32, weight: f32) {
+        self.running_product *= value * weight + self.offset;
+    }
+}
+
+impl FractalLayerResultCompatible<f32> for MultifractalResult {
+    #[inline]
+    fn include_fractal_value(&mut self, value: f32, weight: f32, _artificial_frequency: f32) {
+        self.include_value(value, weight);
+    }
+}
+
 /// A [`LayerResultContext`] that will normalize the results into a weighted average where the derivatives affect the weight.
 /// See also [`Normed`].
 ///
@@ -801,6 +1571,16 @@ where
     }
 }
 
+impl<T, L: Copy, C: Copy> ResetLayerResultContext for NormedByDerivative<T, L, C>
+where
+    NormedResult<T>: LayerResult,
+{
+    #[inline]
+    fn reset(&mut self) {
+        self.total_weights = 0.0;
+    }
+}
+
 impl<T: Default + Div<f32>, I: VectorSpace, L: Copy, C: Copy> LayerResultContextFor<I>
     for NormedByDerivative<T, L, C>
 where
@@ -988,3 +1768,419 @@ impl SampleDerivative<f32> for SmoothDerivativeContribution {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Noise, Sampleable, SampleableFor,
+        misc_noise::Constant,
+        prelude::common_noise::{Fbm, Perlin, RotatedFbm},
+    };
+    use bevy_math::curve::{ConstantCurve, Interval};
+
+    #[test]
+    fn test_constant_curve_weights() {
+        let persistence = Noise::<
+            LayeredNoise<Normed<f32>, Persistence, FractalLayers<Octave<Perlin>>>,
+        >::from(LayeredNoise::new(
+            Normed::default(),
+            Persistence::CONSTANT,
+            FractalLayers::default(),
+        ));
+        let curve = Noise::<
+            LayeredNoise<
+                Normed<f32>,
+                CurveWeights<ConstantCurve<f32>>,
+                FractalLayers<Octave<Perlin>>,
+            >,
+        >::from(LayeredNoise::new(
+            Normed::default(),
+            CurveWeights::new(ConstantCurve::new(Interval::UNIT, 1.0)),
+            FractalLayers::default(),
+        ));
+        for x in -10..=10 {
+            for y in -10..=10 {
+                let point = Vec2::new(x as f32, y as f32) * 0.37;
+                let expected: f32 = persistence.sample(point);
+                let actual: f32 = curve.sample(point);
+                assert!(
+                    (expected - actual).abs() < 1e-5,
+                    "Curve weights differ from constant persistence at {point:?}: {actual} vs {expected}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_curve_weights_span_curve() {
+        let mut weights = CurveWeights::new(crate::curves::Linear);
+        weights.learn_layers(4);
+        let mut weights = weights.start_weights();
+        for expected in [0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0] {
+            assert!((weights.next_weight() - expected).abs() < 1e-6);
+        }
+
+        let single = Noise::<
+            LayeredNoise<
+                Normed<f32>,
+                CurveWeights<crate::curves::Linear>,
+                FractalLayers<Octave<Perlin>>,
+            >,
+        >::from(LayeredNoise::new(
+            Normed::default(),
+            CurveWeights::default(),
+            FractalLayers {
+                amount: 1,
+                ..Default::default()
+            },
+        ));
+        for x in -10..=10 {
+            let value: f32 = single.sample(Vec2::new(x as f32 * 0.37, 0.5));
+            assert!(value.is_finite(), "{value} is not finite");
+        }
+    }
+
+    #[test]
+    fn test_persistence_new() {
+        assert_eq!(Persistence::new(0.5).gain(), 0.5);
+        let fbm = Noise::<Fbm<Perlin>>::default();
+        let from_new = Noise::<Fbm<Perlin>>::from(LayeredNoise::new(
+            Normed::default(),
+            Persistence::new(0.5),
+            FractalLayers::default(),
+        ));
+        for x in -10..=10 {
+            for y in -10..=10 {
+                let point = Vec2::new(x as f32, y as f32) * 0.37;
+                let expected: f32 = fbm.sample(point);
+                let actual: f32 = from_new.sample(point);
+                assert_eq!(expected, actual);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn test_persistence_zero() {
+        let _ = Persistence::new(0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn test_persistence_negative() {
+        let _ = Persistence::new(-0.5);
+    }
+
+    #[test]
+    fn test_abs_normed_alternating() {
+        let layers = FractalLayers {
+            amount: 4,
+            ..Default::default()
+        };
+        let abs_normed = Noise::<
+            LayeredNoise<AbsNormed<f32>, Persistence, FractalLayers<Octave<Perlin>>>,
+        >::from(LayeredNoise::new(
+            AbsNormed::default(),
+            Persistence(-0.5),
+            layers,
+        ));
+        let normed =
+            Noise::<LayeredNoise<Normed<f32>, Persistence, FractalLayers<Octave<Perlin>>>>::from(
+                LayeredNoise::new(Normed::default(), Persistence(-0.5), layers),
+            );
+        // Weights are 1, -0.5, 0.25, -0.125.
+        let abs_total = 1.875;
+        let signed_total = 0.625;
+        for x in -10..=10 {
+            for y in -10..=10 {
+                let point = Vec2::new(x as f32, y as f32) * 0.37;
+                let bounded: f32 = abs_normed.sample(point);
+                let unbounded: f32 = normed.sample(point);
+                assert!((-1.0..=1.0).contains(&bounded));
+                assert!((bounded * abs_total - unbounded * signed_total).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fbm_h() {
+        let layers = FractalLayers::<Octave<Perlin>> {
+            lacunarity: 4.0,
+            ..Default::default()
+        };
+        let settings = FbmH {
+            h: 0.5,
+            lacunarity: 4.0,
+        };
+        assert!((settings.persistence().gain() - 0.5).abs() < 1e-6);
+
+        let from_h = Noise::<LayeredNoise<Normed<f32>, FbmH, FractalLayers<Octave<Perlin>>>>::from(
+            LayeredNoise::new(Normed::default(), settings, layers),
+        );
+        let from_persistence =
+            Noise::<LayeredNoise<Normed<f32>, Persistence, FractalLayers<Octave<Perlin>>>>::from(
+                LayeredNoise::new(Normed::default(), Persistence(0.5), layers),
+            );
+        for x in -10..=10 {
+            for y in -10..=10 {
+                let point = Vec2::new(x as f32, y as f32) * 0.37;
+                let expected: f32 = from_persistence.sample(point);
+                let actual: f32 = from_h.sample(point);
+                assert!((expected - actual).abs() < 1e-5);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fractal_rotation_3d() {
+        // Without rotation, every layer's lattice lines up, so the fbm is pinned to 0 at every lattice point.
+        // Rotating between layers breaks up that axis aligned structure, so only the first layer is still pinned.
+        fn lattice_energy(noise: &impl SampleableFor<Vec3, f32>) -> (f32, f32) {
+            let mut on_lattice = 0.0;
+            let mut off_lattice = 0.0;
+            for x in -6..6 {
+                for y in -6..6 {
+                    for z in -6..6 {
+                        let point = Vec3::new(x as f32, y as f32, z as f32);
+                        on_lattice += noise.sample(point).powi(2);
+                        off_lattice += noise.sample(point + Vec3::splat(0.37)).powi(2);
+                    }
+                }
+            }
+            (on_lattice, off_lattice)
+        }
+
+        let unrotated = Noise::<
+            LayeredNoise<Normed<f32>, Persistence, FractalLayers<Octave<Perlin>>>,
+        >::default();
+        let rotated = Noise::from(LayeredNoise::new(
+            Normed::<f32>::default(),
+            Persistence(0.5),
+            RotatedLayers {
+                layers: FractalLayers {
+                    layer: Octave::<Perlin>::default(),
+                    lacunarity: 2.0,
+                    amount: 8,
+                },
+                rotation: Quat::from_axis_angle(Vec3::new(1.0, 2.0, 3.0).normalize(), 0.7),
+            },
+        ));
+
+        let (on, off) = lattice_energy(&unrotated);
+        assert!(on < 1e-6 * off, "on: {on}, off: {off}");
+        let (on, off) = lattice_energy(&rotated);
+        assert!(on > 0.1 * off, "on: {on}, off: {off}");
+    }
+
+    #[test]
+    fn test_fractal_rotation_isotropy() {
+        // Compares directional autocorrelation along the lattice axes to along the diagonals.
+        // Without rotation, every layer's lattice lines up, so each layer adds to the same directional bias.
+        // Octaves are weighted equally so the alignment of later layers matters as much as the first.
+        fn anisotropy(noise: &impl SampleableFor<Vec3, f32>) -> f32 {
+            let axes = [Vec3::X, Vec3::Y, Vec3::Z];
+            let diagonals = [
+                Vec3::new(1.0, 1.0, 1.0),
+                Vec3::new(1.0, -1.0, 1.0),
+                Vec3::new(1.0, 1.0, -1.0),
+                Vec3::new(-1.0, 1.0, 1.0),
+            ]
+            .map(Vec3::normalize);
+            let lags = [0.2, 0.3, 0.5];
+            let rng = NoiseRng(0);
+            let mut variance = 0.0;
+            let mut axis_correlation = [0.0; 3];
+            let mut diagonal_correlation = [0.0; 3];
+            for i in 0..20000u32 {
+                let point = (rng.rand_unorm_vec3(i) - 0.5) * 100.0;
+                let value = noise.sample(point);
+                variance += value * value;
+                for (lag_index, lag) in lags.into_iter().enumerate() {
+                    for axis in axes {
+                        axis_correlation[lag_index] +=
+                            value * noise.sample(point + axis * lag) / 3.0;
+                    }
+                    for diagonal in diagonals {
+                        diagonal_correlation[lag_index] +=
+                            value * noise.sample(point + diagonal * lag) / 4.0;
+                    }
+                }
+            }
+            axis_correlation
+                .into_iter()
+                .zip(diagonal_correlation)
+                .map(|(axis, diagonal)| (axis - diagonal).abs() / variance)
+                .sum()
+        }
+
+        let layers = FractalLayers {
+            layer: Octave::<Perlin>::default(),
+            lacunarity: 2.0,
+            amount: 4,
+        };
+        let unrotated = Noise::from(LayeredNoise::new(
+            Normed::<f32>::default(),
+            Persistence::CONSTANT,
+            layers,
+        ));
+        let rotated = Noise::from(LayeredNoise::new(
+            Normed::<f32>::default(),
+            Persistence::CONSTANT,
+            RotatedLayers {
+                layers,
+                rotation: Quat::from_axis_angle(Vec3::new(1.0, 2.0, 3.0).normalize(), 0.7),
+            },
+        ));
+
+        let unrotated = anisotropy(&unrotated);
+        let rotated = anisotropy(&rotated);
+        assert!(
+            rotated < 0.5 * unrotated,
+            "rotated anisotropy: {rotated}, unrotated anisotropy: {unrotated}"
+        );
+    }
+
+    #[test]
+    fn test_octave_array() {
+        let layers = FractalLayers {
+            amount: 3,
+            ..Default::default()
+        };
+        let noise = LayeredNoise::<OctaveArray<3>, Persistence, FractalLayers<Octave<Perlin>>>::new(
+            OctaveArray,
+            Persistence(0.5),
+            layers,
+        );
+        for x in -10..10 {
+            for y in -10..10 {
+                let point = Vec2::new(x as f32, y as f32) * 0.37;
+                let octaves = noise.evaluate(point, &mut NoiseRng(7));
+
+                let mut seeds = NoiseRng(7);
+                for (octave, value) in octaves.into_iter().enumerate() {
+                    let expected =
+                        Perlin::default().evaluate(point * 2f32.powi(octave as i32), &mut seeds);
+                    seeds.re_seed();
+                    assert_eq!(value, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_rotated_fbm() {
+        let rotated = Noise::from(RotatedFbm::<Perlin>::rotated_fbm(8));
+        let unrotated = Noise::<Fbm<Perlin>>::default();
+        let mut max_difference = 0.0f32;
+        for x in -10..=10 {
+            for y in -10..=10 {
+                let point = Vec2::new(x as f32, y as f32) * 0.37;
+                let value: f32 = rotated.sample(point);
+                assert!((-1.0..=1.0).contains(&value), "{value} is out of range");
+                let unrotated: f32 = unrotated.sample(point);
+                max_difference = max_difference.max((value - unrotated).abs());
+            }
+        }
+        assert!(max_difference > 0.01);
+    }
+
+    #[test]
+    fn test_multifractal() {
+        let noise =
+            Noise::<LayeredNoise<Multifractal, FbmH, FractalLayers<Octave<Perlin>>>>::default();
+        // Pair each sample with how much it differs from its neighbor, a measure of roughness.
+        let step = 0.02;
+        let mut samples = Vec::new();
+        for x in -100..100 {
+            for y in -100..100 {
+                let point = Vec2::new(x as f32, y as f32) * 0.173;
+                let value: f32 = noise.sample(point);
+                assert!((0.0..=1.0).contains(&value), "{value} is out of range");
+                let next: f32 = noise.sample(point + Vec2::new(step, 0.0));
+                samples.push((value, (next - value).abs()));
+            }
+        }
+
+        // Compare the lowest quarter of samples to the highest quarter.
+        samples.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let quarter = samples.len() / 4;
+        let roughness = |samples: &[(f32, f32)]| {
+            samples
+                .iter()
+                .map(|(_, difference)| difference)
+                .sum::<f32>()
+                / samples.len() as f32
+        };
+        let low = roughness(&samples[..quarter]);
+        let high = roughness(&samples[samples.len() - quarter..]);
+        assert!(
+            low < high,
+            "lowlands {low} should be smoother than highlands {high}"
+        );
+    }
+
+    #[test]
+    fn test_set_octaves() {
+        let mut noise = Noise::<Fbm<Perlin>>::default();
+        noise.noise.set_octaves(3);
+        let expected = Noise::<Fbm<Perlin>>::from(LayeredNoise::new(
+            Normed::default(),
+            Persistence::default(),
+            FractalLayers {
+                amount: 3,
+                ..Default::default()
+            },
+        ));
+        for x in -20..=20 {
+            for y in -20..=20 {
+                let point = Vec2::new(x as f32, y as f32) * 0.37;
+                let value: f32 = noise.sample(point);
+                assert_eq!(value, expected.sample_for::<f32>(point));
+            }
+        }
+
+        // Averaging a constant stays that constant, no matter the number of octaves.
+        let mut constant = Noise::<
+            LayeredNoise<Normed<f32>, Persistence, FractalLayers<Octave<Constant<f32>>>>,
+        >::from(LayeredNoise::new(
+            Normed::default(),
+            Persistence::default(),
+            FractalLayers {
+                layer: Octave(Constant(0.75)),
+                lacunarity: 2.0,
+                amount: 8,
+            },
+        ));
+        for octaves in [1, 2, 5, 12] {
+            constant.noise.set_octaves(octaves);
+            let value: f32 = constant.sample(Vec2::new(0.3, -1.7));
+            assert!(
+                (value - 0.75).abs() < 1e-6,
+                "{value} with {octaves} octaves"
+            );
+        }
+    }
+
+    #[test]
+    fn test_layer_count() {
+        let layers = (
+            DomainWarp::<Perlin>::default(),
+            FractalLayers {
+                layer: (Octave::<Perlin>::default(), Octave::<Perlin>::default()),
+                lacunarity: 2.0,
+                amount: 3,
+            },
+            Octave::<Perlin>::default(),
+        );
+        let count = LayerOperation::<Normed<f32>, PersistenceWeights>::layer_count(&layers);
+        assert_eq!(count, 7);
+
+        let mut prepared = Normed::<f32>::default();
+        let mut weights = Persistence::CONSTANT.start_weights();
+        layers.prepare(&mut prepared, &mut weights);
+        assert_eq!(prepared.total_weights, count as f32);
+    }
+}
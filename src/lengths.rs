@@ -36,6 +36,9 @@ pub struct EuclideanLength;
 
 /// A [`LengthFunction`] for squared [`EuclideanLength`] length.
 /// This is in some ways, a faster approximation of [`EuclideanLength`].
+/// It never takes a square root, and its [`max_for_element_max`](LengthFunction::max_for_element_max) is squared too,
+/// so worley noise that uses it stays normalized while skipping the square root entirely.
+/// This is useful for masking, where only the relative magnitude matters.
 #[derive(Default, Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
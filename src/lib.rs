@@ -5,6 +5,9 @@
 )]
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub mod cell_noise;
 pub mod cells;
 pub mod curves;
@@ -13,10 +16,18 @@ pub mod lengths;
 pub mod math_noise;
 pub mod misc_noise;
 pub mod prelude;
+#[cfg(feature = "profiling")]
+pub mod profiling;
+#[cfg(feature = "alloc")]
+pub mod registry;
 pub mod rng;
 
-use bevy_math::VectorSpace;
-use rng::NoiseRng;
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use bevy_math::{IVec2, UVec2, Vec2, Vec3, VectorSpace};
+use cell_noise::PerCell;
+use cells::{OrthoGrid, WrappingAmount};
+use rng::{NoiseRng, NoiseRngInput};
 
 /// Represents a simple noise function with an input `I` and an output.
 ///
@@ -30,6 +41,14 @@ pub trait NoiseFunction<I> {
     /// Evaluates the function at some `input` with this [`NoiseRng`].
     /// This function should be deterministic.
     fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output;
+
+    /// Evaluates the function at some `input` with a local copy of this `seed`.
+    /// This is the same as [`evaluate`](NoiseFunction::evaluate), but it doesn't need a mutable [`NoiseRng`] and can't affect one.
+    /// That makes it easy to reproduce the output of an inner noise function exactly, ex: in tests.
+    #[inline]
+    fn evaluate_with_seed(&self, input: I, mut seed: NoiseRng) -> Self::Output {
+        self.evaluate(input, &mut seed)
+    }
 }
 
 impl<I, T0: NoiseFunction<I>> NoiseFunction<I> for (T0,) {
@@ -89,6 +108,147 @@ mod function_impls {
     impl_noise_function_tuple!(T0-T1-1, T1-T2-2, T2-T3-3, T3-T4-4, T4-T5-5, T5-T6-6, T6-T7-7, T7-T8-8, T8-T9-9, T9-T10-10, T10-T11-11, T11-T12-12, T12-T13-13, T13-T14-14, T14-T15-15);
 }
 
+/// Any function or closure with the right signature is a [`NoiseFunction`].
+/// This is handy for quick experiments and one-off steps in a chain:
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use noiz::rng::NoiseRng;
+/// # use bevy_math::prelude::*;
+/// let to_unorm = |value: f32, _seeds: &mut NoiseRng| value * 0.5 + 0.5;
+/// let noise = Noise::<(common_noise::Perlin, _)>::from((common_noise::Perlin::default(), to_unorm));
+/// let value = noise.sample_for::<f32>(Vec2::new(0.5, 0.0));
+/// ```
+impl<I, O, F: Fn(I, &mut NoiseRng) -> O> NoiseFunction<I> for F {
+    type Output = O;
+
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        self(input, seeds)
+    }
+}
+
+/// An extension trait that lets [`NoiseFunction`]s be chained inline.
+/// Chaining `a.then(b)` just produces the tuple `(a, b)`, which evaluates `a` and passes its output into `b`.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// use noiz::{NoiseFunctionExt, math_noise::Abs};
+/// let noise = Noise::from(common_noise::Perlin::default().then(Abs).then(SNormToUNorm));
+/// # let val = noise.sample_for::<f32>(bevy_math::Vec2::ZERO);
+/// ```
+///
+/// The above is equivalent to `Noise::<(common_noise::Perlin, Abs, SNormToUNorm)>`, but the resulting type is the nested `((Perlin, Abs), SNormToUNorm)`.
+///
+/// Since [`NoiseFunction`] is generic over its input, this is implemented for every type, so it is not in the prelude.
+/// Otherwise, it would conflict with other `then` methods, like [`bool::then`].
+pub trait NoiseFunctionExt: Sized {
+    /// Chains `next` after this function, producing `(self, next)`.
+    #[inline]
+    fn then<N>(self, next: N) -> (Self, N) {
+        (self, next)
+    }
+}
+
+impl<T> NoiseFunctionExt for T {}
+
+/// The range of `f32` values a [`NoiseFunction`] can produce. See [`NoiseRange`].
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct OutputRange {
+    /// The least value that can be produced.
+    pub min: f32,
+    /// The greatest value that can be produced.
+    pub max: f32,
+}
+
+impl OutputRange {
+    /// The range (0, 1).
+    pub const UNORM: Self = Self { min: 0.0, max: 1.0 };
+    /// The range (-1, 1).
+    pub const SNORM: Self = Self {
+        min: -1.0,
+        max: 1.0,
+    };
+    /// A range that could be anything.
+    /// This is also used when the values are not scalars, as is the case for most inputs.
+    pub const UNKNOWN: Self = Self {
+        min: f32::NEG_INFINITY,
+        max: f32::INFINITY,
+    };
+
+    /// Maps this range through `f`, which must be monotonic over the range.
+    #[inline]
+    pub fn map_monotonic(self, f: impl Fn(f32) -> f32) -> Self {
+        let a = f(self.min);
+        let b = f(self.max);
+        Self {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+
+    /// Linearly remaps `value` from this range to (0, 1).
+    /// If this range is not finite, the result will not be meaningful.
+    #[inline]
+    pub fn normalize(self, value: f32) -> f32 {
+        (value - self.min) / (self.max - self.min)
+    }
+}
+
+/// Represents a [`NoiseFunction`] that knows the range of `f32` values it produces.
+/// This is propagated through tuples, so a composition of [`NoiseFunction`]s can know its range too.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use noiz::{NoiseRange, OutputRange};
+/// let noise = Noise::<(common_noise::Perlin, SNormToUNorm)>::default();
+/// assert!(noise.noise.output_range(OutputRange::UNKNOWN) == OutputRange::UNORM);
+/// ```
+///
+/// See also [`Noise::sample_normalized`].
+pub trait NoiseRange {
+    /// Given the range of the input to this function, returns the range of its output.
+    /// If the input is unknown or is not a scalar, pass [`OutputRange::UNKNOWN`].
+    /// Functions that operate on the domain (the sample location) will generally pass the `input` through.
+    fn output_range(&self, input: OutputRange) -> OutputRange;
+}
+
+macro_rules! impl_noise_range_tuple {
+    ($($t:ident-$i:tt),*) => {
+        impl<$($t: NoiseRange,)*> NoiseRange for ($($t,)*) {
+            #[inline]
+            fn output_range(&self, input: OutputRange) -> OutputRange {
+                $(let input = self.$i.output_range(input);)*
+                input
+            }
+        }
+    };
+}
+
+#[rustfmt::skip]
+mod range_impls {
+    use super::*;
+    impl_noise_range_tuple!(T0-0);
+    impl_noise_range_tuple!(T0-0, T1-1);
+    impl_noise_range_tuple!(T0-0, T1-1, T2-2);
+    impl_noise_range_tuple!(T0-0, T1-1, T2-2, T3-3);
+    impl_noise_range_tuple!(T0-0, T1-1, T2-2, T3-3, T4-4);
+    impl_noise_range_tuple!(T0-0, T1-1, T2-2, T3-3, T4-4, T5-5);
+    impl_noise_range_tuple!(T0-0, T1-1, T2-2, T3-3, T4-4, T5-5, T6-6);
+    impl_noise_range_tuple!(T0-0, T1-1, T2-2, T3-3, T4-4, T5-5, T6-6, T7-7);
+    impl_noise_range_tuple!(T0-0, T1-1, T2-2, T3-3, T4-4, T5-5, T6-6, T7-7, T8-8);
+    impl_noise_range_tuple!(T0-0, T1-1, T2-2, T3-3, T4-4, T5-5, T6-6, T7-7, T8-8, T9-9);
+    impl_noise_range_tuple!(T0-0, T1-1, T2-2, T3-3, T4-4, T5-5, T6-6, T7-7, T8-8, T9-9, T10-10);
+    impl_noise_range_tuple!(T0-0, T1-1, T2-2, T3-3, T4-4, T5-5, T6-6, T7-7, T8-8, T9-9, T10-10, T11-11);
+    impl_noise_range_tuple!(T0-0, T1-1, T2-2, T3-3, T4-4, T5-5, T6-6, T7-7, T8-8, T9-9, T10-10, T11-11, T12-12);
+    impl_noise_range_tuple!(T0-0, T1-1, T2-2, T3-3, T4-4, T5-5, T6-6, T7-7, T8-8, T9-9, T10-10, T11-11, T12-12, T13-13);
+    impl_noise_range_tuple!(T0-0, T1-1, T2-2, T3-3, T4-4, T5-5, T6-6, T7-7, T8-8, T9-9, T10-10, T11-11, T12-12, T13-13, T14-14);
+    impl_noise_range_tuple!(T0-0, T1-1, T2-2, T3-3, T4-4, T5-5, T6-6, T7-7, T8-8, T9-9, T10-10, T11-11, T12-12, T13-13, T14-14, T15-15);
+}
+
 /// Specifies that this noise is seedable.
 ///
 /// ```
@@ -104,6 +264,7 @@ pub trait SeedableNoise {
     fn set_seed(&mut self, seed: u32);
 
     /// Gets the seed of the noise.
+    /// This only needs a shared reference, so it works in read only contexts.
     fn get_seed(&self) -> u32;
 }
 
@@ -131,7 +292,8 @@ pub trait ScalableNoise {
     fn set_frequency(&mut self, frequency: f32);
 
     /// Gets the scale of the noise via its frequency.
-    /// See also [`get_frequency`](ScalableNoise::get_frequency).
+    /// This only needs a shared reference, so it works in read only contexts.
+    /// See also [`set_frequency`](ScalableNoise::set_frequency).
     fn get_frequency(&self) -> f32;
 
     /// Sets the scale of the noise via its period.
@@ -231,6 +393,22 @@ pub trait SampleableFor<I, T> {
     fn sample(&self, loc: I) -> T;
 }
 
+impl<I, N: Sampleable<I>> Sampleable<I> for &N {
+    type Result = N::Result;
+
+    #[inline]
+    fn sample_raw(&self, loc: I) -> (Self::Result, NoiseRng) {
+        N::sample_raw(self, loc)
+    }
+}
+
+impl<I, T, N: SampleableFor<I, T>> SampleableFor<I, T> for &N {
+    #[inline]
+    fn sample(&self, loc: I) -> T {
+        N::sample(self, loc)
+    }
+}
+
 /// A version of [`Sampleable<I, Result=T>`] that is dyn-compatible.
 /// Generally, `noize` uses exact types whenever possible to enable more inlining and optimizations,
 /// but this trait focuses instead on usability at the expense of speed.
@@ -271,6 +449,135 @@ impl<I, T, N: SeedableNoise + ScalableNoise + DynamicSampleable<I, T>>
 {
 }
 
+/// An extension trait that boxes any sampler as a [`DynamicSampleable`] trait object.
+/// This saves spelling out the box and trait object type, for example, when collecting several noise types into one list.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// let options = [
+///     Noise::<common_noise::Perlin>::default().into_dyn::<Vec2, f32>(),
+///     Noise::<common_noise::Simplex>::default().into_dyn(),
+/// ];
+/// let value = options[1].sample_dyn(Vec2::new(1.0, -1.0));
+/// ```
+#[cfg(feature = "alloc")]
+pub trait IntoDynamicSampleable: Sized {
+    /// Boxes `self` as a [`DynamicSampleable`] from `I` to `T`.
+    fn into_dyn<I, T>(self) -> Box<dyn DynamicSampleable<I, T> + Send + Sync>
+    where
+        Self: DynamicSampleable<I, T> + Send + Sync + 'static,
+    {
+        Box::new(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<N> IntoDynamicSampleable for N {}
+
+/// An extension trait for [`SampleableFor<Vec2, f32>`] that fills caller owned heightmap buffers.
+/// This is the minimal primitive that image or array integrations can build on.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// let noise = Noise::<common_noise::Perlin>::default();
+/// let mut heights = [0.0; 16 * 16];
+/// noise.fill_heightmap(&mut heights, 16, Vec2::ZERO, Vec2::splat(0.1));
+/// ```
+pub trait HeightmapSampleable: SampleableFor<Vec2, f32> {
+    /// Fills `out` as a row-major heightmap `width` samples wide.
+    /// The sample at column `x` and row `y` is taken at `origin + step * (x, y)`.
+    /// If the length of `out` is not a multiple of `width`, the last row is left partial.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `width` is zero and `out` is not empty.
+    fn fill_heightmap(&self, out: &mut [f32], width: usize, origin: Vec2, step: Vec2) {
+        for (index, height) in out.iter_mut().enumerate() {
+            let column = index % width;
+            let row = index / width;
+            *height = self.sample(origin + step * Vec2::new(column as f32, row as f32));
+        }
+    }
+}
+
+impl<N: SampleableFor<Vec2, f32>> HeightmapSampleable for N {}
+
+/// Samples 2d noise in square chunks laid out on a world grid, such that adjacent chunks agree exactly on their shared edges.
+///
+/// Each chunk spans [`cells_per_chunk`](ChunkSampler::cells_per_chunk) cells per axis, and has one more sample than that per axis, so that each chunk includes its far edges.
+/// Every sample location is computed from its integer world coordinate, never by accumulating offsets,
+/// so the last row or column of one chunk is sampled from the exact same locations as the first of its neighbor.
+/// This holds as long as world coordinates stay within the range where `f32` represents integers exactly (about 16 million cells).
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// use noiz::ChunkSampler;
+/// let noise = Noise::<common_noise::Perlin>::default();
+/// let chunks = ChunkSampler {
+///     cells_per_chunk: 16,
+///     cell_size: 0.1,
+/// };
+/// let mut heights = [0.0f32; 17 * 17];
+/// chunks.fill_chunk(&noise, IVec2::new(3, -2), &mut heights);
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct ChunkSampler {
+    /// The number of cells along each axis of a chunk.
+    pub cells_per_chunk: u32,
+    /// The distance between adjacent samples in the noise's sample space.
+    pub cell_size: f32,
+}
+
+impl Default for ChunkSampler {
+    fn default() -> Self {
+        Self {
+            cells_per_chunk: 32,
+            cell_size: 1.0,
+        }
+    }
+}
+
+impl ChunkSampler {
+    /// The number of samples along each axis of a chunk.
+    #[inline]
+    pub fn samples_per_side(&self) -> usize {
+        self.cells_per_chunk as usize + 1
+    }
+
+    /// The location to sample for the sample at `local` within the chunk at `chunk`.
+    #[inline]
+    pub fn sample_location(&self, chunk: IVec2, local: UVec2) -> Vec2 {
+        let cells = self.cells_per_chunk as i64;
+        let x = chunk.x as i64 * cells + local.x as i64;
+        let y = chunk.y as i64 * cells + local.y as i64;
+        Vec2::new(x as f32, y as f32) * self.cell_size
+    }
+
+    /// Fills `out` as a row-major grid of [`samples_per_side`](ChunkSampler::samples_per_side) by [`samples_per_side`](ChunkSampler::samples_per_side) samples of `noise` for the chunk at `chunk`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `out` is not exactly `samples_per_side * samples_per_side` long.
+    pub fn fill_chunk<T>(&self, noise: &impl SampleableFor<Vec2, T>, chunk: IVec2, out: &mut [T]) {
+        let side = self.samples_per_side();
+        assert_eq!(
+            out.len(),
+            side * side,
+            "The chunk buffer must hold exactly {side} by {side} samples."
+        );
+        for (index, sample) in out.iter_mut().enumerate() {
+            let local = UVec2::new((index % side) as u32, (index / side) as u32);
+            *sample = noise.sample(self.sample_location(chunk, local));
+        }
+    }
+}
+
 /// This is the standard [`Sampleable`] of a [`NoiseFunction`] `N`.
 /// It wraps `N` with a self contained random number generator and frequency.
 /// This currently only supports sampling from [`VectorSpace`] types.
@@ -329,6 +636,39 @@ impl<I: VectorSpace, N: NoiseFunction<I>> NoiseFunction<I> for Noise<N> {
     }
 }
 
+impl<N: NoiseRange> NoiseRange for Noise<N> {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        self.noise.output_range(input)
+    }
+}
+
+impl<N: NoiseRange> Noise<N> {
+    /// Samples the noise at `loc`, remapping the result to (0, 1) based on its [`NoiseRange`].
+    /// This saves needing to know if a particular noise is SNorm, UNorm, etc.
+    /// If the range is not finite, like [`OutputRange::UNKNOWN`], there is nothing to remap from, so the raw sample is returned instead.
+    ///
+    /// ```
+    /// # use noiz::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// let noise = Noise::<common_noise::Perlin>::default();
+    /// let value = noise.sample_normalized(Vec2::new(1.0, -1.0));
+    /// ```
+    #[inline]
+    pub fn sample_normalized<I>(&self, loc: I) -> f32
+    where
+        Self: SampleableFor<I, f32>,
+    {
+        let range = self.noise.output_range(OutputRange::UNKNOWN);
+        let value = self.sample(loc);
+        if range.min.is_finite() && range.max.is_finite() {
+            range.normalize(value)
+        } else {
+            value
+        }
+    }
+}
+
 impl<N> ScalableNoise for Noise<N> {
     fn set_frequency(&mut self, frequency: f32) {
         self.frequency = frequency;
@@ -368,6 +708,31 @@ impl<T, I: VectorSpace, N: NoiseFunction<I, Output: Into<T>>> SampleableFor<I, T
     }
 }
 
+impl<W, N: NoiseFunction<u32>> Noise<PerCell<OrthoGrid<W>, N>> {
+    /// Samples the noise of the grid cell at the integer coordinate `coord`, for a result of type `T`.
+    /// The coordinate may be an [`IVec2`], [`IVec3`](bevy_math::IVec3), or [`IVec4`](bevy_math::IVec4).
+    /// This is a fast path for purely lattice based noise, like per tile white noise, that skips flooring the sample location.
+    ///
+    /// Note that `coord` is in the noise's own grid, so the [`frequency`](Noise::frequency) is not applied.
+    /// With a frequency of 1, this is the same as sampling anywhere within the cell, like `coord.as_vec2() + 0.5`.
+    ///
+    /// ```
+    /// # use noiz::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// let noise = Noise::<common_noise::White>::default();
+    /// let value: f32 = noise.sample_lattice(IVec2::new(3, -7));
+    /// ```
+    #[inline]
+    pub fn sample_lattice<T, I: NoiseRngInput>(&self, coord: I) -> T
+    where
+        W: WrappingAmount<I>,
+        N::Output: Into<T>,
+    {
+        let mut seeds = self.seed;
+        self.noise.evaluate_lattice(coord, &mut seeds).into()
+    }
+}
+
 /// This is an alternative to [`Noise`] for when scaling an sample location is not desired or is impossible.
 /// In general, [`Noise`] is easier to use, but this offers more control if desired.
 #[derive(PartialEq, Clone, Copy)]
@@ -409,6 +774,13 @@ impl<I, N: NoiseFunction<I>> NoiseFunction<I> for RawNoise<N> {
     }
 }
 
+impl<N: NoiseRange> NoiseRange for RawNoise<N> {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        self.noise.output_range(input)
+    }
+}
+
 impl<N> SeedableNoise for RawNoise<N> {
     fn set_seed(&mut self, seed: u32) {
         self.seed = NoiseRng(seed);
@@ -437,3 +809,329 @@ impl<T, I, N: NoiseFunction<I, Output: Into<T>>> SampleableFor<I, T> for RawNois
         result.into()
     }
 }
+
+/// A [`Sampleable`] that animates 2d noise by sampling 3d noise `N`, using [`time`](Self::time) as the third axis.
+/// This also works as a [`NoiseFunction`] when `N` is a [`NoiseFunction<Vec3>`].
+///
+/// Note that when `N` is a [`Noise`], the time is also scaled by its frequency.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use noiz::Animated;
+/// # use bevy_math::prelude::*;
+/// let mut noise = Animated::<Noise<common_noise::Perlin>>::default();
+/// noise.set_time(1.5);
+/// let value = noise.sample_for::<f32>(Vec2::new(1.0, -1.0));
+/// ```
+#[derive(Default, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Animated<N> {
+    /// The 3d noise being animated.
+    pub noise: N,
+    /// The current time, used as the third axis of the sample.
+    pub time: f32,
+}
+
+impl<N> From<N> for Animated<N> {
+    fn from(value: N) -> Self {
+        Self {
+            noise: value,
+            time: 0.0,
+        }
+    }
+}
+
+impl<N> Animated<N> {
+    /// Sets the [`time`](Self::time) of the animation.
+    #[inline]
+    pub fn set_time(&mut self, time: f32) {
+        self.time = time;
+    }
+}
+
+impl<N: NoiseFunction<Vec3>> NoiseFunction<Vec2> for Animated<N> {
+    type Output = N::Output;
+
+    #[inline]
+    fn evaluate(&self, input: Vec2, seeds: &mut NoiseRng) -> Self::Output {
+        self.noise.evaluate(input.extend(self.time), seeds)
+    }
+}
+
+impl<N: ScalableNoise> ScalableNoise for Animated<N> {
+    fn set_frequency(&mut self, frequency: f32) {
+        self.noise.set_frequency(frequency);
+    }
+
+    fn get_frequency(&self) -> f32 {
+        self.noise.get_frequency()
+    }
+}
+
+impl<N: SeedableNoise> SeedableNoise for Animated<N> {
+    fn set_seed(&mut self, seed: u32) {
+        self.noise.set_seed(seed);
+    }
+
+    fn get_seed(&self) -> u32 {
+        self.noise.get_seed()
+    }
+}
+
+impl<N: Sampleable<Vec3>> Sampleable<Vec2> for Animated<N> {
+    type Result = N::Result;
+
+    #[inline]
+    fn sample_raw(&self, loc: Vec2) -> (Self::Result, NoiseRng) {
+        self.noise.sample_raw(loc.extend(self.time))
+    }
+}
+
+impl<T, N: SampleableFor<Vec3, T>> SampleableFor<Vec2, T> for Animated<N> {
+    #[inline]
+    fn sample(&self, loc: Vec2) -> T {
+        self.noise.sample(loc.extend(self.time))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        cell_noise::WorleySmoothMin,
+        curves::CubicSMin,
+        prelude::{common_noise::*, *},
+    };
+
+    fn test_normalized<N: NoiseRange>(noise: Noise<N>)
+    where
+        Noise<N>: SampleableFor<Vec2, f32>,
+    {
+        for x in -20..=20 {
+            for y in -20..=20 {
+                let point = Vec2::new(x as f32, y as f32) * 0.37;
+                let value = noise.sample_normalized(point);
+                assert!(
+                    (0.0..=1.0).contains(&value),
+                    "Normalized value {value} is out of range at {point:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_output_ranges() {
+        let range = |noise: &dyn NoiseRange| noise.output_range(OutputRange::UNKNOWN);
+        assert!(range(&Perlin::default()) == OutputRange::SNORM);
+        assert!(range(&Simplex::default()) == OutputRange::SNORM);
+        assert!(range(&Value::default()) == OutputRange::UNORM);
+        assert!(range(&Worley::default()) == OutputRange::UNORM);
+        assert!(range(&Fbm::<Perlin>::default()) == OutputRange::SNORM);
+        assert!(range(&(Perlin::default(), SNormToUNorm)) == OutputRange::UNORM);
+        assert!(range(&(Perlin::default(), Billow::default())) == OutputRange::SNORM);
+    }
+
+    #[test]
+    fn test_sample_normalized() {
+        test_normalized(Noise::<Value>::default());
+        test_normalized(Noise::<Worley>::default());
+        test_normalized(Noise::<Fbm<Value>>::default());
+        test_normalized(Noise::<
+            MixCellValues<OrthoGrid, Smoothstep, Random<SNorm, f32>>,
+        >::default());
+        test_normalized(Noise::<(
+            MixCellValues<OrthoGrid, Smoothstep, Random<SNorm, f32>>,
+            Billow,
+        )>::default());
+    }
+
+    #[test]
+    fn test_animated() {
+        let mut noise = Animated::<Noise<Perlin>>::default();
+        let loc = Vec2::new(1.3, -0.7);
+        let mut previous: f32 = noise.sample(loc);
+        let mut changed = false;
+        for step in 1..=1000 {
+            noise.set_time(step as f32 * 1e-3);
+            let value: f32 = noise.sample(loc);
+            assert!(
+                (value - previous).abs() < 0.01,
+                "Animation jumped from {previous} to {value} at time {}",
+                noise.time
+            );
+            changed |= value != previous;
+            previous = value;
+        }
+        assert!(changed, "Changing the time did not change the noise.");
+    }
+
+    #[test]
+    fn test_fill_heightmap() {
+        let noise = Noise::<Perlin>::default();
+        let origin = Vec2::new(-3.0, 1.5);
+        let step = Vec2::new(0.25, 0.5);
+        let width = 7;
+        let mut heights = [0.0; 7 * 5 + 3];
+        noise.fill_heightmap(&mut heights, width, origin, step);
+        for (index, height) in heights.into_iter().enumerate() {
+            let point = origin + step * Vec2::new((index % width) as f32, (index / width) as f32);
+            let expected: f32 = noise.sample(point);
+            assert_eq!(height, expected);
+        }
+    }
+
+    #[test]
+    fn test_closure_noise() {
+        fn halve(input: f32, _seeds: &mut NoiseRng) -> f32 {
+            input * 0.5
+        }
+
+        let length = |input: Vec2, _seeds: &mut NoiseRng| input.length();
+        let chained = (length, halve);
+        for x in -5..=5 {
+            let input = Vec2::new(x as f32 * 0.2, 0.0);
+            let expected = input.length() * 0.5;
+            assert_eq!(chained.evaluate(input, &mut NoiseRng(0)), expected);
+        }
+
+        let seeded = |_input: f32, seeds: &mut NoiseRng| seeds.0;
+        assert_eq!(seeded.evaluate(0.0, &mut NoiseRng(42)), 42);
+    }
+
+    #[test]
+    fn test_sample_reference() {
+        fn sample_shared(noise: impl SampleableFor<Vec2, f32>, loc: Vec2) -> f32 {
+            noise.sample(loc)
+        }
+
+        fn sample_raw_shared(noise: impl Sampleable<Vec2, Result = f32>, loc: Vec2) -> f32 {
+            noise.sample_raw(loc).0
+        }
+
+        let noise = Noise::<Fbm<Perlin>>::default();
+        let shared = &noise;
+        for x in -5..=5 {
+            let loc = Vec2::new(x as f32 * 0.37, 1.3);
+            let owned: f32 = noise.sample(loc);
+            assert_eq!(sample_shared(shared, loc), owned);
+            assert_eq!(sample_raw_shared(shared, loc), owned);
+        }
+    }
+
+    #[test]
+    fn test_then_matches_tuple() {
+        use crate::math_noise::Abs;
+
+        let chained = Noise::from(Perlin::default().then(Abs).then(SNormToUNorm));
+        let tuple = Noise::<(Perlin, Abs, SNormToUNorm)>::default();
+        for x in -20..=20 {
+            for y in -20..=20 {
+                let point = Vec2::new(x as f32, y as f32) * 0.37;
+                let expected: f32 = tuple.sample(point);
+                let actual: f32 = chained.sample(point);
+                assert_eq!(expected, actual);
+            }
+        }
+    }
+
+    #[test]
+    fn test_read_config_through_shared_reference() {
+        fn read(noise: &impl SeedableNoise, scalable: &impl ScalableNoise) -> (u32, f32) {
+            (noise.get_seed(), scalable.get_frequency())
+        }
+
+        let mut noise = Noise::<Perlin>::default();
+        noise.set_seed(1234);
+        noise.set_frequency(0.25);
+        let shared = &noise;
+        assert_eq!(read(shared, shared), (1234, 0.25));
+        assert_eq!(shared.get_period(), 4.0);
+    }
+
+    #[test]
+    fn test_adjacent_chunks_share_edges() {
+        let noise = Noise::<Fbm<Perlin>>::default();
+        let chunks = ChunkSampler {
+            cells_per_chunk: 16,
+            cell_size: 0.37,
+        };
+        let side = chunks.samples_per_side();
+        let mut center = [0.0f32; 17 * 17];
+        let mut right = [0.0f32; 17 * 17];
+        let mut up = [0.0f32; 17 * 17];
+        chunks.fill_chunk(&noise, IVec2::new(-1, 5), &mut center);
+        chunks.fill_chunk(&noise, IVec2::new(0, 5), &mut right);
+        chunks.fill_chunk(&noise, IVec2::new(-1, 6), &mut up);
+        for i in 0..side {
+            assert_eq!(center[i * side + side - 1], right[i * side]);
+            assert_eq!(center[(side - 1) * side + i], up[i]);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_into_dyn() {
+        let noise = Noise::<Perlin>::default();
+        let boxed = noise.into_dyn::<Vec2, f32>();
+        for x in -10..10 {
+            let point = Vec2::new(x as f32 * 0.37, 0.5);
+            assert_eq!(boxed.sample_dyn(point), noise.sample_for::<f32>(point));
+        }
+    }
+
+    #[test]
+    fn test_sample_lattice_matches_sample() {
+        let noise = Noise::<White>::default();
+        for x in -20..20 {
+            for y in -20..20 {
+                let coord = IVec2::new(x, y);
+                let lattice: f32 = noise.sample_lattice(coord);
+                assert_eq!(lattice, noise.sample_for::<f32>(coord.as_vec2() + 0.5));
+            }
+        }
+    }
+
+    #[test]
+    fn test_sample_lattice_3d_matches_sample() {
+        let noise = Noise::<White>::default();
+        for x in -6..6 {
+            for y in -6..6 {
+                for z in -6..6 {
+                    let coord = bevy_math::IVec3::new(x, y, z);
+                    let lattice: f32 = noise.sample_lattice(coord);
+                    assert_eq!(lattice, noise.sample_for::<f32>(coord.as_vec3() + 0.5));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_evaluate_with_seed() {
+        let perlin = Perlin::default();
+        let mut any_differ = false;
+        for x in -10..=10 {
+            let point = Vec2::new(x as f32 * 0.37, x as f32 * 0.21 + 0.5);
+            let first = perlin.evaluate_with_seed(point, NoiseRng(7));
+            let second = perlin.evaluate_with_seed(point, NoiseRng(7));
+            assert_eq!(first, second);
+            assert_eq!(first, perlin.evaluate(point, &mut NoiseRng(7)));
+            any_differ |= first != perlin.evaluate_with_seed(point, NoiseRng(8));
+        }
+        assert!(any_differ);
+    }
+
+    #[test]
+    fn test_sample_normalized_unknown_range() {
+        let noise = Noise::<
+            PerCellPointDistances<Voronoi, EuclideanLength, WorleySmoothMin<CubicSMin>>,
+        >::default();
+        assert!(noise.noise.output_range(OutputRange::UNKNOWN) == OutputRange::UNKNOWN);
+        for x in -5..=5 {
+            let point = Vec2::new(x as f32, 0.5) * 0.37;
+            let value: f32 = noise.sample(point);
+            assert!(noise.sample_normalized(point) == value);
+        }
+    }
+}
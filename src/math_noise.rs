@@ -5,9 +5,14 @@
 
 use core::ops::{Mul, Neg};
 
-use bevy_math::{Curve, Vec2, Vec3, Vec3A, Vec4};
+use bevy_math::{Curve, Mat2, NormedVectorSpace, Vec2, Vec3, Vec3A, Vec4};
 
-use crate::{NoiseFunction, cells::WithGradient, lengths::LengthFunction};
+use crate::{
+    NoiseFunction, NoiseRange, OutputRange,
+    cells::{WithGradient, WithHessian},
+    curves::Smoothstep,
+    lengths::LengthFunction,
+};
 
 /// A [`NoiseFunction`] that maps vectors from (-1,1) to (0, 1).
 #[derive(Default, PartialEq, Clone, Copy)]
@@ -103,6 +108,38 @@ pub struct ReverseUNorm;
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct Negate;
 
+/// A [`NoiseFunction`] that adjusts the contrast of SNorm values by scaling them around zero and clamping the result to -1..=1.
+/// Unlike [`Scaled`](crate::misc_noise::Scaled), this keeps the output SNorm, so values pushed past the range saturate instead of growing.
+/// Factors above 1 increase contrast, and factors between 0 and 1 decrease it.
+/// For vectors, each element is scaled and clamped independently.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Contrast(pub f32);
+
+impl Default for Contrast {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
+/// A [`NoiseFunction`] that applies gamma correction to UNorm values, ex: `input.powf(1.0 / gamma)`.
+/// Inputs are clamped to 0..=1 first, so the output is always UNorm.
+/// Gammas above 1 brighten midtones, like encoding linear values for sRGB with a gamma of 2.2, and gammas between 0 and 1 darken them.
+/// For vectors, this is applied to each element, so it works for colors too.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Gamma(pub f32);
+
+impl Default for Gamma {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
+
 /// A [`NoiseFunction`] that produces a billowing effect for SNorm values.
 /// Inspired by [libnoise](https://docs.rs/libnoise/latest/libnoise/).
 ///
@@ -117,6 +154,96 @@ pub type Billow = (Abs, UNormToSNorm);
 #[cfg_attr(feature = "debug", derive(Debug))]
 pub struct Wrapped(pub f32);
 
+impl NoiseRange for SNormToUNorm {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        input.map_monotonic(|x| x * 0.5 + 0.5)
+    }
+}
+
+impl NoiseRange for UNormToSNorm {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        input.map_monotonic(|x| x * 2.0 - 1.0)
+    }
+}
+
+impl NoiseRange for Negate {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        input.map_monotonic(|x| -x)
+    }
+}
+
+impl NoiseRange for ReverseUNorm {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        input.map_monotonic(|x| 1.0 - x)
+    }
+}
+
+impl NoiseRange for Abs {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        if input.min >= 0.0 {
+            input
+        } else if input.max <= 0.0 {
+            input.map_monotonic(|x| -x)
+        } else {
+            OutputRange {
+                min: 0.0,
+                max: input.max.max(-input.min),
+            }
+        }
+    }
+}
+
+impl NoiseRange for Pow2 {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        Abs.output_range(input).map_monotonic(|x| x * x)
+    }
+}
+
+impl NoiseRange for Pow3 {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        input.map_monotonic(|x| x * x * x)
+    }
+}
+
+impl NoiseRange for Pow4 {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        Abs.output_range(input).map_monotonic(|x| x * x * x * x)
+    }
+}
+
+impl NoiseRange for Sqrt {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        OutputRange {
+            min: input.min.max(0.0),
+            max: input.max.max(0.0),
+        }
+        .map_monotonic(bevy_math::ops::sqrt)
+    }
+}
+
+impl NoiseRange for Contrast {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        input.map_monotonic(|x| (x * self.0).clamp(-1.0, 1.0))
+    }
+}
+
+impl NoiseRange for Gamma {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        input.map_monotonic(|x| bevy_math::ops::powf(x.clamp(0.0, 1.0), 1.0 / self.0))
+    }
+}
+
 macro_rules! impl_vector_spaces {
     (scalar $n:ty) => {
         impl_vector_spaces!(both $n);
@@ -156,6 +283,24 @@ macro_rules! impl_vector_spaces {
                 bevy_math::ops::sqrt(input)
             }
         }
+
+        impl NoiseFunction<$n> for Contrast {
+            type Output = $n;
+
+            #[inline]
+            fn evaluate(&self, input: $n, _seeds: &mut crate::rng::NoiseRng) -> Self::Output {
+                (input * self.0).clamp(-1.0, 1.0)
+            }
+        }
+
+        impl NoiseFunction<$n> for Gamma {
+            type Output = $n;
+
+            #[inline]
+            fn evaluate(&self, input: $n, _seeds: &mut crate::rng::NoiseRng) -> Self::Output {
+                bevy_math::ops::powf(input.clamp(0.0, 1.0), 1.0 / self.0)
+            }
+        }
     };
 
     (vec $n:ty) => {
@@ -196,6 +341,27 @@ macro_rules! impl_vector_spaces {
                 input.map(|v| v.powi(self.0))
             }
         }
+
+        impl NoiseFunction<$n> for Contrast {
+            type Output = $n;
+
+            #[inline]
+            fn evaluate(&self, input: $n, _seeds: &mut crate::rng::NoiseRng) -> Self::Output {
+                (input * self.0).clamp(<$n>::NEG_ONE, <$n>::ONE)
+            }
+        }
+
+        impl NoiseFunction<$n> for Gamma {
+            type Output = $n;
+
+            #[inline]
+            fn evaluate(&self, input: $n, _seeds: &mut crate::rng::NoiseRng) -> Self::Output {
+                let inverse = 1.0 / self.0;
+                input
+                    .clamp(<$n>::ZERO, <$n>::ONE)
+                    .map(|v| bevy_math::ops::powf(v, inverse))
+            }
+        }
     };
 
     (both $n:ty) => {
@@ -325,6 +491,8 @@ impl NoiseFunction<f32> for PingPong {
 }
 
 /// A [`NoiseFunction`] that samples some [`Curve`] directly.
+/// For vectors, the curve is applied to each component, which is useful for remapping vector valued noise, like colors.
+/// See also [`NoiseCurveClamped`].
 #[derive(Default, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
@@ -341,6 +509,7 @@ impl<C: Curve<f32>> NoiseFunction<f32> for NoiseCurve<C> {
 }
 
 /// A [`NoiseFunction`] that samples some [`Curve`] in the proper range by clamping.
+/// For vectors, the curve is applied to each component. See also [`NoiseCurve`].
 #[derive(Default, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
@@ -417,6 +586,54 @@ impl<L: LengthFunction<Vec2>> NoiseFunction<Vec2> for Spiral<L> {
     }
 }
 
+/// A [`NoiseFunction`] that turns a cartesian `Vec2` into a polar `Vec2` of its radius and its angle, in radians from -π to π.
+/// Sampling noise with this as the input produces radial and circular patterns.
+///
+/// If `DIFFERENTIATE` is on (off by default), this also produces the Jacobian of the transform in a [`WithGradient`],
+/// where each element of the gradient is the derivative of the polar coordinate with respect to x and y respectively.
+/// Given the gradient `g` of some noise sampled at the polar coordinate, its gradient in cartesian space is `Vec2::new(g.dot(gradient[0]), g.dot(gradient[1]))`.
+///
+/// The transform is not differentiable at the origin, where the Jacobian is defined to be zero.
+/// The angle also jumps from π to -π across the negative x axis, so noise sampled by angle should be periodic or masked there.
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct ToPolar<const DIFFERENTIATE: bool = false>;
+
+impl NoiseFunction<Vec2> for ToPolar<false> {
+    type Output = Vec2;
+
+    #[inline]
+    fn evaluate(&self, input: Vec2, _seeds: &mut crate::rng::NoiseRng) -> Self::Output {
+        Vec2::new(input.length(), input.to_angle())
+    }
+}
+
+impl NoiseFunction<Vec2> for ToPolar<true> {
+    type Output = WithGradient<Vec2, [Vec2; 2]>;
+
+    #[inline]
+    fn evaluate(&self, input: Vec2, _seeds: &mut crate::rng::NoiseRng) -> Self::Output {
+        let radius = input.length();
+        let radius_sqrd = input.length_squared();
+        let value = Vec2::new(radius, input.to_angle());
+        if radius < f32::EPSILON {
+            return WithGradient {
+                value,
+                gradient: [Vec2::ZERO; 2],
+            };
+        }
+        WithGradient {
+            value,
+            gradient: [
+                Vec2::new(input.x / radius, -input.y / radius_sqrd),
+                Vec2::new(input.y / radius, input.x / radius_sqrd),
+            ],
+        }
+    }
+}
+
 impl<T, G: Mul<f32, Output = G>> NoiseFunction<WithGradient<T, G>> for SNormToUNorm
 where
     Self: NoiseFunction<T, Output = T>,
@@ -590,3 +807,913 @@ where
         }
     }
 }
+
+/// A [`NoiseFunction`] that takes a [`WithGradient`] and produces the length of its gradient.
+/// This is the steepness, or slope, of the noise at that point.
+/// For example, `(PerlinWithDerivative, SlopeMagnitude)` produces a slope field for perlin noise.
+#[derive(Default, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct SlopeMagnitude;
+
+impl<G: NormedVectorSpace> NoiseFunction<WithGradient<f32, G>> for SlopeMagnitude {
+    type Output = f32;
+
+    #[inline]
+    fn evaluate(
+        &self,
+        input: WithGradient<f32, G>,
+        _seeds: &mut crate::rng::NoiseRng,
+    ) -> Self::Output {
+        input.gradient.norm()
+    }
+}
+
+/// A [`NoiseFunction`] that takes a [`WithGradient`] and limits the length of its gradient to [`max`](ClampGradient::max), leaving the value untouched.
+/// Gradients longer than `max` keep their direction but are rescaled to exactly `max`.
+/// This keeps normals stable near sharp features, like worley cell borders, where gradients can spike and cause lighting to pop.
+#[derive(PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct ClampGradient {
+    /// The greatest length the gradient can have.
+    /// Defaults to 1.
+    pub max: f32,
+}
+
+impl Default for ClampGradient {
+    fn default() -> Self {
+        Self { max: 1.0 }
+    }
+}
+
+impl<G: NormedVectorSpace> NoiseFunction<WithGradient<f32, G>> for ClampGradient {
+    type Output = WithGradient<f32, G>;
+
+    #[inline]
+    fn evaluate(
+        &self,
+        input: WithGradient<f32, G>,
+        _seeds: &mut crate::rng::NoiseRng,
+    ) -> Self::Output {
+        let length = input.gradient.norm();
+        if length <= self.max {
+            return input;
+        }
+        WithGradient {
+            value: input.value,
+            gradient: input.gradient * (self.max / length),
+        }
+    }
+}
+
+impl NoiseRange for ClampGradient {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        input
+    }
+}
+
+/// A [`NoiseFunction`] that terraces its input into [`steps`](TerraceDiff::steps) flat plateaus joined by smooth risers, like stratified terrain.
+/// This works on `f32` values and on [`WithGradient`]s, where the gradient is scaled by the derivative of the terrace, so normals show the terraces too.
+///
+/// Each step is shaped by `f^p / (f^p + (1 - f)^p)`, where `f` is the position within the step and `p` is `1 + sharpness`.
+/// This has no slope at the plateaus and a slope of `p` at the middle of each riser, so the terraced value is continuous and smooth.
+/// A `sharpness` of 0 leaves the input unchanged, and larger values make flatter plateaus and steeper risers.
+/// A `steps` of 0 also leaves the input unchanged.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use noiz::math_noise::TerraceDiff;
+/// let noise = Noise::from((
+///     common_noise::PerlinWithDerivative::default(),
+///     SNormToUNorm,
+///     TerraceDiff::default(),
+/// ));
+/// let value = noise.sample_for::<WithGradient<f32, Vec2>>(Vec2::new(1.0, -1.0));
+/// ```
+#[derive(PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct TerraceDiff {
+    /// How many terraces there are per unit of input.
+    /// Defaults to 4.
+    pub steps: u32,
+    /// How flat the plateaus are compared to the risers.
+    /// Defaults to 3.
+    pub sharpness: f32,
+}
+
+impl Default for TerraceDiff {
+    fn default() -> Self {
+        Self {
+            steps: 4,
+            sharpness: 3.0,
+        }
+    }
+}
+
+impl TerraceDiff {
+    /// Terraces `input`, producing the terraced value and its derivative with respect to `input`.
+    #[inline]
+    pub fn terrace(&self, input: f32) -> (f32, f32) {
+        if self.steps == 0 {
+            return (input, 1.0);
+        }
+        let steps = self.steps as f32;
+        let scaled = input * steps;
+        let base = bevy_math::ops::floor(scaled);
+        let f = scaled - base;
+        let p = 1.0 + self.sharpness.max(0.0);
+        let rise = bevy_math::ops::powf(f, p);
+        let fall = bevy_math::ops::powf(1.0 - f, p);
+        let total = rise + fall;
+        let shaped = rise / total;
+        let derivative = p * bevy_math::ops::powf(f * (1.0 - f), p - 1.0) / (total * total);
+        ((base + shaped) / steps, derivative)
+    }
+}
+
+impl NoiseFunction<f32> for TerraceDiff {
+    type Output = f32;
+
+    #[inline]
+    fn evaluate(&self, input: f32, _seeds: &mut crate::rng::NoiseRng) -> Self::Output {
+        self.terrace(input).0
+    }
+}
+
+impl<G: Mul<f32, Output = G>> NoiseFunction<WithGradient<f32, G>> for TerraceDiff {
+    type Output = WithGradient<f32, G>;
+
+    #[inline]
+    fn evaluate(
+        &self,
+        input: WithGradient<f32, G>,
+        _seeds: &mut crate::rng::NoiseRng,
+    ) -> Self::Output {
+        let (value, derivative) = self.terrace(input.value);
+        WithGradient {
+            value,
+            gradient: input.gradient * derivative,
+        }
+    }
+}
+
+impl NoiseRange for TerraceDiff {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        input.map_monotonic(|x| self.terrace(x).0)
+    }
+}
+
+/// A [`NoiseFunction`] that crudely anti-aliases a [`WithGradient`] by fading its value toward 0 where it changes quickly.
+/// Where the noise is steep compared to [`filter_width`](AntiAlias::filter_width), its detail is smaller than the filter and would alias, so it is attenuated, approximating a low-pass filter.
+///
+/// Both the value and gradient are scaled by `1 / (1 + (|gradient| * filter_width)²)`, so flat regions pass through unchanged.
+/// This assumes the noise is centered around 0, like SNorm noise, so apply it before remapping to UNorm.
+/// The gradient is scaled along with the value but is not the exact derivative of the result.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use noiz::math_noise::AntiAlias;
+/// let noise = Noise::from((
+///     common_noise::PerlinWithDerivative::default(),
+///     AntiAlias { filter_width: 0.25 },
+/// ));
+/// let value = noise.sample_for::<WithGradient<f32, Vec2>>(Vec2::new(1.0, -1.0));
+/// ```
+#[derive(PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct AntiAlias {
+    /// The width of the filter, usually the distance between samples, in the units of the noise's input.
+    /// Defaults to 1.
+    pub filter_width: f32,
+}
+
+impl Default for AntiAlias {
+    fn default() -> Self {
+        Self { filter_width: 1.0 }
+    }
+}
+
+impl<G: NormedVectorSpace> NoiseFunction<WithGradient<f32, G>> for AntiAlias {
+    type Output = WithGradient<f32, G>;
+
+    #[inline]
+    fn evaluate(
+        &self,
+        input: WithGradient<f32, G>,
+        _seeds: &mut crate::rng::NoiseRng,
+    ) -> Self::Output {
+        let frequency = input.gradient.norm() * self.filter_width;
+        let attenuation = 1.0 / (1.0 + frequency * frequency);
+        WithGradient {
+            value: input.value * attenuation,
+            gradient: input.gradient * attenuation,
+        }
+    }
+}
+
+impl NoiseRange for AntiAlias {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        // Fading toward 0 can reach anything between 0 and the input.
+        OutputRange {
+            min: input.min.min(0.0),
+            max: input.max.max(0.0),
+        }
+    }
+}
+
+/// A [`NoiseFunction`] that takes a [`WithGradient`] of a 2d heightfield and produces its unit surface normal, with z up.
+/// This is `normalize(-dx, -dy, 1)`, where the gradient is first scaled by [`height_scale`](TerrainNormal::height_scale).
+/// For example, `(PerlinWithDerivative, TerrainNormal)` produces normals for lighting a perlin heightmap.
+#[derive(PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct TerrainNormal {
+    /// How much the height is scaled by when it is displayed.
+    /// Defaults to 1.
+    pub height_scale: f32,
+}
+
+impl Default for TerrainNormal {
+    fn default() -> Self {
+        Self { height_scale: 1.0 }
+    }
+}
+
+impl NoiseFunction<WithGradient<f32, Vec2>> for TerrainNormal {
+    type Output = Vec3;
+
+    #[inline]
+    fn evaluate(
+        &self,
+        input: WithGradient<f32, Vec2>,
+        _seeds: &mut crate::rng::NoiseRng,
+    ) -> Self::Output {
+        (-input.gradient * self.height_scale)
+            .extend(1.0)
+            .normalize()
+    }
+}
+
+/// A [`NoiseFunction`] that takes a [`WithGradient`] of a 2d field and produces the dot product of its gradient with [`direction`](GradientDot::direction).
+/// This is greatest on slopes rising toward `direction`, which is useful for directional masks, like highlighting slopes that face a light.
+#[derive(PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct GradientDot {
+    /// The direction to compare the gradient to.
+    /// This is usually normalized.
+    pub direction: Vec2,
+}
+
+impl Default for GradientDot {
+    fn default() -> Self {
+        Self { direction: Vec2::X }
+    }
+}
+
+impl NoiseFunction<WithGradient<f32, Vec2>> for GradientDot {
+    type Output = f32;
+
+    #[inline]
+    fn evaluate(
+        &self,
+        input: WithGradient<f32, Vec2>,
+        _seeds: &mut crate::rng::NoiseRng,
+    ) -> Self::Output {
+        input.gradient.dot(self.direction)
+    }
+}
+
+/// A [`NoiseFunction`] that takes a unorm `f32` and smoothly thresholds it so that [`amount`](Coverage::amount) controls how much of the field is filled.
+/// This is the standard coverage control for clouds: an `amount` of 0 produces all zeros, 1 produces all ones, and values in between fill the highest parts of the field first.
+///
+/// The threshold is centered on `1 - amount` and softened by a smoothstep [`band`](Coverage::band) on either side.
+/// The center is stretched slightly so that the band never cuts into the field at the extremes of `amount`.
+#[derive(PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Coverage {
+    /// How much of the field is covered, from 0 to 1.
+    /// Defaults to 0.5.
+    pub amount: f32,
+    /// Half the width of the soft edge.
+    /// Defaults to 0.1.
+    pub band: f32,
+}
+
+impl Default for Coverage {
+    fn default() -> Self {
+        Self {
+            amount: 0.5,
+            band: 0.1,
+        }
+    }
+}
+
+impl Coverage {
+    #[inline]
+    fn cover(&self, input: f32) -> f32 {
+        let center = (1.0 - self.amount) * (1.0 + 2.0 * self.band) - self.band;
+        let t = (input - center + self.band) / (2.0 * self.band);
+        Smoothstep.sample_clamped(t)
+    }
+}
+
+impl NoiseFunction<f32> for Coverage {
+    type Output = f32;
+
+    #[inline]
+    fn evaluate(&self, input: f32, _seeds: &mut crate::rng::NoiseRng) -> Self::Output {
+        self.cover(input)
+    }
+}
+
+impl NoiseRange for Coverage {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        input.map_monotonic(|x| self.cover(x))
+    }
+}
+
+/// A [`NoiseFunction`] that takes a [`WithGradient`] and produces an approximate signed distance to where the value crosses [`ApproxSdf::iso`].
+/// This is the standard first-order normalization, `(value - iso) / |gradient|`.
+/// It is much closer to a true signed distance field than the raw value, which reduces stepping artifacts when raymarching or meshing the surface.
+///
+/// Where the gradient is (nearly) zero, it is treated as having length [`f32::EPSILON`] to avoid dividing by zero.
+#[derive(Default, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct ApproxSdf {
+    /// The value of the noise that forms the surface.
+    pub iso: f32,
+}
+
+impl<G: NormedVectorSpace> NoiseFunction<WithGradient<f32, G>> for ApproxSdf {
+    type Output = f32;
+
+    #[inline]
+    fn evaluate(
+        &self,
+        input: WithGradient<f32, G>,
+        _seeds: &mut crate::rng::NoiseRng,
+    ) -> Self::Output {
+        (input.value - self.iso) / input.gradient.norm().max(f32::EPSILON)
+    }
+}
+
+/// A [`NoiseFunction`] that takes a [`WithHessian`] and produces its Laplacian, the sum of its unmixed second partial derivatives.
+/// This measures curvature: it is positive in valleys and negative on ridges.
+/// See [`MixCellValuesHessian`](crate::cell_noise::MixCellValuesHessian) for producing a [`WithHessian`].
+#[derive(Default, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Laplacian;
+
+impl<G> NoiseFunction<WithHessian<f32, G, Mat2>> for Laplacian {
+    type Output = f32;
+
+    #[inline]
+    fn evaluate(
+        &self,
+        input: WithHessian<f32, G, Mat2>,
+        _seeds: &mut crate::rng::NoiseRng,
+    ) -> Self::Output {
+        input.hessian.x_axis.x + input.hessian.y_axis.y
+    }
+}
+
+/// A [`NoiseFunction`] that takes the components of a vector field, each as a [`WithGradient`], and produces its divergence, the sum of each component's partial derivative along its own axis.
+/// This measures how much the field flows outward from a point: it is positive at sources, negative at sinks, and zero for incompressible flow, like a curl field.
+///
+/// This is implemented for a pair of 2d components, as in `(dvx, dvy)` or `[dvx, dvy]`, and an array of 3 components in 3d.
+/// For example, [`PerChannel`](crate::misc_noise::PerChannel) over a differentiable noise produces an array of components.
+#[derive(Default, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Divergence;
+
+impl NoiseFunction<(WithGradient<f32, Vec2>, WithGradient<f32, Vec2>)> for Divergence {
+    type Output = f32;
+
+    #[inline]
+    fn evaluate(
+        &self,
+        input: (WithGradient<f32, Vec2>, WithGradient<f32, Vec2>),
+        _seeds: &mut crate::rng::NoiseRng,
+    ) -> Self::Output {
+        input.0.gradient.x + input.1.gradient.y
+    }
+}
+
+impl NoiseFunction<[WithGradient<f32, Vec2>; 2]> for Divergence {
+    type Output = f32;
+
+    #[inline]
+    fn evaluate(
+        &self,
+        input: [WithGradient<f32, Vec2>; 2],
+        _seeds: &mut crate::rng::NoiseRng,
+    ) -> Self::Output {
+        input[0].gradient.x + input[1].gradient.y
+    }
+}
+
+impl NoiseFunction<[WithGradient<f32, Vec3>; 3]> for Divergence {
+    type Output = f32;
+
+    #[inline]
+    fn evaluate(
+        &self,
+        input: [WithGradient<f32, Vec3>; 3],
+        _seeds: &mut crate::rng::NoiseRng,
+    ) -> Self::Output {
+        input[0].gradient.x + input[1].gradient.y + input[2].gradient.z
+    }
+}
+
+/// A [`NoiseFunction`] that buckets a UNorm input into `LEVELS` integer levels, `0..LEVELS`.
+/// An input of exactly 1.0 maps to `LEVELS - 1`, and inputs outside of (0, 1) are clamped.
+/// This is handy for generating tile indices or terrain types directly.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use noiz::math_noise::Quantize;
+/// let noise = Noise::<(common_noise::Perlin, SNormToUNorm, Quantize<4>)>::default();
+/// let tile = noise.sample_for::<u8>(Vec2::new(1.0, -1.0));
+/// ```
+#[derive(Default, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Quantize<const LEVELS: u8>;
+
+impl<const LEVELS: u8> NoiseFunction<f32> for Quantize<LEVELS> {
+    type Output = u8;
+
+    #[inline]
+    fn evaluate(&self, input: f32, _seeds: &mut crate::rng::NoiseRng) -> Self::Output {
+        ((input.clamp(0.0, 1.0) * LEVELS as f32) as u8).min(LEVELS.saturating_sub(1))
+    }
+}
+
+/// A [`NoiseFunction`] that maps a UNorm input into a lookup [`table`](Lut::table), interpolating linearly between entries.
+/// The first entry is at 0.0 and the last at 1.0, with the rest evenly spaced between them, and inputs outside of 0..=1 are clamped.
+/// This is a cheap, artist friendly alternative to fitting a [`Curve`], like for stylized ramps.
+///
+/// An empty table always produces 0.0. See [`LutArray`] for a version that owns its table.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use noiz::math_noise::Lut;
+/// let ramp = [0.0, 0.1, 0.8, 1.0];
+/// let noise = Noise::from((common_noise::Value::default(), Lut { table: &ramp }));
+/// let value = noise.sample_for::<f32>(Vec2::new(1.0, -1.0));
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Lut<'a> {
+    /// The values to interpolate between.
+    pub table: &'a [f32],
+}
+
+/// A [`NoiseFunction`] that works just like [`Lut`], but owns its table of `N` entries.
+/// This is useful when the table can't be borrowed, like when storing the noise.
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct LutArray<const N: usize> {
+    /// The values to interpolate between.
+    pub table: [f32; N],
+}
+
+/// Samples the `table` at `t` for [`Lut`] and [`LutArray`].
+#[inline]
+fn sample_lut(table: &[f32], t: f32) -> f32 {
+    let Some(last) = table.len().checked_sub(1) else {
+        return 0.0;
+    };
+    if last == 0 {
+        return table[0];
+    }
+    let position = t.clamp(0.0, 1.0) * last as f32;
+    let index = (position as usize).min(last - 1);
+    let lerp = position - index as f32;
+    table[index] + (table[index + 1] - table[index]) * lerp
+}
+
+/// Computes the [`OutputRange`] of [`Lut`] and [`LutArray`], the range of their `table`.
+#[inline]
+fn lut_range(table: &[f32]) -> OutputRange {
+    if table.is_empty() {
+        return OutputRange { min: 0.0, max: 0.0 };
+    }
+    OutputRange {
+        min: table.iter().copied().fold(f32::INFINITY, f32::min),
+        max: table.iter().copied().fold(f32::NEG_INFINITY, f32::max),
+    }
+}
+
+impl NoiseFunction<f32> for Lut<'_> {
+    type Output = f32;
+
+    #[inline]
+    fn evaluate(&self, input: f32, _seeds: &mut crate::rng::NoiseRng) -> Self::Output {
+        sample_lut(self.table, input)
+    }
+}
+
+impl NoiseRange for Lut<'_> {
+    #[inline]
+    fn output_range(&self, _input: OutputRange) -> OutputRange {
+        lut_range(self.table)
+    }
+}
+
+impl<const N: usize> NoiseFunction<f32> for LutArray<N> {
+    type Output = f32;
+
+    #[inline]
+    fn evaluate(&self, input: f32, _seeds: &mut crate::rng::NoiseRng) -> Self::Output {
+        sample_lut(&self.table, input)
+    }
+}
+
+impl<const N: usize> NoiseRange for LutArray<N> {
+    #[inline]
+    fn output_range(&self, _input: OutputRange) -> OutputRange {
+        lut_range(&self.table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rng::NoiseRng;
+
+    #[test]
+    fn test_quantize_boundaries() {
+        let quantize = Quantize::<4>;
+        let bucket = |input: f32| quantize.evaluate(input, &mut NoiseRng(0));
+        assert_eq!(bucket(0.0), 0);
+        assert_eq!(bucket(0.2499), 0);
+        assert_eq!(bucket(0.25), 1);
+        assert_eq!(bucket(0.4999), 1);
+        assert_eq!(bucket(0.5), 2);
+        assert_eq!(bucket(0.75), 3);
+        assert_eq!(bucket(0.9999), 3);
+        assert_eq!(bucket(1.0), 3);
+        assert_eq!(bucket(-0.5), 0);
+        assert_eq!(bucket(1.5), 3);
+
+        let single = Quantize::<1>;
+        assert_eq!(single.evaluate(1.0, &mut NoiseRng(0)), 0);
+    }
+
+    #[test]
+    fn test_terrain_normal() {
+        let normal = |gradient: Vec2, height_scale: f32| {
+            TerrainNormal { height_scale }.evaluate(
+                WithGradient {
+                    value: 0.5,
+                    gradient,
+                },
+                &mut NoiseRng(0),
+            )
+        };
+        assert!((normal(Vec2::ZERO, 1.0) - Vec3::Z).length() < 1e-6);
+        // Rising toward +x, so the surface faces back toward -x.
+        let tilted = normal(Vec2::new(1.0, 0.0), 1.0);
+        assert!((tilted - Vec3::new(-1.0, 0.0, 1.0).normalize()).length() < 1e-6);
+        let steep = normal(Vec2::new(0.0, -0.5), 4.0);
+        assert!((steep - Vec3::new(0.0, 2.0, 1.0).normalize()).length() < 1e-6);
+        assert!((steep.length() - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_gradient_dot() {
+        let direction = Vec2::new(1.0, 2.0).normalize();
+        let dot = GradientDot { direction };
+        let mut best_angle = 0.0;
+        let mut best = f32::NEG_INFINITY;
+        for step in 0..360 {
+            let angle = (step as f32).to_radians();
+            let gradient = Vec2::from_angle(angle) * 0.8;
+            let value = dot.evaluate(
+                WithGradient {
+                    value: 0.0,
+                    gradient,
+                },
+                &mut NoiseRng(0),
+            );
+            if value > best {
+                best = value;
+                best_angle = angle;
+            }
+        }
+        assert!((Vec2::from_angle(best_angle) - direction).length() < 0.02);
+        assert!((best - 0.8).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_noise_curve_componentwise() {
+        use crate::misc_noise::RemapCurve;
+        use bevy_math::curve::{FunctionCurve, Interval};
+
+        let curve = FunctionCurve::new(Interval::UNIT, |t: f32| t * t);
+        let clamped = NoiseCurveClamped(curve.clone());
+        let unclamped = NoiseCurve(curve.clone());
+        let scalar_clamped = RemapCurve::<_, f32, true>::from(curve.clone());
+        let scalar_unclamped = RemapCurve::<_, f32, false>::from(curve);
+        let rng = &mut NoiseRng(0);
+
+        let input = Vec4::new(-0.5, 0.25, 0.75, 1.5);
+        let clamped_out = clamped.evaluate(input, rng);
+        let unclamped_out = unclamped.evaluate(input, rng);
+        for axis in 0..4 {
+            assert_eq!(clamped_out[axis], scalar_clamped.evaluate(input[axis], rng));
+            assert_eq!(
+                unclamped_out[axis],
+                scalar_unclamped.evaluate(input[axis], rng)
+            );
+        }
+        assert_eq!(clamped_out.x, 0.0);
+        assert_eq!(unclamped_out.w, 2.25);
+
+        let input = Vec3::new(0.1, 0.5, 0.9);
+        let smooth = NoiseCurveClamped(Smoothstep).evaluate(input, rng);
+        let expected = input.map(|v| Smoothstep.sample_clamped(v));
+        assert_eq!(smooth, expected);
+    }
+
+    #[test]
+    fn test_coverage_extremes() {
+        let mut seeds = NoiseRng(0);
+        let full = Coverage {
+            amount: 1.0,
+            ..Default::default()
+        };
+        let empty = Coverage {
+            amount: 0.0,
+            ..Default::default()
+        };
+        for i in 0..=100 {
+            let input = i as f32 / 100.0;
+            assert_eq!(full.evaluate(input, &mut seeds), 1.0);
+            assert_eq!(empty.evaluate(input, &mut seeds), 0.0);
+        }
+        let half = Coverage::default();
+        assert!(half.evaluate(0.2, &mut seeds) < half.evaluate(0.8, &mut seeds));
+    }
+
+    #[test]
+    fn test_contrast() {
+        let mut seeds = NoiseRng(0);
+        for i in -10..=10 {
+            let value = i as f32 / 10.0;
+            assert_eq!(Contrast(1.0).evaluate(value, &mut seeds), value);
+        }
+        assert_eq!(Contrast(2.0).evaluate(0.25, &mut seeds), 0.5);
+        assert_eq!(Contrast(2.0).evaluate(0.75, &mut seeds), 1.0);
+        assert_eq!(Contrast(2.0).evaluate(-0.75, &mut seeds), -1.0);
+        let vector = Contrast(4.0).evaluate(Vec2::new(0.1, -0.5), &mut seeds);
+        assert!((vector - Vec2::new(0.4, -1.0)).length() < 1e-6);
+    }
+
+    #[test]
+    fn test_to_polar_jacobian() {
+        let step = 1e-3;
+        let mut seeds = NoiseRng(0);
+        for x in -5..=5 {
+            for y in 1..=5 {
+                // Stay away from the origin and the negative x axis.
+                let point = Vec2::new(x as f32 * 0.7, y as f32 * 0.6);
+                let polar = ToPolar::<true>.evaluate(point, &mut seeds);
+                assert_eq!(polar.value, ToPolar::<false>.evaluate(point, &mut seeds));
+                let d_dx = (ToPolar::<false>.evaluate(point + Vec2::X * step, &mut seeds)
+                    - ToPolar::<false>.evaluate(point - Vec2::X * step, &mut seeds))
+                    / (2.0 * step);
+                let d_dy = (ToPolar::<false>.evaluate(point + Vec2::Y * step, &mut seeds)
+                    - ToPolar::<false>.evaluate(point - Vec2::Y * step, &mut seeds))
+                    / (2.0 * step);
+                assert!(d_dx.distance(polar.gradient[0]) < 1e-2, "{point:?}");
+                assert!(d_dy.distance(polar.gradient[1]) < 1e-2, "{point:?}");
+            }
+        }
+        let origin = ToPolar::<true>.evaluate(Vec2::ZERO, &mut seeds);
+        assert_eq!(origin.value.x, 0.0);
+        assert!(origin.gradient.iter().all(|column| *column == Vec2::ZERO));
+    }
+
+    #[test]
+    fn test_gamma() {
+        let mut seeds = NoiseRng(0);
+        for value in [0.0, 0.1, 0.5, 0.9, 1.0] {
+            assert!((Gamma(1.0).evaluate(value, &mut seeds) - value).abs() < 1e-6);
+            let corrected: f32 = Gamma(2.2).evaluate(value, &mut seeds);
+            assert!(corrected >= value);
+            assert!((0.0..=1.0).contains(&corrected));
+        }
+        assert!(Gamma(2.2).evaluate(0.5, &mut seeds) > 0.7);
+        assert_eq!(Gamma(2.2).evaluate(1.5, &mut seeds), 1.0);
+        let color = Gamma(2.2).evaluate(Vec3::new(0.0, 0.5, 1.0), &mut seeds);
+        assert!(color.x == 0.0 && color.y > 0.7 && color.z == 1.0);
+    }
+
+    #[test]
+    fn test_divergence() {
+        use crate::{
+            Noise, Sampleable,
+            cell_noise::MixCellValuesHessian,
+            cells::OrthoGrid,
+            curves::DoubleSmoothstep,
+            rng::{Random, SNorm},
+        };
+        type Potential = MixCellValuesHessian<OrthoGrid, DoubleSmoothstep, Random<SNorm, f32>>;
+
+        // The curl of a potential, (dp/dy, -dp/dx), is incompressible.
+        let curl = Noise::from((
+            Potential::default(),
+            |p: WithHessian<f32, Vec2, Mat2>, _: &mut NoiseRng| {
+                (
+                    WithGradient {
+                        value: p.gradient.y,
+                        gradient: Vec2::new(p.hessian.x_axis.y, p.hessian.y_axis.y),
+                    },
+                    WithGradient {
+                        value: -p.gradient.x,
+                        gradient: -Vec2::new(p.hessian.x_axis.x, p.hessian.y_axis.x),
+                    },
+                )
+            },
+            Divergence,
+        ));
+        // The divergence of the gradient of a potential is its Laplacian.
+        let gradient = Noise::from((
+            Potential::default(),
+            |p: WithHessian<f32, Vec2, Mat2>, _: &mut NoiseRng| {
+                [
+                    WithGradient {
+                        value: p.gradient.x,
+                        gradient: p.hessian.x_axis,
+                    },
+                    WithGradient {
+                        value: p.gradient.y,
+                        gradient: p.hessian.y_axis,
+                    },
+                ]
+            },
+            Divergence,
+        ));
+        let laplacian = Noise::<(Potential, Laplacian)>::default();
+        for x in -10..=10 {
+            for y in -10..=10 {
+                let point = Vec2::new(x as f32, y as f32) * 0.37;
+                let divergence = curl.sample_for::<f32>(point);
+                assert!(
+                    divergence.abs() < 1e-4,
+                    "Curl diverges by {divergence} at {point:?}"
+                );
+                let expected = laplacian.sample_for::<f32>(point);
+                let actual = gradient.sample_for::<f32>(point);
+                assert!((actual - expected).abs() < 1e-4);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lut() {
+        let mut seeds = NoiseRng(0);
+        let identity = Lut { table: &[0.0, 1.0] };
+        for value in [0.0, 0.1, 0.5, 0.75, 1.0] {
+            assert!((identity.evaluate(value, &mut seeds) - value).abs() < 1e-6);
+        }
+        assert_eq!(identity.evaluate(-0.5, &mut seeds), 0.0);
+        assert_eq!(identity.evaluate(1.5, &mut seeds), 1.0);
+
+        let ramp = LutArray {
+            table: [0.0, 1.0, 0.5],
+        };
+        assert_eq!(ramp.evaluate(0.5, &mut seeds), 1.0);
+        assert!((ramp.evaluate(0.25, &mut seeds) - 0.5).abs() < 1e-6);
+        assert!((ramp.evaluate(0.75, &mut seeds) - 0.75).abs() < 1e-6);
+        assert_eq!(ramp.evaluate(1.0, &mut seeds), 0.5);
+
+        assert_eq!(LutArray { table: [0.3] }.evaluate(0.7, &mut seeds), 0.3);
+        assert_eq!(Lut { table: &[] }.evaluate(0.7, &mut seeds), 0.0);
+    }
+
+    #[test]
+    fn test_clamp_gradient() {
+        let clamp = ClampGradient { max: 2.0 };
+        let mut seeds = NoiseRng(0);
+        let small = clamp.evaluate(
+            WithGradient {
+                value: 0.3,
+                gradient: Vec2::new(1.0, -1.0),
+            },
+            &mut seeds,
+        );
+        assert_eq!(small.value, 0.3);
+        assert_eq!(small.gradient, Vec2::new(1.0, -1.0));
+
+        let large = clamp.evaluate(
+            WithGradient {
+                value: 0.3,
+                gradient: Vec3::new(3.0, 0.0, -4.0),
+            },
+            &mut seeds,
+        );
+        assert_eq!(large.value, 0.3);
+        assert!((large.gradient.length() - 2.0).abs() < 1e-6);
+        assert!(
+            large
+                .gradient
+                .normalize()
+                .abs_diff_eq(Vec3::new(0.6, 0.0, -0.8), 1e-6)
+        );
+    }
+
+    #[test]
+    fn test_terrace_diff_gradient() {
+        let terrace = TerraceDiff {
+            steps: 4,
+            sharpness: 2.0,
+        };
+        // A smooth 1d field to terrace.
+        let field = |x: f32| 0.5 + 0.4 * bevy_math::ops::sin(x);
+        let terraced = |x: f32| terrace.evaluate(field(x), &mut NoiseRng(0));
+        const STEP: f32 = 1e-3;
+        for i in 0..600 {
+            let x = i as f32 * 0.01;
+            let input = WithGradient {
+                value: field(x),
+                gradient: 0.4 * bevy_math::ops::cos(x),
+            };
+            let analytic = terrace.evaluate(input, &mut NoiseRng(0)).gradient;
+            let approx = (terraced(x + STEP) - terraced(x - STEP)) / (2.0 * STEP);
+            assert!(
+                (analytic - approx).abs() < 1e-2,
+                "gradient {analytic} does not match slope {approx} at {x}"
+            );
+        }
+
+        // Plateaus are flat, and the middle of each riser has a slope of `1 + sharpness`.
+        let (value, derivative) = terrace.terrace(0.25);
+        assert!((value - 0.25).abs() < 1e-6 && derivative.abs() < 1e-6);
+        let (value, derivative) = terrace.terrace(0.375);
+        assert!((value - 0.375).abs() < 1e-6 && (derivative - 3.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_anti_alias() {
+        let anti_alias = AntiAlias { filter_width: 0.5 };
+        let mut seeds = NoiseRng(0);
+        let flat = anti_alias.evaluate(
+            WithGradient {
+                value: 0.7,
+                gradient: Vec2::ZERO,
+            },
+            &mut seeds,
+        );
+        assert_eq!(flat.value, 0.7);
+
+        let mut previous = 0.7;
+        for slope in [0.5, 2.0, 8.0, 32.0] {
+            let steep = anti_alias.evaluate(
+                WithGradient {
+                    value: 0.7,
+                    gradient: Vec2::new(slope, 0.0),
+                },
+                &mut seeds,
+            );
+            assert!(
+                steep.value < previous && steep.value > 0.0,
+                "{} at slope {slope}",
+                steep.value
+            );
+            previous = steep.value;
+        }
+        assert!(previous < 0.01, "very steep regions should vanish");
+    }
+}
@@ -5,9 +5,20 @@ use core::{
     ops::{Add, Mul},
 };
 
-use bevy_math::{Curve, HasTangent, Vec2, Vec3, Vec3A, Vec4, curve::derivatives::SampleDerivative};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
-use crate::{NoiseFunction, cells::WithGradient, rng::NoiseRng};
+use bevy_math::{
+    Curve, HasTangent, Vec2, Vec3, Vec3A, Vec4, VectorSpace, curve::derivatives::SampleDerivative,
+};
+
+use crate::{
+    NoiseFunction, NoiseRange, OutputRange, SampleableFor,
+    cells::WithGradient,
+    curves::{CubicSMin, SmoothMin, Smoothstep},
+    lengths::{EuclideanLength, LengthFunction},
+    rng::NoiseRng,
+};
 
 /// A [`NoiseFunction`] that wraps an inner [`NoiseFunction`] `N` and produces values of the same type as the input with random elements sourced from `N`.
 ///
@@ -86,6 +97,200 @@ impl<N: NoiseFunction<Vec4, Output = f32>> NoiseFunction<Vec4> for RandomElement
     }
 }
 
+/// A [`NoiseFunction`] that layers [`RandomElements`] of a scalar [`NoiseFunction`] `N` into a fractal vector, for rich domain warping in one node.
+/// This produces a displacement of the same type as the input, which is usually passed to [`Offset`].
+///
+/// Each of the [`octaves`](FbmWarp::octaves) samples at a frequency [`lacunarity`](FbmWarp::lacunarity) times the last with an amplitude [`persistence`](FbmWarp::persistence) times the last.
+/// The sum is normalized by the total amplitude and scaled by [`strength`](FbmWarp::strength), so a single octave is just [`RandomElements`] times `strength`.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use noiz::misc_noise::{FbmWarp, Offset};
+/// let noise = Noise::<(Offset<FbmWarp<common_noise::Perlin>>, common_noise::Perlin)>::default();
+/// let value = noise.sample_for::<f32>(Vec2::new(1.0, -1.0));
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct FbmWarp<N> {
+    /// The [`RandomElements`] sampled for each octave.
+    pub elements: RandomElements<N>,
+    /// The number of octaves.
+    /// Defaults to 3.
+    pub octaves: u32,
+    /// How much the frequency increases each octave.
+    /// Defaults to 2.
+    pub lacunarity: f32,
+    /// How much the amplitude changes each octave.
+    /// Defaults to 0.5.
+    pub persistence: f32,
+    /// The scale of the final displacement.
+    /// Defaults to 1.
+    pub strength: f32,
+}
+
+impl<N: Default> Default for FbmWarp<N> {
+    fn default() -> Self {
+        Self {
+            elements: RandomElements::default(),
+            octaves: 3,
+            lacunarity: 2.0,
+            persistence: 0.5,
+            strength: 1.0,
+        }
+    }
+}
+
+impl<I: VectorSpace, N> NoiseFunction<I> for FbmWarp<N>
+where
+    RandomElements<N>: NoiseFunction<I, Output = I>,
+{
+    type Output = I;
+
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        let mut sum = I::ZERO;
+        let mut total_amplitude = 0.0;
+        let mut amplitude = 1.0;
+        let mut frequency = 1.0;
+        for _ in 0..self.octaves {
+            sum = sum + self.elements.evaluate(input * frequency, seeds) * amplitude;
+            total_amplitude += amplitude;
+            amplitude *= self.persistence;
+            frequency *= self.lacunarity;
+        }
+        if total_amplitude == 0.0 {
+            return I::ZERO;
+        }
+        sum * (self.strength / total_amplitude)
+    }
+}
+
+/// A [`NoiseFunction`] that wraps an inner vector-valued [`NoiseFunction`] `N` and produces its output directly as random elements.
+/// This is an alternative to [`RandomElements`] for domain warping.
+///
+/// Where [`RandomElements`] re-seeds between each component, making each axis of the displacement an independent noise,
+/// this takes every component from a single sample of `N`.
+/// Since the components come from the same cells, they tend to agree with each other,
+/// giving warps a more directional, sheared swirl instead of the uncorrelated turbulence of [`RandomElements`].
+/// This also matches how some other noise libraries warp domains.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use noiz::misc_noise::{CoherentElements, Offset};
+/// use noiz::cell_noise::MixCellValuesForDomain;
+/// let noise = Noise::<(
+///     Offset<CoherentElements<MixCellValuesForDomain<OrthoGrid, Smoothstep, SNorm>>>,
+///     common_noise::Perlin,
+/// )>::default();
+/// let value = noise.sample_for::<f32>(Vec2::new(1.0, -1.0));
+/// ```
+#[derive(Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct CoherentElements<N>(pub N);
+
+impl<I, N: NoiseFunction<I, Output = I>> NoiseFunction<I> for CoherentElements<N> {
+    type Output = I;
+
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        let result = self.0.evaluate(input, seeds);
+        seeds.re_seed();
+        result
+    }
+}
+
+/// A [`NoiseFunction`] that evaluates each of `K` inner [`NoiseFunction`]s `N`, producing an array of their outputs.
+/// The seed is changed between each one, just like [`RandomElements`], so each channel is independent.
+///
+/// This is useful for generating multiple channels of data, like colors, in one pass:
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use noiz::misc_noise::PerChannel;
+/// let noise = Noise::<PerChannel<[common_noise::Perlin; 3]>>::default();
+/// let [r, g, b] = noise.sample_for::<[f32; 3]>(Vec2::new(1.0, -1.0));
+/// ```
+#[derive(Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct PerChannel<T>(pub T);
+
+impl<I: Copy, N: NoiseFunction<I>, const K: usize> NoiseFunction<I> for PerChannel<[N; K]> {
+    type Output = [N::Output; K];
+
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        core::array::from_fn(|channel| {
+            let value = self.0[channel].evaluate(input, seeds);
+            seeds.re_seed();
+            value
+        })
+    }
+}
+
+/// A [`NoiseFunction`] that evaluates a scalar [`NoiseFunction`] `N` three times with different seeds to produce a [`Vec3`] color.
+/// Each channel is sampled at the input scaled by its element of [`frequencies`](RgbNoise::frequencies), so channels can also vary at different scales.
+///
+/// This is a convenient way to make procedural color textures in one call.
+/// Like [`PerChannel`], the seed is changed between each channel, so they are independent.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use noiz::misc_noise::RgbNoise;
+/// let noise = Noise::<RgbNoise<common_noise::Value>>::default();
+/// let color = noise.sample_for::<Vec3>(Vec2::new(1.0, -1.0));
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct RgbNoise<N> {
+    /// The scalar [`NoiseFunction`] sampled for each channel.
+    pub noise: N,
+    /// The amount to scale the input by for the red, green, and blue channels respectively.
+    pub frequencies: Vec3,
+}
+
+impl<N: Default> Default for RgbNoise<N> {
+    fn default() -> Self {
+        Self {
+            noise: N::default(),
+            frequencies: Vec3::ONE,
+        }
+    }
+}
+
+impl<I: VectorSpace, N: NoiseFunction<I, Output = f32>> NoiseFunction<I> for RgbNoise<N> {
+    type Output = Vec3;
+
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        let r = self.noise.evaluate(input * self.frequencies.x, seeds);
+        seeds.re_seed();
+        let g = self.noise.evaluate(input * self.frequencies.y, seeds);
+        seeds.re_seed();
+        let b = self.noise.evaluate(input * self.frequencies.z, seeds);
+        seeds.re_seed();
+        Vec3::new(r, g, b)
+    }
+}
+
+impl<N: NoiseRange> NoiseRange for RgbNoise<N> {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        self.noise.output_range(input)
+    }
+}
+
 /// A [`NoiseFunction`] that pushes its input by some offset calculated by an inner [`NoiseFunction`] `N`.
 ///
 /// This is most commonly used for domain warping:
@@ -129,93 +334,1033 @@ impl<I: Add<N::Output> + Copy, N: NoiseFunction<I, Output: Mul<f32, Output = N::
     }
 }
 
-/// A [`NoiseFunction`] that scales/multiplies its input by some factor `T`.
+impl<N> NoiseRange for Offset<N> {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        input
+    }
+}
+
+/// A [`NoiseFunction`] that repeatedly warps its input by an inner [`NoiseFunction`] `W`, feeding each displaced location back into `W`.
+/// Each iteration offsets the original input by `W` sampled at the previous iteration's result, so `iterations` of 1 is the same as [`Offset`].
+/// This is the "fbm of fbm" style of domain warping that produces a cloudy, organic look.
+/// Between iterations, the seed is changed, so each iteration warps differently.
 ///
-/// If you want this to be [`NoiseFunction`] based, see [`Masked`].
-#[derive(Clone, Copy, PartialEq, Eq, Default)]
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use noiz::misc_noise::{RandomElements, IteratedWarp};
+/// let noise = Noise::<(
+///     IteratedWarp<RandomElements<common_noise::Fbm<common_noise::Perlin>>>,
+///     common_noise::Fbm<common_noise::Perlin>,
+/// )>::default();
+/// let value = noise.sample_for::<f32>(Vec2::new(1.0, -1.0));
+/// ```
+#[derive(Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub struct Scaled<T>(pub T);
+pub struct IteratedWarp<W> {
+    /// The inner [`NoiseFunction`].
+    pub warper: W,
+    /// How many times to warp.
+    /// At 0, the input is passed through unchanged.
+    pub iterations: u32,
+    /// The warp's strength/multiplier.
+    pub strength: f32,
+}
 
-impl<I: Mul<T>, T: Copy> NoiseFunction<I> for Scaled<T> {
-    type Output = I::Output;
+impl<W: Default> Default for IteratedWarp<W> {
+    fn default() -> Self {
+        Self {
+            warper: W::default(),
+            iterations: 2,
+            strength: 1.0,
+        }
+    }
+}
+
+impl<
+    I: Add<W::Output, Output = I> + Copy,
+    W: NoiseFunction<I, Output: Mul<f32, Output = W::Output>>,
+> NoiseFunction<I> for IteratedWarp<W>
+{
+    type Output = I;
+
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        let mut warped = input;
+        for iteration in 0..self.iterations {
+            if iteration > 0 {
+                seeds.re_seed();
+            }
+            warped = input + self.warper.evaluate(warped, seeds) * self.strength;
+        }
+        warped
+    }
+}
+
+impl<W> NoiseRange for IteratedWarp<W> {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        input
+    }
+}
+
+/// A [`NoiseFunction`] that warps its input by a differentiable [`NoiseFunction`] `W` before sampling a differentiable [`NoiseFunction`] `N`.
+/// This is like combining [`Offset`] with `N`, but the gradient is corrected by the Jacobian of the warp via the chain rule.
+/// That makes it possible to compute correct normals for domain warped noise.
+///
+/// `W` must produce a [`WithGradient`] whose gradient holds the derivative of the warp along each input axis,
+/// and `N` must produce a [`WithGradient`] with a vector gradient.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use noiz::{misc_noise::WarpedDiff, cell_noise::MixCellValuesForDomain};
+/// let noise = Noise::<WarpedDiff<
+///     MixCellValuesForDomain<OrthoGrid, Smoothstep, SNorm, true>,
+///     common_noise::PerlinWithDerivative,
+/// >>::default();
+/// let value = noise.sample_for::<WithGradient<f32, Vec2>>(Vec2::new(1.0, -1.0));
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct WarpedDiff<W, N> {
+    /// The [`NoiseFunction`] doing the warping.
+    pub warper: W,
+    /// The [`NoiseFunction`] sampled at the warped location.
+    pub noise: N,
+    /// The warp's strength/multiplier.
+    pub warp_strength: f32,
+}
+
+impl<W: Default, N: Default> Default for WarpedDiff<W, N> {
+    fn default() -> Self {
+        Self {
+            warper: W::default(),
+            noise: N::default(),
+            warp_strength: 1.0,
+        }
+    }
+}
+
+impl<
+    W: NoiseFunction<Vec2, Output = WithGradient<Vec2, [Vec2; 2]>>,
+    N: NoiseFunction<Vec2, Output = WithGradient<f32, Vec2>>,
+> NoiseFunction<Vec2> for WarpedDiff<W, N>
+{
+    type Output = WithGradient<f32, Vec2>;
+
+    #[inline]
+    fn evaluate(&self, input: Vec2, seeds: &mut NoiseRng) -> Self::Output {
+        let warp = self.warper.evaluate(input, seeds);
+        let warped = input + warp.value * self.warp_strength;
+        // The columns of the warp's Jacobian.
+        let d_dx = Vec2::X + warp.gradient[0] * self.warp_strength;
+        let d_dy = Vec2::Y + warp.gradient[1] * self.warp_strength;
+
+        let sample = self.noise.evaluate(warped, seeds);
+        WithGradient {
+            value: sample.value,
+            gradient: Vec2::new(sample.gradient.dot(d_dx), sample.gradient.dot(d_dy)),
+        }
+    }
+}
+
+/// A [`NoiseFunction`] that warps its 2d input without bunching or stretching it, producing the warped location.
+/// The displacement is the curl of a differentiable scalar potential [`NoiseFunction`] `N`, its gradient rotated by 90 degrees, times [`strength`](IncompressibleWarp::strength).
+///
+/// Because the curl of a potential is divergence free, this preserves area to first order in the strength, so features keep their size after warping.
+/// The area still changes by an amount proportional to the square of the strength, so small strengths preserve it best.
+/// Ordinary domain warps, like [`Offset`], instead compress features in some places and stretch them in others.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use noiz::misc_noise::IncompressibleWarp;
+/// let noise = Noise::<(IncompressibleWarp<common_noise::PerlinWithDerivative>, common_noise::Perlin)>::default();
+/// let value = noise.sample_for::<f32>(Vec2::new(1.0, -1.0));
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct IncompressibleWarp<N> {
+    /// The differentiable [`NoiseFunction`] whose curl displaces the input.
+    pub potential: N,
+    /// How far the input is displaced per unit of the potential's curl.
+    pub strength: f32,
+}
+
+impl<N: Default> Default for IncompressibleWarp<N> {
+    fn default() -> Self {
+        Self {
+            potential: N::default(),
+            strength: 0.1,
+        }
+    }
+}
+
+impl<N: NoiseFunction<Vec2, Output = WithGradient<f32, Vec2>>> NoiseFunction<Vec2>
+    for IncompressibleWarp<N>
+{
+    type Output = Vec2;
+
+    #[inline]
+    fn evaluate(&self, input: Vec2, seeds: &mut NoiseRng) -> Self::Output {
+        let gradient = self.potential.evaluate(input, seeds).gradient;
+        let curl = Vec2::new(gradient.y, -gradient.x);
+        input + curl * self.strength
+    }
+}
+
+/// A [`NoiseFunction`] that displaces its input by the gradient of a differentiable control [`NoiseFunction`] `W` before sampling `N`.
+/// Unlike warping a noise by its own gradient, this lets a coarse control field steer a separate, finer detail field,
+/// pushing the detail along the slopes of the control.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use noiz::misc_noise::ControlWarp;
+/// let noise = Noise::<ControlWarp<common_noise::PerlinWithDerivative, common_noise::Perlin>>::default();
+/// let value = noise.sample_for::<f32>(Vec2::new(1.0, -1.0));
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct ControlWarp<W, N> {
+    /// The differentiable [`NoiseFunction`] whose gradient displaces the input.
+    pub control: W,
+    /// The [`NoiseFunction`] sampled at the displaced location.
+    pub noise: N,
+    /// How far the input is displaced per unit of the control's gradient.
+    pub strength: f32,
+}
+
+impl<W: Default, N: Default> Default for ControlWarp<W, N> {
+    fn default() -> Self {
+        Self {
+            control: W::default(),
+            noise: N::default(),
+            strength: 1.0,
+        }
+    }
+}
+
+impl<W: NoiseFunction<Vec2, Output = WithGradient<f32, Vec2>>, N: NoiseFunction<Vec2>>
+    NoiseFunction<Vec2> for ControlWarp<W, N>
+{
+    type Output = N::Output;
+
+    #[inline]
+    fn evaluate(&self, input: Vec2, seeds: &mut NoiseRng) -> Self::Output {
+        let control = self.control.evaluate(input, seeds);
+        self.noise
+            .evaluate(input + control.gradient * self.strength, seeds)
+    }
+}
+
+impl<W, N: NoiseRange> NoiseRange for ControlWarp<W, N> {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        self.noise.output_range(input)
+    }
+}
+
+/// A [`NoiseFunction`] that scales its input by a factor that varies with a control [`NoiseFunction`] `C` before sampling `N`.
+/// The local scale is [`base`](VariableScale::base) plus the control's value times [`range`](VariableScale::range).
+/// This makes regions of stretched and compressed detail, like zooming in and out across the domain.
+///
+/// A smooth, coarse control works best, since the scale is applied relative to the origin, so quickly changing scales can tear the detail apart.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use noiz::misc_noise::VariableScale;
+/// let noise = Noise::from(VariableScale {
+///     control: common_noise::Value::default(),
+///     noise: common_noise::Perlin::default(),
+///     base: 1.0,
+///     range: 2.0,
+/// });
+/// let value = noise.sample_for::<f32>(Vec2::new(1.0, -1.0));
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct VariableScale<C, N> {
+    /// The [`NoiseFunction`] that controls the local scale.
+    pub control: C,
+    /// The [`NoiseFunction`] sampled at the scaled location.
+    pub noise: N,
+    /// The scale when the control is 0.
+    pub base: f32,
+    /// How much the scale changes per unit of the control.
+    pub range: f32,
+}
+
+impl<C: Default, N: Default> Default for VariableScale<C, N> {
+    fn default() -> Self {
+        Self {
+            control: C::default(),
+            noise: N::default(),
+            base: 1.0,
+            range: 1.0,
+        }
+    }
+}
+
+impl<I: VectorSpace, C: NoiseFunction<I, Output = f32>, N: NoiseFunction<I>> NoiseFunction<I>
+    for VariableScale<C, N>
+{
+    type Output = N::Output;
+
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        let scale = self.base + self.control.evaluate(input, seeds) * self.range;
+        self.noise.evaluate(input * scale, seeds)
+    }
+}
+
+impl<C, N: NoiseRange> NoiseRange for VariableScale<C, N> {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        self.noise.output_range(input)
+    }
+}
+
+/// A [`NoiseFunction`] that crossfades between two variants of the same [`NoiseFunction`] `N`, each with a different seed.
+/// Each seed is combined (xor) with the current seed, so both variants still change when the seed of the [`Noise`](crate::Noise) does.
+/// The results are interpolated by [`t`](SeedLerp::t), where 0 is entirely the variant from [`seed_a`](SeedLerp::seed_a) and 1 is entirely the variant from [`seed_b`](SeedLerp::seed_b).
+///
+/// Animating `t` morphs one noise into another without changing its structure, like the frequency or layering.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use noiz::misc_noise::SeedLerp;
+/// let mut noise = Noise::from(SeedLerp {
+///     noise: common_noise::Perlin::default(),
+///     seed_a: 1,
+///     seed_b: 2,
+///     t: 0.0,
+/// });
+/// noise.noise.t = 0.25;
+/// let value = noise.sample_for::<f32>(Vec2::new(1.0, -1.0));
+/// ```
+#[derive(Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct SeedLerp<N> {
+    /// The [`NoiseFunction`] to evaluate with each seed.
+    pub noise: N,
+    /// The seed of the variant when [`t`](SeedLerp::t) is 0.
+    pub seed_a: u32,
+    /// The seed of the variant when [`t`](SeedLerp::t) is 1.
+    pub seed_b: u32,
+    /// How far to interpolate from the first variant to the second.
+    pub t: f32,
+}
+
+impl<I: Copy, N: NoiseFunction<I, Output: VectorSpace>> NoiseFunction<I> for SeedLerp<N> {
+    type Output = N::Output;
+
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        let a = self
+            .noise
+            .evaluate_with_seed(input, NoiseRng(seeds.0 ^ self.seed_a));
+        let b = self
+            .noise
+            .evaluate_with_seed(input, NoiseRng(seeds.0 ^ self.seed_b));
+        a.lerp(b, self.t)
+    }
+}
+
+impl<N: NoiseRange> NoiseRange for SeedLerp<N> {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        self.noise.output_range(input)
+    }
+}
+
+/// A [`NoiseFunction`] that scales/multiplies its input by some factor `T`.
+///
+/// If you want this to be [`NoiseFunction`] based, see [`Masked`].
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Scaled<T>(pub T);
+
+impl<I: Mul<T>, T: Copy> NoiseFunction<I> for Scaled<T> {
+    type Output = I::Output;
+
+    #[inline]
+    fn evaluate(&self, input: I, _seeds: &mut NoiseRng) -> Self::Output {
+        input * self.0
+    }
+}
+
+impl NoiseRange for Scaled<f32> {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        input.map_monotonic(|x| x * self.0)
+    }
+}
+
+/// A [`NoiseFunction`] that translates/adds its input by some offset `T`.
+///
+/// If you want this to be [`NoiseFunction`] based, see [`Offset`].
+#[derive(Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Translated<T>(pub T);
+
+impl<I: Add<T>, T: Copy> NoiseFunction<I> for Translated<T> {
+    type Output = I::Output;
+
+    #[inline]
+    fn evaluate(&self, input: I, _seeds: &mut NoiseRng) -> Self::Output {
+        input + self.0
+    }
+}
+
+impl NoiseRange for Translated<f32> {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        input.map_monotonic(|x| x + self.0)
+    }
+}
+
+/// A [`NoiseFunction`] that rotates its input around the origin by an angle that grows with its distance from the origin.
+/// The angle, in radians, is [`strength`](Twist::strength) times the length of the input.
+/// This is useful for vortex and twist effects when put before some other noise.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use noiz::misc_noise::Twist;
+/// let noise = Noise::<(Twist, common_noise::Perlin)>::default();
+/// let value = noise.sample_for::<f32>(Vec2::new(1.0, -1.0));
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Twist {
+    /// The radians of rotation per unit of distance from the origin.
+    pub strength: f32,
+}
+
+impl Default for Twist {
+    fn default() -> Self {
+        Self { strength: 1.0 }
+    }
+}
+
+impl NoiseFunction<Vec2> for Twist {
+    type Output = Vec2;
+
+    #[inline]
+    fn evaluate(&self, input: Vec2, _seeds: &mut NoiseRng) -> Self::Output {
+        let (sin, cos) = bevy_math::ops::sin_cos(self.strength * input.length());
+        Vec2::new(cos, sin).rotate(input)
+    }
+}
+
+/// A [`NoiseFunction`] that folds its input into a single wedge around the origin, giving any noise after it [`sectors`](Kaleidoscope::sectors)-fold rotational symmetry.
+/// Each wedge is also mirrored about its center, so the noise is continuous across the boundaries between wedges, like a kaleidoscope.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use noiz::misc_noise::Kaleidoscope;
+/// let noise = Noise::<(Kaleidoscope, common_noise::Perlin)>::default();
+/// let value = noise.sample_for::<f32>(Vec2::new(1.0, -1.0));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Kaleidoscope {
+    /// The number of wedges the plane is split into.
+    /// Defaults to 6. A value of 0 is treated as 1.
+    pub sectors: u32,
+}
+
+impl Default for Kaleidoscope {
+    fn default() -> Self {
+        Self { sectors: 6 }
+    }
+}
+
+impl NoiseFunction<Vec2> for Kaleidoscope {
+    type Output = Vec2;
+
+    #[inline]
+    fn evaluate(&self, input: Vec2, _seeds: &mut NoiseRng) -> Self::Output {
+        let wedge = core::f32::consts::TAU / self.sectors.max(1) as f32;
+        let angle = bevy_math::ops::atan2(input.y, input.x);
+        let mut folded = angle - wedge * bevy_math::ops::floor(angle / wedge);
+        if folded > wedge * 0.5 {
+            folded = wedge - folded;
+        }
+        let (sin, cos) = bevy_math::ops::sin_cos(folded);
+        Vec2::new(cos, sin) * input.length()
+    }
+}
+
+/// A [`NoiseFunction`] that folds its input back and forth between mirrors placed every [`period`](MirrorRepeat::period) along each axis, like a triangle wave.
+/// This makes any noise after it tile seamlessly, repeating every `2 * period`, while staying continuous across every seam.
+/// Unlike wrapping, this needs no special support from the noise, but the mirroring is visible as reflections at each seam.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use noiz::misc_noise::MirrorRepeat;
+/// let noise = Noise::<(MirrorRepeat<Vec2>, common_noise::Perlin)>::from((
+///     MirrorRepeat {
+///         period: Vec2::splat(8.0),
+///     },
+///     Default::default(),
+/// ));
+/// let value = noise.sample_for::<f32>(Vec2::new(1.0, -1.0));
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct MirrorRepeat<T> {
+    /// The distance between mirrors along each axis.
+    /// Each element must be positive.
+    pub period: T,
+}
+
+macro_rules! impl_mirror_repeat {
+    ($($t:ty),*) => {
+        $(
+            impl Default for MirrorRepeat<$t> {
+                fn default() -> Self {
+                    Self {
+                        period: <$t>::ONE,
+                    }
+                }
+            }
+
+            impl NoiseFunction<$t> for MirrorRepeat<$t> {
+                type Output = $t;
+
+                #[inline]
+                fn evaluate(&self, input: $t, _seeds: &mut NoiseRng) -> Self::Output {
+                    let full = self.period * 2.0;
+                    let within = input - (input / full).floor() * full;
+                    self.period - (within - self.period).abs()
+                }
+            }
+        )*
+    };
+}
+
+impl_mirror_repeat!(Vec2, Vec3, Vec3A, Vec4);
+
+/// A [`NoiseFunction`] always returns a constant `T`.
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Constant<T>(pub T);
+
+impl<I, T: Copy> NoiseFunction<I> for Constant<T> {
+    type Output = T;
+
+    #[inline]
+    fn evaluate(&self, _input: I, _seeds: &mut NoiseRng) -> Self::Output {
+        self.0
+    }
+}
+
+/// A [`NoiseFunction`] that multiplies the result of two [`NoiseFunction`]s evaluated at the same input.
+///
+/// This is generally commutative, so `N` and `M` can swap without changing what kind of noise it is (though due to rng, the results may differ).
+/// If you need to mask more than two noise functions, you can nest `M` or `N` in another [`Masked`].
+/// If you only need to mask one, see [`SelfMasked`].
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Masked<N, M>(pub N, pub M);
+
+impl<I: Copy, N: NoiseFunction<I>, M: NoiseFunction<I, Output: Mul<N::Output>>> NoiseFunction<I>
+    for Masked<N, M>
+{
+    type Output = <M::Output as Mul<N::Output>>::Output;
+
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        self.1.evaluate(input, seeds) * self.0.evaluate(input, seeds)
+    }
+}
+
+/// A [`NoiseFunction`] that multiplies two distinct results of an inner [`NoiseFunction`]s at each input.
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct SelfMasked<N>(pub N);
+
+impl<I: Copy, N: NoiseFunction<I, Output: Mul<N::Output>>> NoiseFunction<I> for SelfMasked<N> {
+    type Output = <N::Output as Mul<N::Output>>::Output;
+
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        self.0.evaluate(input, seeds) * self.0.evaluate(input, seeds)
+    }
+}
+
+/// An operation that combines two signed distance fields in an [`SdfCombine`].
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub enum SdfOp {
+    /// Keeps the space inside either shape: `min(a, b)`.
+    #[default]
+    Union,
+    /// Keeps the space inside both shapes: `max(a, b)`.
+    Intersection,
+    /// Keeps the space inside `a` but not `b`: `max(a, -b)`.
+    Subtraction,
+}
+
+/// A [`NoiseFunction`] that combines the `f32` outputs of `A` and `B`, treated as signed distance fields, by an [`SdfOp`].
+/// This allows CSG-style shapes to be built from noise, for example, carving caves out of terrain with [`SdfOp::Subtraction`].
+///
+/// When [`smoothing`](SdfCombine::smoothing) is positive, the op is smoothed by the [`SmoothMin`] `S`, rounding off the seams between shapes.
+/// Smooth maximums are computed as `-smin(-a, -b)`.
+#[derive(Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct SdfCombine<A, B, S = CubicSMin> {
+    /// The first distance field.
+    pub a: A,
+    /// The second distance field.
+    pub b: B,
+    /// How the two fields are combined.
+    pub op: SdfOp,
+    /// The blend radius of the [`SmoothMin`], or 0 for a sharp combination.
+    pub smoothing: f32,
+    /// The [`SmoothMin`] used when `smoothing` is positive.
+    pub smooth_min: S,
+}
+
+impl<A, B, S: SmoothMin> SdfCombine<A, B, S> {
+    /// Combines two distances by [`op`](SdfCombine::op).
+    #[inline]
+    pub fn combine(&self, a: f32, b: f32) -> f32 {
+        let min = |a, b| {
+            if self.smoothing > 0.0 {
+                self.smooth_min.smin_norm(a, b, self.smoothing)
+            } else {
+                f32::min(a, b)
+            }
+        };
+        match self.op {
+            SdfOp::Union => min(a, b),
+            SdfOp::Intersection => -min(-a, -b),
+            SdfOp::Subtraction => -min(-a, b),
+        }
+    }
+}
+
+impl<I: Copy, A: NoiseFunction<I, Output = f32>, B: NoiseFunction<I, Output = f32>, S: SmoothMin>
+    NoiseFunction<I> for SdfCombine<A, B, S>
+{
+    type Output = f32;
+
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        let a = self.a.evaluate(input, seeds);
+        let b = self.b.evaluate(input, seeds);
+        self.combine(a, b)
+    }
+}
+
+/// A [`NoiseFunction`] that produces a radial mask, which is 1.0 at [`center`](RadialFalloff::center) and falls off to 0.0 at [`radius`](RadialFalloff::radius).
+/// The distance from the center is measured by a [`LengthFunction`] `L`, and the shape of the falloff is determined by a [`Curve`] `C` over `[0, 1]`.
+/// The result is `1 - curve(distance / radius)`, so the curve should go from 0 to 1.
+///
+/// This is commonly used with [`Masked`] to carve out islands or continents:
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use noiz::misc_noise::RadialFalloff;
+/// let noise = Noise::<Masked<common_noise::Fbm<common_noise::Perlin>, RadialFalloff>>::default();
+/// let value = noise.sample_for::<f32>(Vec2::new(0.1, -0.2));
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct RadialFalloff<L = EuclideanLength, C = Smoothstep> {
+    /// The center of the mask, where it is 1.0.
+    pub center: Vec2,
+    /// The distance from the center at which the mask reaches 0.0.
+    pub radius: f32,
+    /// The [`LengthFunction`] that measures distance from the center.
+    pub length_mode: L,
+    /// The [`Curve`] that shapes the falloff.
+    pub curve: C,
+}
+
+impl<L: Default, C: Default> Default for RadialFalloff<L, C> {
+    fn default() -> Self {
+        Self {
+            center: Vec2::ZERO,
+            radius: 1.0,
+            length_mode: L::default(),
+            curve: C::default(),
+        }
+    }
+}
+
+impl<L: LengthFunction<Vec2>, C: Curve<f32>> NoiseFunction<Vec2> for RadialFalloff<L, C> {
+    type Output = f32;
+
+    #[inline]
+    fn evaluate(&self, input: Vec2, _seeds: &mut NoiseRng) -> Self::Output {
+        let t = self.length_mode.length_of(input - self.center) / self.radius;
+        1.0 - self.curve.sample_clamped(t.min(1.0))
+    }
+}
+
+impl<L, C> NoiseRange for RadialFalloff<L, C> {
+    #[inline]
+    fn output_range(&self, _input: OutputRange) -> OutputRange {
+        OutputRange::UNORM
+    }
+}
+
+/// A [`NoiseFunction`] that produces a mask around a set of fixed, user provided [`points`](DistanceToPoints::points), like the locations of cities or other landmarks.
+/// The result is 1.0 at each point and falls off linearly to 0.0 at a distance of [`falloff`](DistanceToPoints::falloff) from the nearest one, as measured by a [`LengthFunction`] `L`.
+/// If there are no points, the result is always 0.0.
+///
+/// Each sample checks every point, so this is meant for small sets of points.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use noiz::misc_noise::DistanceToPoints;
+/// let near_city = Noise::from(DistanceToPoints {
+///     points: vec![Vec2::new(10.0, 5.0), Vec2::new(-3.0, 8.0)],
+///     falloff: 4.0,
+///     length_mode: EuclideanLength,
+/// });
+/// let value = near_city.sample_for::<f32>(Vec2::new(9.0, 6.0));
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct DistanceToPoints<L = EuclideanLength> {
+    /// The points around which the mask is 1.0.
+    pub points: Vec<Vec2>,
+    /// The distance from the nearest point at which the mask reaches 0.0.
+    pub falloff: f32,
+    /// The [`LengthFunction`] that measures distance to the points.
+    pub length_mode: L,
+}
+
+#[cfg(feature = "alloc")]
+impl<L: Default> Default for DistanceToPoints<L> {
+    fn default() -> Self {
+        Self {
+            points: Vec::new(),
+            falloff: 1.0,
+            length_mode: L::default(),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<L: LengthFunction<Vec2>> NoiseFunction<Vec2> for DistanceToPoints<L> {
+    type Output = f32;
+
+    #[inline]
+    fn evaluate(&self, input: Vec2, _seeds: &mut NoiseRng) -> Self::Output {
+        let least = self
+            .points
+            .iter()
+            .map(|&point| self.length_mode.length_ordering(input - point))
+            .fold(f32::INFINITY, f32::min);
+        if least == f32::INFINITY {
+            return 0.0;
+        }
+        (1.0 - self.length_mode.length_from_ordering(least) / self.falloff).max(0.0)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<L> NoiseRange for DistanceToPoints<L> {
+    #[inline]
+    fn output_range(&self, _input: OutputRange) -> OutputRange {
+        OutputRange::UNORM
+    }
+}
+
+/// A [`NoiseFunction`] that produces a rectangular mask, which is 1.0 inside the axis-aligned box from [`min`](RegionMask::min) to [`max`](RegionMask::max) and 0.0 outside it.
+/// If [`feather`](RegionMask::feather) is positive, the mask instead falls off smoothly to 0.0 over that distance outside the box.
+///
+/// This is commonly used with [`Masked`] to clip noise to a region:
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use noiz::misc_noise::RegionMask;
+/// let noise = Noise::from(Masked(
+///     common_noise::Perlin::default(),
+///     RegionMask {
+///         min: Vec2::new(-2.0, -1.0),
+///         max: Vec2::new(2.0, 1.0),
+///         feather: 0.5,
+///     },
+/// ));
+/// let value = noise.sample_for::<f32>(Vec2::new(0.1, -0.2));
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct RegionMask {
+    /// The least corner of the box.
+    pub min: Vec2,
+    /// The greatest corner of the box.
+    pub max: Vec2,
+    /// The distance outside the box over which the mask falls off to 0.0.
+    /// If this is 0 or less, the edge is hard.
+    pub feather: f32,
+}
+
+impl Default for RegionMask {
+    fn default() -> Self {
+        Self {
+            min: Vec2::NEG_ONE,
+            max: Vec2::ONE,
+            feather: 0.0,
+        }
+    }
+}
+
+impl NoiseFunction<Vec2> for RegionMask {
+    type Output = f32;
+
+    #[inline]
+    fn evaluate(&self, input: Vec2, _seeds: &mut NoiseRng) -> Self::Output {
+        let outside = (self.min - input).max(input - self.max).max(Vec2::ZERO);
+        if self.feather > 0.0 {
+            let t = outside.length() / self.feather;
+            1.0 - Smoothstep.sample_clamped(t.min(1.0))
+        } else if outside == Vec2::ZERO {
+            1.0
+        } else {
+            0.0
+        }
+    }
+}
+
+impl NoiseRange for RegionMask {
+    #[inline]
+    fn output_range(&self, _input: OutputRange) -> OutputRange {
+        OutputRange::UNORM
+    }
+}
+
+/// A [`NoiseFunction`] that evaluates an inner [`NoiseFunction`] `N` and, in debug builds, asserts that its output is within [`min`](AssertRange::min) and [`max`](AssertRange::max).
+/// If it is not, this panics with the offending value and input.
+/// In release builds, this just passes the value through.
+///
+/// This is useful while composing noise functions to catch values drifting out of their expected range:
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use noiz::misc_noise::AssertRange;
+/// let noise = Noise::from(AssertRange {
+///     noise: (common_noise::Perlin::default(), SNormToUNorm),
+///     min: 0.0,
+///     max: 1.0,
+/// });
+/// let value = noise.sample_for::<f32>(Vec2::new(1.0, -1.0));
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct AssertRange<N> {
+    /// The inner [`NoiseFunction`].
+    pub noise: N,
+    /// The least allowed value.
+    pub min: f32,
+    /// The greatest allowed value.
+    pub max: f32,
+}
+
+impl<N: Default> Default for AssertRange<N> {
+    fn default() -> Self {
+        Self {
+            noise: N::default(),
+            min: 0.0,
+            max: 1.0,
+        }
+    }
+}
+
+impl<I: Copy + core::fmt::Debug, N: NoiseFunction<I, Output = f32>> NoiseFunction<I>
+    for AssertRange<N>
+{
+    type Output = f32;
+
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        let value = self.noise.evaluate(input, seeds);
+        debug_assert!(
+            (self.min..=self.max).contains(&value),
+            "Noise value {value} at {input:?} is outside of the expected range {} to {}.",
+            self.min,
+            self.max
+        );
+        value
+    }
+}
 
+impl<N: NoiseRange> NoiseRange for AssertRange<N> {
     #[inline]
-    fn evaluate(&self, input: I, _seeds: &mut NoiseRng) -> Self::Output {
-        input * self.0
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        self.noise.output_range(input)
     }
 }
 
-/// A [`NoiseFunction`] that translates/adds its input by some offset `T`.
+/// Records the least and greatest `f32` values a noise produces, so its output can be normalized by a [`RangeRemap`].
+/// This is useful for normalizing custom noise whose true range is unknown.
 ///
-/// If you want this to be [`NoiseFunction`] based, see [`Offset`].
-#[derive(Clone, Copy, PartialEq, Eq, Default)]
+/// To use it, [`sample`](Calibrate::sample) the noise through this over a representative region (the calibration pass), and then use [`remap`](Calibrate::remap) to get a [`RangeRemap`] that normalizes to (0, 1).
+/// This is kept separate from the noise it observes, so the noise stays plain data that can still be shared between threads.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use noiz::misc_noise::Calibrate;
+/// let noise = Noise::<common_noise::Perlin>::default();
+/// let mut calibrate = Calibrate::new();
+/// for x in -10..10 {
+///     for y in -10..10 {
+///         calibrate.sample(&noise, Vec2::new(x as f32, y as f32) * 0.37);
+///     }
+/// }
+/// let normalized = Noise::from((noise.noise, calibrate.remap()));
+/// let value = normalized.sample_for::<f32>(Vec2::new(1.0, -1.0));
+/// ```
+#[derive(Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub struct Translated<T>(pub T);
+pub struct Calibrate {
+    /// The range of values observed so far.
+    /// Before anything is observed, this is an empty range from infinity to negative infinity.
+    pub observed: OutputRange,
+}
 
-impl<I: Add<T>, T: Copy> NoiseFunction<I> for Translated<T> {
-    type Output = I::Output;
+impl Calibrate {
+    /// The [`observed`](Self::observed) range before anything has been observed.
+    const EMPTY: OutputRange = OutputRange {
+        min: f32::INFINITY,
+        max: f32::NEG_INFINITY,
+    };
 
+    /// Constructs a new [`Calibrate`] that has not yet observed anything.
     #[inline]
-    fn evaluate(&self, input: I, _seeds: &mut NoiseRng) -> Self::Output {
-        input + self.0
+    pub fn new() -> Self {
+        Self {
+            observed: Self::EMPTY,
+        }
     }
-}
 
-/// A [`NoiseFunction`] always returns a constant `T`.
-#[derive(Default, Clone, Copy, PartialEq, Eq)]
-#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
-#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
-#[cfg_attr(feature = "debug", derive(Debug))]
-pub struct Constant<T>(pub T);
+    /// Includes `value` in the [`observed`](Self::observed) range.
+    #[inline]
+    pub fn observe(&mut self, value: f32) {
+        self.observed.min = self.observed.min.min(value);
+        self.observed.max = self.observed.max.max(value);
+    }
 
-impl<I, T: Copy> NoiseFunction<I> for Constant<T> {
-    type Output = T;
+    /// Samples `noise` at `loc`, observing and returning the result.
+    #[inline]
+    pub fn sample<I>(&mut self, noise: &impl SampleableFor<I, f32>, loc: I) -> f32 {
+        let value = noise.sample(loc);
+        self.observe(value);
+        value
+    }
 
+    /// Forgets everything observed so far, starting a new calibration pass.
     #[inline]
-    fn evaluate(&self, _input: I, _seeds: &mut NoiseRng) -> Self::Output {
-        self.0
+    pub fn reset(&mut self) {
+        self.observed = Self::EMPTY;
+    }
+
+    /// Creates a [`RangeRemap`] that normalizes the [`observed`](Self::observed) range to (0, 1).
+    #[inline]
+    pub fn remap(&self) -> RangeRemap {
+        RangeRemap(self.observed)
     }
 }
 
-/// A [`NoiseFunction`] that multiplies the result of two [`NoiseFunction`]s evaluated at the same input.
-///
-/// This is generally commutative, so `N` and `M` can swap without changing what kind of noise it is (though due to rng, the results may differ).
-/// If you need to mask more than two noise functions, you can nest `M` or `N` in another [`Masked`].
-/// If you only need to mask one, see [`SelfMasked`].
-#[derive(Default, Clone, Copy, PartialEq, Eq)]
+impl Default for Calibrate {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A [`NoiseFunction`] that linearly remaps `f32` values from its [`OutputRange`] to (0, 1), clamping values outside of it.
+/// This is usually made by [`Calibrate::remap`].
+/// If the range is empty, this produces 0.
+#[derive(Clone, Copy, PartialEq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
 #[cfg_attr(feature = "debug", derive(Debug))]
-pub struct Masked<N, M>(pub N, pub M);
-
-impl<I: Copy, N: NoiseFunction<I>, M: NoiseFunction<I, Output: Mul<N::Output>>> NoiseFunction<I>
-    for Masked<N, M>
-{
-    type Output = <M::Output as Mul<N::Output>>::Output;
+pub struct RangeRemap(pub OutputRange);
 
-    #[inline]
-    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
-        self.1.evaluate(input, seeds) * self.0.evaluate(input, seeds)
+impl Default for RangeRemap {
+    fn default() -> Self {
+        Self(OutputRange::UNORM)
     }
 }
 
-/// A [`NoiseFunction`] that multiplies two distinct results of an inner [`NoiseFunction`]s at each input.
-#[derive(Default, Clone, Copy, PartialEq, Eq)]
-#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
-#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
-#[cfg_attr(feature = "debug", derive(Debug))]
-pub struct SelfMasked<N>(pub N);
+impl NoiseFunction<f32> for RangeRemap {
+    type Output = f32;
 
-impl<I: Copy, N: NoiseFunction<I, Output: Mul<N::Output>>> NoiseFunction<I> for SelfMasked<N> {
-    type Output = <N::Output as Mul<N::Output>>::Output;
+    #[inline]
+    fn evaluate(&self, input: f32, _seeds: &mut NoiseRng) -> Self::Output {
+        if self.0.max > self.0.min {
+            self.0.normalize(input).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+}
 
+impl NoiseRange for RangeRemap {
     #[inline]
-    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
-        self.0.evaluate(input, seeds) * self.0.evaluate(input, seeds)
+    fn output_range(&self, _input: OutputRange) -> OutputRange {
+        OutputRange::UNORM
     }
 }
 
@@ -365,6 +1510,71 @@ impl NoiseFunction<Vec4> for DisAligned {
     }
 }
 
+/// A [`NoiseFunction`] that converts its input into `T` via [`From`].
+/// This is useful as a type bridge when chaining [`NoiseFunction`]s, ex: from [`Vec3A`] to [`Vec3`].
+/// To turn a scalar into a vector, see [`Broadcast`].
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use noiz::misc_noise::Cast;
+/// let noise = Noise::<(Cast<Vec3A>, common_noise::Perlin)>::default();
+/// let value = noise.sample_for::<f32>(Vec3::new(1.0, -1.0, 0.5));
+/// ```
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Cast<T>(pub PhantomData<T>);
+
+impl<I, T: From<I>> NoiseFunction<I> for Cast<T> {
+    type Output = T;
+
+    #[inline(always)]
+    fn evaluate(&self, input: I, _seeds: &mut NoiseRng) -> Self::Output {
+        T::from(input)
+    }
+}
+
+impl<T> NoiseRange for Cast<T> {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        input
+    }
+}
+
+/// A [`NoiseFunction`] that splats its scalar input into each element of a vector `T`.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use noiz::misc_noise::Broadcast;
+/// let noise = Noise::<(common_noise::Perlin, Broadcast<Vec2>)>::default();
+/// let value = noise.sample_for::<Vec2>(Vec2::new(1.0, -1.0));
+/// ```
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Broadcast<T>(pub PhantomData<T>);
+
+macro_rules! impl_broadcast {
+    ($($t:ty),*) => {
+        $(
+            impl NoiseFunction<f32> for Broadcast<$t> {
+                type Output = $t;
+
+                #[inline(always)]
+                fn evaluate(&self, input: f32, _seeds: &mut NoiseRng) -> Self::Output {
+                    <$t>::splat(input)
+                }
+            }
+        )*
+    };
+}
+
+impl_broadcast!(Vec2, Vec3, Vec3A, Vec4);
+
 /// A [`NoiseFunction`] that forces a gradient of this value.
 /// This is mathematically arbitrary and will not be an actual derivative/gradient unless you calculate it to be so.
 /// This exists as an escape hatch to use [`crate::layering::NormedByDerivative`] with noise functions that are not differentiable.
@@ -388,6 +1598,7 @@ impl<T, G: Copy> NoiseFunction<T> for WithGradientOf<G> {
 
 /// A [`NoiseFunction`] that remaps a scalar input by passing it through a [`Curve`].
 /// If `CLAMP` is `true`, this will use [`Curve::sample_clamped`]; otherwise, it will use [`Curve::sample_unchecked`].
+/// To remap each component of a vector instead, see [`NoiseCurveClamped`](crate::math_noise::NoiseCurveClamped) and [`NoiseCurve`](crate::math_noise::NoiseCurve).
 #[derive(Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
 #[cfg_attr(feature = "serialize", derive(serde::Serialize))]
@@ -448,3 +1659,685 @@ impl<C: SampleDerivative<T>, T: HasTangent, G: Add<T::Tangent>, const CLAMP: boo
         }
     }
 }
+
+/// A [`NoiseFunction`] that remaps a scalar input by passing it through a [`Curve<f32>`] `C`, clamping the input to the curve's domain.
+/// This is a simpler version of [`RemapCurve`] for the common case of remapping a noise value, for example, through an editor-authored curve.
+///
+/// When the input is a [`WithGradient`], the gradient is scaled by the curve's derivative via the chain rule.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use noiz::misc_noise::Remap;
+/// let noise = Noise::<(common_noise::Value, Remap<Smoothstep>)>::default();
+/// let value = noise.sample_for::<f32>(Vec2::new(1.0, -1.0));
+/// ```
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Remap<C>(pub C);
+
+impl<C: Curve<f32>> NoiseFunction<f32> for Remap<C> {
+    type Output = f32;
+
+    #[inline]
+    fn evaluate(&self, input: f32, _seeds: &mut NoiseRng) -> Self::Output {
+        self.0.sample_clamped(input)
+    }
+}
+
+impl<C: SampleDerivative<f32>, G: Mul<f32, Output = G>> NoiseFunction<WithGradient<f32, G>>
+    for Remap<C>
+{
+    type Output = WithGradient<f32, G>;
+
+    #[inline]
+    fn evaluate(&self, input: WithGradient<f32, G>, _seeds: &mut NoiseRng) -> Self::Output {
+        let f = self.0.sample_with_derivative_clamped(input.value);
+        WithGradient {
+            value: f.value,
+            gradient: input.gradient * f.derivative,
+        }
+    }
+}
+
+/// A [`NoiseFunction`] that samples its inner 2d [`NoiseFunction`] `N` along a path `P`, a [`Curve<Vec2>`], taking the curve's parameter as its `f32` input.
+/// This is useful for varying something along a spline, like the width of a road or river.
+///
+/// The input is clamped to the curve's domain.
+/// For the input to be the distance travelled along the path, the curve should be parameterized by arc length.
+/// For a straight path, [`Lerped`](crate::curves::Lerped) from a start to the start plus a unit direction does this.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// # use noiz::misc_noise::AlongPath;
+/// let noise = Noise::from(AlongPath {
+///     noise: common_noise::Perlin::default(),
+///     path: Lerped {
+///         start: Vec2::ZERO,
+///         end: Vec2::new(1.0, 1.0).normalize(),
+///     },
+/// });
+/// let width = noise.sample_for::<f32>(12.5);
+/// ```
+#[derive(Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct AlongPath<N, P> {
+    /// The inner [`NoiseFunction`] to sample along the path.
+    pub noise: N,
+    /// The [`Curve`] that maps the input to a 2d location to sample.
+    pub path: P,
+}
+
+impl<N: NoiseFunction<Vec2>, P: Curve<Vec2>> NoiseFunction<f32> for AlongPath<N, P> {
+    type Output = N::Output;
+
+    #[inline]
+    fn evaluate(&self, input: f32, seeds: &mut NoiseRng) -> Self::Output {
+        self.noise.evaluate(self.path.sample_clamped(input), seeds)
+    }
+}
+
+impl<N: NoiseRange, P> NoiseRange for AlongPath<N, P> {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        self.noise.output_range(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Noise, Sampleable, SampleableFor, prelude::common_noise::Perlin};
+
+    #[test]
+    fn test_cast() {
+        let cast = Cast::<Vec3>::default();
+        let value = cast.evaluate(Vec3A::new(1.0, 2.0, 3.0), &mut NoiseRng(0));
+        assert_eq!(value, Vec3::new(1.0, 2.0, 3.0));
+
+        let noise = Noise::<(Cast<Vec3A>, Perlin)>::default();
+        let expected = Noise::<Perlin>::default();
+        let point = Vec3::new(0.3, -1.7, 2.1);
+        let actual: f32 = noise.sample(point);
+        let expected: f32 = expected.sample(Vec3A::from(point));
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_broadcast() {
+        let broadcast = Broadcast::<Vec2>::default();
+        assert_eq!(broadcast.evaluate(0.5, &mut NoiseRng(0)), Vec2::splat(0.5));
+
+        let noise = Noise::<(Perlin, Broadcast<Vec4>)>::default();
+        let scalar = Noise::<Perlin>::default();
+        for x in -5..=5 {
+            let point = Vec2::new(x as f32 * 0.37, 1.3);
+            let value: Vec4 = noise.sample(point);
+            let expected: f32 = scalar.sample(point);
+            assert_eq!(value, Vec4::splat(expected));
+        }
+    }
+
+    #[test]
+    fn test_per_channel() {
+        use crate::prelude::common_noise::White;
+
+        let channels = Noise::<PerChannel<[White; 3]>>::default();
+        let single = Noise::<White>::default();
+        let mut sums = [0.0f32; 3];
+        let mut sum_sqrs = [0.0f32; 3];
+        let mut sum_01 = 0.0;
+        let mut sum_12 = 0.0;
+        for x in 0..100 {
+            for y in 0..100 {
+                let point = Vec2::new(x as f32, y as f32) + 0.5;
+                let values: [f32; 3] = channels.sample(point);
+                let first: f32 = single.sample(point);
+                assert_eq!(values[0], first);
+                for (channel, value) in values.into_iter().enumerate() {
+                    sums[channel] += value;
+                    sum_sqrs[channel] += value * value;
+                }
+                sum_01 += values[0] * values[1];
+                sum_12 += values[1] * values[2];
+            }
+        }
+
+        let n = 10_000.0;
+        let mean = sums.map(|sum| sum / n);
+        let variance: [f32; 3] = core::array::from_fn(|i| sum_sqrs[i] / n - mean[i] * mean[i]);
+        let correlation = |cross: f32, a: usize, b: usize| {
+            (cross / n - mean[a] * mean[b]) / (variance[a] * variance[b]).sqrt()
+        };
+        assert!(correlation(sum_01, 0, 1).abs() < 0.05);
+        assert!(correlation(sum_12, 1, 2).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_coherent_elements() {
+        use crate::{
+            cell_noise::MixCellValuesForDomain, cells::OrthoGrid, curves::Smoothstep,
+            prelude::common_noise::Value, rng::SNorm,
+        };
+
+        let inner = MixCellValuesForDomain::<OrthoGrid, Smoothstep, SNorm>::default();
+        let coherent = CoherentElements(inner);
+        let random = RandomElements(Value::default());
+        for x in -5..=5 {
+            let point = Vec2::new(x as f32 * 0.37, 1.3);
+
+            // Coherent elements are a single sample of the inner noise.
+            let mut seeds = NoiseRng(7);
+            let value = coherent.evaluate(point, &mut seeds);
+            assert_eq!(value, inner.evaluate(point, &mut NoiseRng(7)));
+            let mut expected_seeds = NoiseRng(7);
+            expected_seeds.re_seed();
+            assert_eq!(seeds.0, expected_seeds.0);
+
+            // Random elements sample each component with its own seed.
+            let value = random.evaluate(point, &mut NoiseRng(7));
+            let mut seeds = NoiseRng(7);
+            let first = Value::default().evaluate(point, &mut seeds);
+            seeds.re_seed();
+            let second = Value::default().evaluate(point, &mut seeds);
+            assert_eq!(value, Vec2::new(first, second));
+        }
+    }
+
+    #[test]
+    fn test_twist() {
+        let none = Twist { strength: 0.0 };
+        let twist = Twist { strength: 0.3 };
+        for x in 1..=10 {
+            let radius = x as f32 * 0.5;
+            let point = Vec2::new(radius, 0.0);
+            assert_eq!(none.evaluate(point, &mut NoiseRng(0)), point);
+
+            let twisted = twist.evaluate(point, &mut NoiseRng(0));
+            assert!((twisted.length() - radius).abs() < 1e-5);
+            assert!((twisted.to_angle() - 0.3 * radius).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_radial_falloff() {
+        let falloff = RadialFalloff::<EuclideanLength, Smoothstep> {
+            center: Vec2::new(2.0, -1.0),
+            radius: 3.0,
+            ..Default::default()
+        };
+        assert_eq!(falloff.evaluate(falloff.center, &mut NoiseRng(0)), 1.0);
+        for angle in 0..16 {
+            let direction = Vec2::from_angle(angle as f32 * 0.4);
+            let mut last = 1.0;
+            for distance in 1..=10 {
+                let point = falloff.center + direction * (distance as f32 * 0.5);
+                let value = falloff.evaluate(point, &mut NoiseRng(0));
+                let distance = distance as f32 * 0.5;
+                if distance > falloff.radius {
+                    assert_eq!(value, 0.0);
+                } else if distance == falloff.radius {
+                    assert!(value < 1e-5);
+                } else {
+                    assert!(value > 0.0 && value <= last);
+                }
+                last = value;
+            }
+        }
+    }
+
+    #[test]
+    fn test_iterated_warp_single_matches_offset() {
+        let offset = Noise::from((
+            Offset {
+                offseter: RandomElements::<Perlin>::default(),
+                offset_strength: 0.7,
+            },
+            Perlin::default(),
+        ));
+        let iterated = Noise::from((
+            IteratedWarp {
+                warper: RandomElements::<Perlin>::default(),
+                iterations: 1,
+                strength: 0.7,
+            },
+            Perlin::default(),
+        ));
+        let twice = Noise::from((
+            IteratedWarp {
+                warper: RandomElements::<Perlin>::default(),
+                iterations: 2,
+                strength: 0.7,
+            },
+            Perlin::default(),
+        ));
+        let mut differs = false;
+        for x in -20..20 {
+            for y in -20..20 {
+                let point = Vec2::new(x as f32, y as f32) * 0.37;
+                let expected: f32 = offset.sample(point);
+                assert_eq!(expected, iterated.sample_for::<f32>(point));
+                differs |= expected != twice.sample_for::<f32>(point);
+            }
+        }
+        assert!(differs);
+    }
+
+    #[test]
+    fn test_region_mask() {
+        let hard = RegionMask {
+            min: Vec2::new(-2.0, -1.0),
+            max: Vec2::new(2.0, 1.0),
+            feather: 0.0,
+        };
+        let soft = RegionMask {
+            feather: 0.5,
+            ..hard
+        };
+        let mask = |mask: &RegionMask, point: Vec2| mask.evaluate(point, &mut NoiseRng(0));
+
+        // inside
+        for point in [Vec2::ZERO, Vec2::new(1.9, -0.9), Vec2::new(2.0, 1.0)] {
+            assert_eq!(mask(&hard, point), 1.0);
+            assert_eq!(mask(&soft, point), 1.0);
+        }
+        // outside
+        for point in [
+            Vec2::new(2.6, 0.0),
+            Vec2::new(0.0, -1.6),
+            Vec2::new(-3.0, 2.0),
+        ] {
+            assert_eq!(mask(&hard, point), 0.0);
+            assert_eq!(mask(&soft, point), 0.0);
+        }
+        // border
+        assert_eq!(mask(&hard, Vec2::new(2.1, 0.0)), 0.0);
+        let mut last = 1.0;
+        for step in 1..10 {
+            let value = mask(&soft, Vec2::new(2.0 + step as f32 * 0.05, 0.0));
+            assert!(value > 0.0 && value < last);
+            last = value;
+        }
+        assert!((mask(&soft, Vec2::new(0.0, 1.25)) - 0.5).abs() < 1e-6);
+
+        let clipped = Noise::from(Masked(Perlin::default(), hard));
+        assert_eq!(clipped.sample_for::<f32>(Vec2::new(5.3, 0.7)), 0.0);
+    }
+
+    #[test]
+    fn test_assert_range_in_range() {
+        let noise = Noise::from(AssertRange {
+            noise: (Perlin::default(), crate::math_noise::SNormToUNorm),
+            min: 0.0,
+            max: 1.0,
+        });
+        for x in -10..10 {
+            for y in -10..10 {
+                let _: f32 = noise.sample(Vec2::new(x as f32, y as f32) * 0.37);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn test_assert_range_out_of_range() {
+        let noise = AssertRange {
+            noise: |_: Vec2, _: &mut NoiseRng| 1.5,
+            min: 0.0,
+            max: 1.0,
+        };
+        noise.evaluate(Vec2::ZERO, &mut NoiseRng(0));
+    }
+
+    #[test]
+    fn test_sdf_combine_ops() {
+        let combine = |op, smoothing| SdfCombine {
+            a: |_: Vec2, _: &mut NoiseRng| 0.3,
+            b: |_: Vec2, _: &mut NoiseRng| -0.5,
+            op,
+            smoothing,
+            smooth_min: CubicSMin,
+        };
+        let mut seeds = NoiseRng(0);
+        let sample = |op, smoothing, seeds: &mut NoiseRng| {
+            combine(op, smoothing).evaluate(Vec2::ZERO, seeds)
+        };
+        assert_eq!(sample(SdfOp::Union, 0.0, &mut seeds), -0.5);
+        assert_eq!(sample(SdfOp::Intersection, 0.0, &mut seeds), 0.3);
+        assert_eq!(sample(SdfOp::Subtraction, 0.0, &mut seeds), 0.5);
+
+        let smooth = SdfCombine {
+            a: (),
+            b: (),
+            op: SdfOp::Union,
+            smoothing: 0.1,
+            smooth_min: CubicSMin,
+        };
+        // k = 0.4, diff = 0.2, h = 0.5
+        assert!((smooth.combine(0.3, 0.5) - 0.275).abs() < 1e-6);
+        let smooth = SdfCombine {
+            op: SdfOp::Intersection,
+            ..smooth
+        };
+        assert!((smooth.combine(0.3, 0.5) - 0.525).abs() < 1e-6);
+        let smooth = SdfCombine {
+            op: SdfOp::Subtraction,
+            ..smooth
+        };
+        assert!((smooth.combine(0.3, -0.5) - 0.525).abs() < 1e-6);
+        // Far apart values are not smoothed.
+        assert_eq!(smooth.combine(0.3, 2.0), 0.3);
+    }
+
+    #[test]
+    fn test_fbm_warp_single_octave() {
+        let warp = FbmWarp::<Perlin> {
+            octaves: 1,
+            strength: 3.0,
+            ..Default::default()
+        };
+        let elements = RandomElements::<Perlin>::default();
+        for x in -10..10 {
+            let point = Vec3::new(x as f32 * 0.37, 0.5, -1.2);
+            let expected = elements.evaluate(point, &mut NoiseRng(5)) * 3.0;
+            assert!((warp.evaluate(point, &mut NoiseRng(5)) - expected).length() < 1e-5);
+        }
+
+        let layered = FbmWarp::<Perlin>::default();
+        let displacement: Vec2 = layered.evaluate(Vec2::new(0.3, 0.7), &mut NoiseRng(5));
+        assert!(displacement.is_finite());
+    }
+
+    #[test]
+    fn test_kaleidoscope_symmetry() {
+        let noise = Noise::<(Kaleidoscope, Perlin)>::default();
+        let wedge = core::f32::consts::TAU / 6.0;
+        for i in 0..20 {
+            let angle = i as f32 * 0.31;
+            let radius = 1.0 + i as f32 * 0.7;
+            let sample = |angle: f32| {
+                let (sin, cos) = bevy_math::ops::sin_cos(angle);
+                noise.sample_for::<f32>(Vec2::new(cos, sin) * radius)
+            };
+            assert!((sample(angle) - sample(angle + wedge)).abs() < 1e-3);
+            // mirrored within the wedge
+            assert!((sample(angle) - sample(-angle)).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_control_warp_zero_strength() {
+        use crate::prelude::common_noise::PerlinWithDerivative;
+
+        let unwarped = ControlWarp::<PerlinWithDerivative, Perlin> {
+            strength: 0.0,
+            ..Default::default()
+        };
+        let warped = ControlWarp::<PerlinWithDerivative, Perlin>::default();
+        let mut moved = false;
+        for x in -10..10 {
+            let point = Vec2::new(x as f32 * 0.37, 0.6);
+            let expected = Perlin::default().evaluate(point, &mut NoiseRng(2));
+            assert_eq!(unwarped.evaluate(point, &mut NoiseRng(2)), expected);
+            moved |= warped.evaluate(point, &mut NoiseRng(2)) != expected;
+        }
+        assert!(moved);
+    }
+
+    #[test]
+    fn test_mirror_repeat_continuous() {
+        let noise = Noise::<(MirrorRepeat<Vec2>, Perlin)>::from((
+            MirrorRepeat {
+                period: Vec2::new(3.0, 5.0),
+            },
+            Perlin::default(),
+        ));
+        // Walk across several mirrors on both axes.
+        let mut previous: f32 = noise.sample(Vec2::new(-7.0, -11.0));
+        for step in 1..=14000 {
+            let point = Vec2::new(-7.0, -11.0) + Vec2::new(1.0, 1.5) * step as f32 * 1e-3;
+            let value: f32 = noise.sample(point);
+            assert!(
+                (value - previous).abs() < 0.02,
+                "Discontinuity from {previous} to {value} at {point:?}"
+            );
+            previous = value;
+        }
+
+        let folded = MirrorRepeat {
+            period: Vec3::splat(2.0),
+        };
+        let mut seeds = NoiseRng(0);
+        let repeated = folded.evaluate(Vec3::new(0.5, 1.5, -0.5), &mut seeds);
+        let mirrored = folded.evaluate(Vec3::new(3.5, -1.5, 4.5), &mut seeds);
+        assert!(repeated.distance(mirrored) < 1e-5);
+        assert!(repeated.distance(Vec3::new(0.5, 1.5, 0.5)) < 1e-5);
+    }
+
+    #[test]
+    fn test_along_path_smooth() {
+        use crate::curves::Lerped;
+        let direction = Vec2::new(3.0, 1.0).normalize();
+        let noise = Noise::from(AlongPath {
+            noise: Perlin::default(),
+            path: Lerped {
+                start: Vec2::new(0.3, -0.2),
+                end: Vec2::new(0.3, -0.2) + direction,
+            },
+        });
+        let perlin = Noise::<Perlin>::default();
+        let mut previous = noise.sample_for::<f32>(0.0);
+        for i in 1..=500 {
+            let t = i as f32 * 0.01;
+            let value = noise.sample_for::<f32>(t);
+            assert!(
+                (value - previous).abs() < 0.05,
+                "Jumped from {previous} to {value} at {t}"
+            );
+            let expected = perlin.sample_for::<f32>(Vec2::new(0.3, -0.2) + direction * t);
+            assert!((value - expected).abs() < 1e-5);
+            previous = value;
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn test_distance_to_points() {
+        let noise = Noise::from(DistanceToPoints {
+            points: alloc::vec![Vec2::new(10.0, 5.0), Vec2::new(-3.0, 8.0)],
+            falloff: 4.0,
+            length_mode: EuclideanLength,
+        });
+        for point in [Vec2::new(10.0, 5.0), Vec2::new(-3.0, 8.0)] {
+            assert!((noise.sample_for::<f32>(point) - 1.0).abs() < 1e-6);
+            let mut previous = 1.0;
+            for step in 1..=10 {
+                let distance = step as f32 * 0.5;
+                let value = noise.sample_for::<f32>(point + Vec2::new(0.6, -0.8) * distance);
+                assert!(value < previous || value == 0.0);
+                assert!((value - (1.0 - distance / 4.0).max(0.0)).abs() < 1e-5);
+                previous = value;
+            }
+        }
+        assert_eq!(noise.sample_for::<f32>(Vec2::new(100.0, 0.0)), 0.0);
+        let empty = Noise::<DistanceToPoints>::default();
+        assert_eq!(empty.sample_for::<f32>(Vec2::ZERO), 0.0);
+    }
+
+    #[test]
+    fn test_variable_scale_constant_control() {
+        let noise = Noise::from(VariableScale {
+            control: |_: Vec2, _: &mut NoiseRng| 0.5,
+            noise: Perlin::default(),
+            base: 1.0,
+            range: 2.0,
+        });
+        let noise_3d = Noise::from(VariableScale {
+            control: |_: Vec3, _: &mut NoiseRng| 0.5,
+            noise: Perlin::default(),
+            base: 1.0,
+            range: 2.0,
+        });
+        let perlin = Noise::<Perlin>::default();
+        for x in -10..=10 {
+            for y in -10..=10 {
+                let point = Vec2::new(x as f32, y as f32) * 0.37;
+                assert_eq!(
+                    noise.sample_for::<f32>(point),
+                    perlin.sample_for::<f32>(point * 2.0)
+                );
+                let point = point.extend(0.3);
+                assert_eq!(
+                    noise_3d.sample_for::<f32>(point),
+                    perlin.sample_for::<f32>(point * 2.0)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_incompressible_warp_preserves_area() {
+        use crate::prelude::common_noise::PerlinWithDerivative;
+        const STRENGTH: f32 = 0.01;
+        const STEP: f32 = 1e-3;
+        let incompressible = IncompressibleWarp {
+            potential: PerlinWithDerivative::default(),
+            strength: STRENGTH,
+        };
+        let ordinary = |input: Vec2, seeds: &mut NoiseRng| {
+            input
+                + PerlinWithDerivative::default()
+                    .evaluate(input, seeds)
+                    .gradient
+                    * STRENGTH
+        };
+        let area_change = |warp: &dyn Fn(Vec2) -> Vec2, point: Vec2| {
+            let d_dx = (warp(point + Vec2::X * STEP) - warp(point - Vec2::X * STEP)) / (2.0 * STEP);
+            let d_dy = (warp(point + Vec2::Y * STEP) - warp(point - Vec2::Y * STEP)) / (2.0 * STEP);
+            (d_dx.perp_dot(d_dy) - 1.0).abs()
+        };
+        let mut max_incompressible = 0.0f32;
+        let mut max_ordinary = 0.0f32;
+        for x in -10..=10 {
+            for y in -10..=10 {
+                let point = Vec2::new(x as f32, y as f32) * 0.37 + 0.05;
+                max_incompressible = max_incompressible.max(area_change(
+                    &|p| incompressible.evaluate(p, &mut NoiseRng(0)),
+                    point,
+                ));
+                max_ordinary =
+                    max_ordinary.max(area_change(&|p| ordinary(p, &mut NoiseRng(0)), point));
+            }
+        }
+        assert!(
+            max_incompressible < 0.02,
+            "Area changed by up to {max_incompressible}"
+        );
+        assert!(max_ordinary > max_incompressible * 2.0);
+    }
+
+    #[test]
+    fn test_seed_lerp_endpoints() {
+        let variant = |seed: u32| Noise {
+            noise: Perlin::default(),
+            seed: NoiseRng(seed),
+            frequency: 1.0,
+        };
+        let lerp = |t: f32| {
+            Noise::from(SeedLerp {
+                noise: Perlin::default(),
+                seed_a: 3,
+                seed_b: 11,
+                t,
+            })
+        };
+        let mut any_differ = false;
+        for x in -10..=10 {
+            for y in -10..=10 {
+                let point = Vec2::new(x as f32, y as f32) * 0.37;
+                let a = variant(3).sample_for::<f32>(point);
+                let b = variant(11).sample_for::<f32>(point);
+                assert!((lerp(0.0).sample_for::<f32>(point) - a).abs() < 1e-6);
+                assert!((lerp(1.0).sample_for::<f32>(point) - b).abs() < 1e-6);
+                let half = lerp(0.5).sample_for::<f32>(point);
+                assert!((half - (a + b) * 0.5).abs() < 1e-6);
+                any_differ |= a != b;
+            }
+        }
+        assert!(any_differ);
+    }
+
+    #[test]
+    fn test_rgb_noise_channels_decorrelated() {
+        let noise = Noise::<RgbNoise<Perlin>>::default();
+        let mut colors = Vec::new();
+        for x in -30..=30 {
+            for y in -30..=30 {
+                let color: Vec3 = noise.sample(Vec2::new(x as f32, y as f32) * 0.37);
+                assert!(color.abs().max_element() <= 1.0, "{color} is out of range");
+                colors.push(color);
+            }
+        }
+
+        let count = colors.len() as f32;
+        let mean = colors.iter().sum::<Vec3>() / count;
+        let covariance = |a: usize, b: usize| {
+            colors
+                .iter()
+                .map(|c| (c[a] - mean[a]) * (c[b] - mean[b]))
+                .sum::<f32>()
+                / count
+        };
+        for (a, b) in [(0, 1), (1, 2), (0, 2)] {
+            let correlation = covariance(a, b) / (covariance(a, a) * covariance(b, b)).sqrt();
+            assert!(
+                correlation.abs() < 0.2,
+                "channels {a} and {b} are correlated by {correlation}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_calibrate_known_range() {
+        // Scaling per cell white noise to (2, 5) gives a known range to recover.
+        let noise = Noise::from(|input: Vec2, seeds: &mut NoiseRng| {
+            2.0 + 3.0 * seeds.rand_unorm(input.floor().as_ivec2())
+        });
+        let mut calibrate = Calibrate::new();
+        assert!(calibrate.remap().evaluate(3.0, &mut NoiseRng(0)) == 0.0);
+        for x in -30..30 {
+            for y in -30..30 {
+                let value = calibrate.sample(&noise, Vec2::new(x as f32, y as f32) + 0.5);
+                assert!((2.0..=5.0).contains(&value));
+            }
+        }
+
+        let observed = calibrate.observed;
+        assert!(
+            observed.min >= 2.0 && observed.min < 2.05,
+            "{}",
+            observed.min
+        );
+        assert!(
+            observed.max <= 5.0 && observed.max > 4.95,
+            "{}",
+            observed.max
+        );
+
+        let remap = calibrate.remap();
+        let mut rng = NoiseRng(0);
+        assert!(remap.evaluate(observed.min, &mut rng).abs() < 1e-6);
+        assert!((remap.evaluate(observed.max, &mut rng) - 1.0).abs() < 1e-6);
+        assert!((remap.evaluate(3.5, &mut rng) - 0.5).abs() < 0.02);
+        assert!(remap.evaluate(10.0, &mut rng) == 1.0);
+
+        calibrate.reset();
+        assert!(calibrate.observed.min == f32::INFINITY);
+    }
+}
@@ -1,8 +1,8 @@
 //! Contains common imports
 
 pub use crate::{
-    DynamicConfigurableSampleable, DynamicSampleable, Noise, NoiseFunction, Sampleable,
-    SampleableFor, ScalableNoise, SeedableNoise,
+    DynamicConfigurableSampleable, DynamicSampleable, HeightmapSampleable, Noise, NoiseFunction,
+    Sampleable, SampleableFor, ScalableNoise, SeedableNoise,
     cell_noise::{
         BlendCellGradients, BlendCellValues, DistanceBlend, MixCellGradients, MixCellValues,
         PerCell, PerCellPointDistances, QuickGradients, SimplecticBlend, WorleyLeastDistance,
@@ -11,14 +11,19 @@ pub use crate::{
     curves::{DoubleSmoothstep, Lerped, Linear, Smoothstep},
     layering::{
         DomainWarp, FractalLayers, LayeredNoise, Normed, NormedByDerivative, Octave,
-        PeakDerivativeContribution, Persistence, SmoothDerivativeContribution,
+        PeakDerivativeContribution, Persistence, RotatedLayers, SmoothDerivativeContribution,
     },
     lengths::{EuclideanLength, ManhattanLength},
     math_noise::{Billow, PingPong, SNormToUNorm, UNormToSNorm},
-    misc_noise::{Masked, Offset, RandomElements, RemapCurve, Scaled, SelfMasked, Translated},
+    misc_noise::{
+        Masked, Offset, RandomElements, Remap, RemapCurve, Scaled, SelfMasked, Translated,
+    },
     rng::{Random, SNorm, UNorm},
 };
 
+#[cfg(feature = "alloc")]
+pub use crate::IntoDynamicSampleable;
+
 /// Contains type aliases for common noise types.
 /// This reduces some boiler plate and is educational.
 pub mod common_noise {
@@ -51,4 +56,27 @@ pub mod common_noise {
 
     /// Represents traditional fractal brownian motion.
     pub type Fbm<T> = LayeredNoise<Normed<f32>, Persistence, FractalLayers<Octave<T>>>;
+
+    /// Represents fractal brownian motion in 2d that also computes its exact gradient in a [`WithGradient`], using the chain rule across octaves.
+    /// `T` must be differentiable, like [`PerlinWithDerivative`] or [`SimplexWithDerivative`].
+    ///
+    /// This gives the height and slope of a heightmap from one sample, instead of sampling nearby points to estimate normals.
+    /// Pair it with [`TerrainNormal`](crate::math_noise::TerrainNormal) to get surface normals directly:
+    ///
+    /// ```
+    /// # use noiz::prelude::*;
+    /// use noiz::math_noise::TerrainNormal;
+    /// let noise = Noise::<(common_noise::FbmWithNormals<common_noise::PerlinWithDerivative>, TerrainNormal)>::default();
+    /// let normal = noise.sample_for::<bevy_math::Vec3>(bevy_math::Vec2::ZERO);
+    /// ```
+    pub type FbmWithNormals<T> = LayeredNoise<
+        Normed<WithGradient<f32, bevy_math::Vec2>>,
+        Persistence,
+        FractalLayers<Octave<T>>,
+    >;
+
+    /// Represents fractal brownian motion in 2d that rotates each layer to hide axis aligned artifacts.
+    /// Construct this with [`rotated_fbm`](LayeredNoise::rotated_fbm).
+    pub type RotatedFbm<T> =
+        LayeredNoise<Normed<f32>, Persistence, RotatedLayers<Octave<T>, bevy_math::Mat2>>;
 }
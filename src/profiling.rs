@@ -0,0 +1,130 @@
+//! Contains [`Timed`], which measures how long a [`NoiseFunction`] takes to evaluate.
+//!
+//! This is only available with the `profiling` feature.
+
+extern crate std;
+
+use alloc::vec::Vec;
+use core::{cell::RefCell, time::Duration};
+use std::{collections::HashMap, thread_local, time::Instant};
+
+use crate::{NoiseFunction, NoiseRange, OutputRange, rng::NoiseRng};
+
+thread_local! {
+    static TIMINGS: RefCell<HashMap<&'static str, Timing>> = RefCell::new(HashMap::new());
+}
+
+/// The accumulated cost of every [`Timed`] with a particular label on this thread.
+#[derive(Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Timing {
+    /// The number of times the noise was evaluated.
+    pub calls: u64,
+    /// The total wall-clock time spent evaluating the noise.
+    pub total: Duration,
+}
+
+impl Timing {
+    /// The average time of one evaluation, or zero if there were none.
+    pub fn average(&self) -> Duration {
+        if self.calls == 0 {
+            Duration::ZERO
+        } else {
+            self.total.div_f64(self.calls as f64)
+        }
+    }
+}
+
+/// A [`NoiseFunction`] that evaluates `N` and records how long it took under `label`.
+/// Use [`timings`] to see the results and [`reset_timings`] to clear them.
+///
+/// This isolates the cost of one node in a deep chain of noise functions, which external profilers often inline away.
+/// Timings are tracked per thread, and nested [`Timed`] nodes include the time of their children.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use noiz::profiling::{Timed, timings};
+/// let noise = Noise::from(Timed {
+///     noise: common_noise::Perlin::default(),
+///     label: "perlin",
+/// });
+/// let _: f32 = noise.sample(bevy_math::Vec2::ZERO);
+/// assert_eq!(timings()[0].1.calls, 1);
+/// ```
+#[derive(Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize))]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct Timed<N> {
+    /// The [`NoiseFunction`] to time.
+    pub noise: N,
+    /// The name its [`Timing`] is recorded under.
+    pub label: &'static str,
+}
+
+impl<I, N: NoiseFunction<I>> NoiseFunction<I> for Timed<N> {
+    type Output = N::Output;
+
+    #[inline]
+    fn evaluate(&self, input: I, seeds: &mut NoiseRng) -> Self::Output {
+        let start = Instant::now();
+        let result = self.noise.evaluate(input, seeds);
+        let elapsed = start.elapsed();
+        TIMINGS.with_borrow_mut(|timings| {
+            let timing = timings.entry(self.label).or_default();
+            timing.calls += 1;
+            timing.total += elapsed;
+        });
+        result
+    }
+}
+
+impl<N: NoiseRange> NoiseRange for Timed<N> {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        self.noise.output_range(input)
+    }
+}
+
+/// Gets the [`Timing`] of each label recorded by a [`Timed`] on this thread, sorted by label.
+pub fn timings() -> Vec<(&'static str, Timing)> {
+    let mut result = TIMINGS.with_borrow(|timings| {
+        timings
+            .iter()
+            .map(|(label, timing)| (*label, *timing))
+            .collect::<Vec<_>>()
+    });
+    result.sort_unstable_by_key(|(label, _)| *label);
+    result
+}
+
+/// Clears every [`Timing`] recorded by a [`Timed`] on this thread.
+pub fn reset_timings() {
+    TIMINGS.with_borrow_mut(HashMap::clear);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Noise, Sampleable, prelude::common_noise::Perlin};
+    use bevy_math::Vec2;
+
+    #[test]
+    fn test_timed_accumulates() {
+        reset_timings();
+        let noise = Noise::from(Timed {
+            noise: Perlin::default(),
+            label: "perlin",
+        });
+        for x in 0..10 {
+            noise.sample_for::<f32>(Vec2::new(x as f32, 0.5));
+        }
+        let recorded = timings();
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].0, "perlin");
+        assert_eq!(recorded[0].1.calls, 10);
+
+        reset_timings();
+        assert!(timings().is_empty());
+    }
+}
@@ -0,0 +1,90 @@
+//! Contains [`NoiseRegistry`], which lets tools enumerate and build noise by name.
+
+use alloc::{boxed::Box, collections::BTreeMap, string::String};
+
+use bevy_math::Vec2;
+
+use crate::DynamicSampleable;
+
+/// A boxed, type-erased noise produced by a [`NoiseRegistry`].
+pub type BoxedNoise = Box<dyn DynamicSampleable<Vec2, f32>>;
+
+/// Maps names to constructors of [`BoxedNoise`].
+/// This lets tools, like editors, list the available noise types and instantiate them by name.
+///
+/// ```
+/// # use noiz::prelude::*;
+/// # use bevy_math::prelude::*;
+/// use noiz::registry::NoiseRegistry;
+/// let mut registry = NoiseRegistry::default();
+/// registry.register("perlin", || Box::new(Noise::<common_noise::Perlin>::default()));
+/// let noise = registry.build("perlin").unwrap();
+/// let value = noise.sample_dyn(Vec2::new(1.0, -1.0));
+/// ```
+#[derive(Default)]
+pub struct NoiseRegistry {
+    constructors: BTreeMap<String, Box<dyn Fn() -> BoxedNoise>>,
+}
+
+impl NoiseRegistry {
+    /// Registers `constructor` under `name`, replacing any constructor that was already registered under it.
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        constructor: impl Fn() -> BoxedNoise + 'static,
+    ) {
+        self.constructors.insert(name.into(), Box::new(constructor));
+    }
+
+    /// Removes the constructor registered under `name`, returning whether there was one.
+    pub fn unregister(&mut self, name: &str) -> bool {
+        self.constructors.remove(name).is_some()
+    }
+
+    /// Builds the noise registered under `name`, if there is one.
+    pub fn build(&self, name: &str) -> Option<BoxedNoise> {
+        self.constructors.get(name).map(|constructor| constructor())
+    }
+
+    /// Returns whether a constructor is registered under `name`.
+    pub fn contains(&self, name: &str) -> bool {
+        self.constructors.contains_key(name)
+    }
+
+    /// Iterates the registered names in sorted order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.constructors.keys().map(String::as_str)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Noise, SampleableFor,
+        prelude::common_noise::{Perlin, Simplex},
+    };
+
+    #[test]
+    fn test_registry() {
+        let mut registry = NoiseRegistry::default();
+        registry.register("perlin", || Box::new(Noise::<Perlin>::default()));
+        registry.register("simplex".to_owned(), || {
+            Box::new(Noise::<Simplex>::default())
+        });
+
+        assert!(registry.names().eq(["perlin", "simplex"]));
+        assert!(registry.build("value").is_none());
+
+        let perlin = registry.build("perlin").unwrap();
+        let simplex = registry.build("simplex").unwrap();
+        let point = Vec2::new(1.3, -0.7);
+        let expected: f32 = Noise::<Perlin>::default().sample(point);
+        assert_eq!(perlin.sample_dyn(point), expected);
+        let expected: f32 = Noise::<Simplex>::default().sample(point);
+        assert_eq!(simplex.sample_dyn(point), expected);
+
+        assert!(registry.unregister("perlin"));
+        assert!(!registry.contains("perlin"));
+    }
+}
@@ -5,7 +5,7 @@ use core::marker::PhantomData;
 
 use bevy_math::{IVec2, IVec3, IVec4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4};
 
-use crate::NoiseFunction;
+use crate::{NoiseFunction, NoiseRange, OutputRange};
 
 /// A seeded random number generator (rng), specialized for procedural noise.
 ///
@@ -49,17 +49,93 @@ impl NoiseRng {
         self.0 = Self::KEY.wrapping_mul(self.0 ^ Self::KEY);
     }
 
+    /// Based on `input`, generates a random `f32` in range (0, 1).
+    #[inline(always)]
+    pub fn rand_unorm(&self, input: impl NoiseRngInput) -> f32 {
+        AnyValueFromBits::<f32>::any_value(&UNorm, self.rand_u32(input))
+    }
+
+    /// Based on `input`, generates a random [`Vec2`] with each element in range (0, 1).
+    /// Each element comes from [`rand_unorm`](Self::rand_unorm) with a different seed, so elements are not correlated.
+    ///
+    /// This is useful for placing points within a cell, ex: in a custom [`Partitioner`](crate::cells::Partitioner).
+    #[inline(always)]
+    pub fn rand_unorm_vec2(&self, input: impl NoiseRngInput) -> Vec2 {
+        let input = input.collapse_for_rng();
+        let mut rng = *self;
+        let x = rng.rand_unorm(input);
+        rng.re_seed();
+        let y = rng.rand_unorm(input);
+        Vec2::new(x, y)
+    }
+
+    /// Based on `input`, generates a random [`Vec3`] with each element in range (0, 1).
+    /// See also [`rand_unorm_vec2`](Self::rand_unorm_vec2).
+    #[inline(always)]
+    pub fn rand_unorm_vec3(&self, input: impl NoiseRngInput) -> Vec3 {
+        let input = input.collapse_for_rng();
+        let mut rng = *self;
+        let x = rng.rand_unorm(input);
+        rng.re_seed();
+        let y = rng.rand_unorm(input);
+        rng.re_seed();
+        let z = rng.rand_unorm(input);
+        Vec3::new(x, y, z)
+    }
+
+    /// Based on `input`, generates a random [`Vec4`] with each element in range (0, 1).
+    /// See also [`rand_unorm_vec2`](Self::rand_unorm_vec2).
+    #[inline(always)]
+    pub fn rand_unorm_vec4(&self, input: impl NoiseRngInput) -> Vec4 {
+        let input = input.collapse_for_rng();
+        let mut rng = *self;
+        let x = rng.rand_unorm(input);
+        rng.re_seed();
+        let y = rng.rand_unorm(input);
+        rng.re_seed();
+        let z = rng.rand_unorm(input);
+        rng.re_seed();
+        let w = rng.rand_unorm(input);
+        Vec4::new(x, y, z, w)
+    }
+
+    /// Gets the high 16 bits of `bits`, a result of [`rand_u32`](Self::rand_u32).
+    /// These are the best distributed bits of the hash, so prefer them when 16 bits are enough.
+    ///
+    /// ```
+    /// # use noiz::rng::*;
+    /// let bits = NoiseRng(1234).rand_u32(42u32);
+    /// let is_tree = NoiseRng::high_u16(bits) < u16::MAX / 4;
+    /// ```
+    #[inline(always)]
+    pub fn high_u16(bits: u32) -> u16 {
+        (bits >> 16) as u16
+    }
+
+    /// Gets 16 bits of `bits`, a result of [`rand_u32`](Self::rand_u32), that are different from [`high_u16`](Self::high_u16).
+    /// The low bits of the hash have less entropy on their own, so they are mixed with the high bits to stay uniform.
+    ///
+    /// Together with [`high_u16`](Self::high_u16), this decodes two random fields from one hash, ex: a variant and a rotation.
+    #[inline(always)]
+    pub fn low_u16(bits: u32) -> u16 {
+        (bits ^ (bits >> 16)) as u16
+    }
+
     /// Based on `input`, generates a random `u32`.
     /// Note that there will be more entropy in higher bits than others.
     #[inline(always)]
     pub fn rand_u32(&self, input: impl NoiseRngInput) -> u32 {
-        let i = input.collapse_for_rng();
+        Self::hash(self.0, input.collapse_for_rng())
+    }
 
+    /// The hash behind [`rand_u32`](Self::rand_u32).
+    #[inline(always)]
+    fn hash(seed: u32, i: u32) -> u32 {
         // Inspired by https://nullprogram.com/blog/2018/07/31/
         let mut x = i;
         x ^= x.rotate_right(17);
         x = x.wrapping_mul(Self::KEY);
-        x ^= x.rotate_right(11) ^ self.0;
+        x ^= x.rotate_right(11) ^ seed;
         x = x.wrapping_mul(!Self::KEY);
         x
 
@@ -133,6 +209,76 @@ impl NoiseRng {
     }
 }
 
+/// A seeded random number generator (rng) that uses a classic, Perlin-style permutation table instead of [`NoiseRng`]'s hash.
+///
+/// This is a standalone rng: nothing in this crate uses it, but you can use it in your own noise functions.
+/// Looking up values in a small table can be faster than multiplying on some CPUs, especially when the same lattice is sampled repeatedly, since the table stays in cache.
+/// The trade off is quality: a table of 256 entries can only produce 256 distinct results.
+/// So no matter the input, [`PermTableRng::rand_u32`] only ever returns 256 different `u32`s, which can lead to tiling and artifacting from far away.
+/// If you are not sure which to use, prefer [`NoiseRng`].
+///
+/// ```
+/// # use noiz::rng::*;
+/// # use bevy_math::IVec2;
+/// let rng = PermTableRng::new(1234);
+/// let bits = rng.rand_u32(IVec2::new(3, -7));
+/// let value: f32 = UNorm.any_value(bits);
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct PermTableRng {
+    /// The permutation of `0..256`, repeated twice so that lookups of `table[a] + b` never need to wrap.
+    table: [u8; 512],
+}
+
+impl Default for PermTableRng {
+    #[inline]
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl PermTableRng {
+    /// Constructs a new [`PermTableRng`], shuffling its table based on `seed`.
+    pub fn new(seed: u32) -> Self {
+        Self {
+            table: Self::shuffled_table(seed),
+        }
+    }
+
+    /// Makes a permutation of `0..256`, repeated twice, shuffled based on `seed`.
+    fn shuffled_table(seed: u32) -> [u8; 512] {
+        let mut table = [0u8; 512];
+        for (i, entry) in table[..256].iter_mut().enumerate() {
+            *entry = i as u8;
+        }
+        // Fisher-Yates shuffle
+        for i in (1..256u32).rev() {
+            let j = (NoiseRng::hash(seed, i) % (i + 1)) as usize;
+            table.swap(i as usize, j);
+        }
+        table.copy_within(..256, 256);
+        table
+    }
+
+    /// Walks `table` through each of `bytes`, producing a random `u32`.
+    #[inline(always)]
+    fn walk(table: &[u8; 512], bytes: impl IntoIterator<Item = u8>) -> u32 {
+        let mut h = 0usize;
+        for byte in bytes {
+            h = table[h + byte as usize] as usize;
+        }
+        u32::from_le_bytes([table[h + 3], table[h + 2], table[h + 1], h as u8])
+    }
+
+    /// Based on `input`, generates a random `u32`.
+    /// Note that this only ever produces 256 distinct values, one for each entry in the table.
+    #[inline(always)]
+    pub fn rand_u32(&self, input: impl NoiseRngInput) -> u32 {
+        Self::walk(&self.table, input.collapse_for_rng().to_le_bytes())
+    }
+}
+
 mod float_rng {
     #![expect(
         clippy::unusual_byte_groupings,
@@ -576,3 +722,125 @@ impl_norms!(
         any_half_rng_float_8(bits as u8),
     )
 );
+
+impl NoiseRange for UNorm {
+    #[inline]
+    fn output_range(&self, _input: OutputRange) -> OutputRange {
+        OutputRange::UNORM
+    }
+}
+
+impl NoiseRange for UNormHalf {
+    #[inline]
+    fn output_range(&self, _input: OutputRange) -> OutputRange {
+        OutputRange { min: 0.0, max: 0.5 }
+    }
+}
+
+impl NoiseRange for SNorm {
+    #[inline]
+    fn output_range(&self, _input: OutputRange) -> OutputRange {
+        OutputRange::SNORM
+    }
+}
+
+impl NoiseRange for SNormSplit {
+    #[inline]
+    fn output_range(&self, _input: OutputRange) -> OutputRange {
+        OutputRange::SNORM
+    }
+}
+
+impl<R: NoiseRange, T> NoiseRange for Random<R, T> {
+    #[inline]
+    fn output_range(&self, input: OutputRange) -> OutputRange {
+        self.0.output_range(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rand_unorm_vecs() {
+        let rng = NoiseRng(1234);
+        let mut sum = Vec4::ZERO;
+        let mut sum_sqr = Vec4::ZERO;
+        let mut sum_xy = 0.0;
+        let mut sum_zw = 0.0;
+        const SAMPLES: u32 = 10_000;
+        for i in 0..SAMPLES {
+            let v2 = rng.rand_unorm_vec2(i);
+            let v3 = rng.rand_unorm_vec3(i);
+            let v = rng.rand_unorm_vec4(i);
+            for element in v2
+                .to_array()
+                .into_iter()
+                .chain(v3.to_array())
+                .chain(v.to_array())
+            {
+                assert!(
+                    (0.0..1.0).contains(&element),
+                    "{element} is out of range for input {i}"
+                );
+            }
+            sum += v;
+            sum_sqr += v * v;
+            sum_xy += v.x * v.y;
+            sum_zw += v.z * v.w;
+        }
+
+        let n = SAMPLES as f32;
+        let mean = sum / n;
+        let variance = sum_sqr / n - mean * mean;
+        let correlation = |cross: f32, a: usize, b: usize| {
+            (cross / n - mean[a] * mean[b]) / (variance[a] * variance[b]).sqrt()
+        };
+        assert!(correlation(sum_xy, 0, 1).abs() < 0.05);
+        assert!(correlation(sum_zw, 2, 3).abs() < 0.05);
+    }
+
+    #[test]
+    fn test_u16_halves_uniform() {
+        const SAMPLES: u32 = 16_000;
+        let rng = NoiseRng(1234);
+        let mut high = [0u32; 16];
+        let mut low = [0u32; 16];
+        let mut low_bits = [0u32; 16];
+        for i in 0..SAMPLES {
+            let bits = rng.rand_u32(UVec2::new(i % 128, i / 128));
+            high[(NoiseRng::high_u16(bits) >> 12) as usize] += 1;
+            low[(NoiseRng::low_u16(bits) >> 12) as usize] += 1;
+            low_bits[(NoiseRng::low_u16(bits) & 15) as usize] += 1;
+        }
+        // Pearson's chi-squared test with 15 degrees of freedom; 37.7 is the 0.1% critical value.
+        let expected = SAMPLES as f32 / 16.0;
+        for buckets in [high, low, low_bits] {
+            let chi_sqr: f32 = buckets
+                .iter()
+                .map(|&count| (count as f32 - expected).powi(2) / expected)
+                .sum();
+            assert!(chi_sqr < 37.7, "{buckets:?} is not uniform");
+        }
+    }
+
+    #[test]
+    fn test_perm_table_is_permutation() {
+        for seed in [0, 1, 1234] {
+            let table = PermTableRng::shuffled_table(seed);
+            let mut seen = [false; 256];
+            for &entry in &table[..256] {
+                assert!(
+                    !seen[entry as usize],
+                    "{entry} appears twice for seed {seed}"
+                );
+                seen[entry as usize] = true;
+            }
+            assert!(table[..256] == table[256..]);
+        }
+        assert!(PermTableRng::shuffled_table(0) != PermTableRng::shuffled_table(1));
+        let rng = PermTableRng::new(1234);
+        assert!(rng.rand_u32(7u32) == PermTableRng::walk(&rng.table, 7u32.to_le_bytes()));
+    }
+}